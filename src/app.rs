@@ -1,12 +1,29 @@
 #![allow(dead_code)]
 
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::collections::VecDeque;
 use std::path::PathBuf;
+use std::time::UNIX_EPOCH;
+
+use jiff::tz::TimeZone;
+use regex::{Regex, RegexBuilder};
 
 use crate::config::LaramuxConfig;
+use crate::editor::{find_file_refs, FileRef};
+use crate::log::exceptions::{ExceptionGroup, ExceptionGroups, ExceptionSort};
+use crate::log::format::LogFormat;
+use crate::log::history::HistoryRequest;
+use crate::log::time::{parse_range, parse_timestamp, unix_now, TimeFilter};
+use crate::log::LogEntry;
+use crate::process::console::{ConsoleBinary, ARTISAN};
+use crate::process::crash::CrashReport;
+use crate::process::manager::RestartState;
+use crate::process::sail::ContainerState;
+use crate::process::supervisor::SupervisorInfo;
 use crate::process::types::{
-    OutputLine, Process, ProcessConfig, ProcessId, ProcessRegistry, ProcessStatus,
+    ExitReason, OutputLine, Process, ProcessConfig, ProcessId, ProcessRegistry, ProcessStatus,
+    RunRecord,
 };
 use crate::process::{FullArtisanCommand, QualityTool};
 use crate::ui::tabs::Tab;
@@ -14,6 +31,15 @@ use crate::ui::tabs::Tab;
 /// Default maximum number of log lines to display
 pub const DEFAULT_MAX_LOG_LINES: usize = 100;
 
+/// Stack trace lines kept in view below an error jumped to with n/N
+const MAX_JUMP_TRACE_LINES: usize = 10;
+
+/// Continuation lines kept per log entry; longer traces are cut off
+const MAX_LOG_CONTINUATION_LINES: usize = 500;
+
+/// Rows kept in view below a log search hit jumped to with n/N
+const LOG_MATCH_CONTEXT_ROWS: usize = 5;
+
 /// System resource statistics
 #[derive(Debug, Clone, Default)]
 pub struct SystemStats {
@@ -25,8 +51,19 @@ pub struct SystemStats {
     pub total_memory: u64,
     /// Used memory in bytes
     pub used_memory: u64,
-    /// Per-process stats keyed by PID
+    /// Per-process stats for the managed process trees, keyed by PID
     pub process_stats: HashMap<u32, ProcessStats>,
+    /// Stats summed over each managed process's tree, keyed by its PID
+    pub process_trees: HashMap<u32, TreeStats>,
+}
+
+/// Resource usage of a managed process together with its descendants and process group
+#[derive(Debug, Clone, Default)]
+pub struct TreeStats {
+    pub cpu_usage: f32,
+    pub memory_bytes: u64,
+    /// PIDs included in the totals, the managed process first
+    pub pids: Vec<u32>,
 }
 
 /// Per-process resource statistics
@@ -36,14 +73,54 @@ pub struct ProcessStats {
     pub cpu_usage: f32,
     /// Memory usage in bytes for this process
     pub memory_bytes: u64,
+    /// Parent process ID
+    pub parent_pid: Option<u32>,
+    /// Start time in seconds since the Unix epoch
+    pub start_time: u64,
+    /// Command line (or process name when unavailable)
+    pub command: String,
 }
 
-/// A line from a log file
+/// An entry from a log file: the header line plus the stack frames, JSON
+/// context and `[stacktrace]` lines that follow it
 #[derive(Debug, Clone)]
 pub struct LogLine {
+    /// Sequence number, stable while older entries are dropped
+    pub id: u64,
+    /// The line as read, or a text header for JSON records
     pub content: String,
     pub level: LogLevel,
+    /// Timestamp as written, empty for lines the source's format doesn't parse
+    pub time: String,
+    pub channel: Option<String>,
+    /// Message without the timestamp, level and channel
+    pub message: String,
     pub file: String,
+    /// Format of the source file
+    pub format: LogFormat,
+    /// Byte offset of the entry's first line in its file
+    pub offset: u64,
+    /// Seconds since the Unix epoch, for entries with a readable timestamp
+    pub timestamp: Option<i64>,
+    pub continuation: Vec<String>,
+}
+
+impl LogLine {
+    /// Number of rows the entry takes up in the Logs tab
+    pub fn row_count(&self, expanded: bool) -> usize {
+        if expanded {
+            1 + self.continuation.len()
+        } else {
+            1
+        }
+    }
+
+    /// Whether the header or any continuation line matches the search
+    pub fn is_match(&self, pattern: &Regex) -> bool {
+        std::iter::once(&self.content)
+            .chain(&self.continuation)
+            .any(|line| pattern.is_match(line))
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -60,16 +137,17 @@ pub enum LogLevel {
 }
 
 impl LogLevel {
+    /// Level from its name in any log format, e.g. `ERROR`, `warn` or `crit`
     pub fn from_str(s: &str) -> Self {
         match s.to_lowercase().as_str() {
-            "debug" => LogLevel::Debug,
-            "info" => LogLevel::Info,
+            "debug" | "trace" => LogLevel::Debug,
+            "info" | "information" => LogLevel::Info,
             "notice" => LogLevel::Notice,
-            "warning" => LogLevel::Warning,
-            "error" => LogLevel::Error,
-            "critical" => LogLevel::Critical,
+            "warning" | "warn" => LogLevel::Warning,
+            "error" | "err" => LogLevel::Error,
+            "critical" | "crit" | "fatal" => LogLevel::Critical,
             "alert" => LogLevel::Alert,
-            "emergency" => LogLevel::Emergency,
+            "emergency" | "emerg" | "panic" => LogLevel::Emergency,
             _ => LogLevel::Unknown,
         }
     }
@@ -124,6 +202,86 @@ impl LogLevel {
     }
 }
 
+/// Turn a line read from a log file into an entry, using its source's
+/// format. Returns whether it starts a new entry, or may continue the file's
+/// previous one. Timestamps without a zone are read in `zone`.
+fn parse_log_line(entry: LogEntry, zone: &TimeZone) -> (LogLine, bool) {
+    let Some(parsed) = entry.format.parse(&entry.content) else {
+        let line = LogLine {
+            id: 0,
+            level: LogLevel::Unknown,
+            time: String::new(),
+            channel: None,
+            message: entry.content.clone(),
+            content: entry.content,
+            file: entry.file,
+            format: entry.format,
+            offset: entry.offset,
+            timestamp: None,
+            continuation: Vec::new(),
+        };
+        return (line, false);
+    };
+
+    let line = LogLine {
+        id: 0,
+        timestamp: parse_timestamp(&parsed.time, zone),
+        // Records that are not text, like JSON, show a Laravel-style header
+        content: parsed.header.unwrap_or(entry.content),
+        level: parsed.level,
+        time: parsed.time,
+        channel: parsed.channel,
+        message: parsed.message,
+        file: entry.file,
+        format: entry.format,
+        offset: entry.offset,
+        continuation: parsed.fields,
+    };
+    (line, true)
+}
+
+/// Attach a line that doesn't start an entry to the last entry from its file,
+/// searching `entries` newest first. Returns the line back when there is no
+/// entry to attach it to.
+fn attach_continuation<'a>(
+    mut entries: impl Iterator<Item = &'a mut LogLine>,
+    line: LogLine,
+) -> Option<LogLine> {
+    let entry = entries
+        .find(|entry| entry.file == line.file)
+        .filter(|entry| entry.level != LogLevel::Unknown);
+    match entry {
+        Some(entry) => {
+            if entry.continuation.len() < MAX_LOG_CONTINUATION_LINES {
+                entry.continuation.push(line.content);
+            }
+            None
+        }
+        None => Some(line),
+    }
+}
+
+/// Ids of live entries start here, leaving room below for older entries
+/// paged in before them
+const FIRST_LIVE_LOG_ID: u64 = 1 << 32;
+
+/// Older log entries paged in from disk above the live tail
+#[derive(Debug, Default)]
+pub struct LogHistoryState {
+    /// Entries at the front of the buffer that were paged in
+    pub lines: usize,
+    /// A request was sent and its page has not arrived yet
+    pub pending: bool,
+    /// The start of the files was reached
+    pub exhausted: bool,
+    request: Option<HistoryRequest>,
+    /// Per file, the offset of the first entry read from the live tail.
+    /// Kept after the entry is evicted, so paging back resumes above it.
+    tail_start: HashMap<String, u64>,
+    /// Per file, the offset of the oldest entry paged in
+    paged_start: HashMap<String, u64>,
+}
+
 // ============================================================================
 // Processes Tab State
 // ============================================================================
@@ -134,6 +292,8 @@ pub enum ProcessesView {
     #[default]
     List,
     Output,
+    Inspector,
+    Crashes,
 }
 
 /// State for the Processes tab
@@ -142,6 +302,21 @@ pub struct ProcessesTabState {
     pub view: ProcessesView,
     pub selected_index: usize,
     pub output_scroll_offset: usize,
+    pub inspector_scroll: u16,
+    /// Highlighted entry of the signal menu, when it is open
+    pub signal_menu: Option<usize>,
+    /// Run number shown in the output view; None follows the latest run
+    pub viewed_run: Option<u32>,
+    /// Show output as received, ignoring the process's hide and highlight rules
+    pub raw_output: bool,
+    /// Absolute line number of the error last jumped to with n/N
+    pub error_cursor: Option<u64>,
+    /// Crash reports on disk, newest first
+    pub crashes: Vec<CrashReport>,
+    pub crash_index: usize,
+    /// Content of the selected crash report
+    pub crash_preview: String,
+    pub crash_scroll: u16,
 }
 
 impl ProcessesTabState {
@@ -152,8 +327,48 @@ impl ProcessesTabState {
     pub fn toggle_view(&mut self) {
         self.view = match self.view {
             ProcessesView::List => ProcessesView::Output,
-            ProcessesView::Output => ProcessesView::List,
+            _ => ProcessesView::List,
+        };
+        self.viewed_run = None;
+        self.error_cursor = None;
+    }
+
+    /// Show the crash list, or go back to the process list
+    pub fn toggle_crashes(&mut self, crashes: Vec<CrashReport>) {
+        self.view = match self.view {
+            ProcessesView::Crashes => ProcessesView::List,
+            _ => ProcessesView::Crashes,
+        };
+        self.crashes = crashes;
+        self.crash_index = 0;
+        self.crash_preview.clear();
+        self.crash_scroll = 0;
+    }
+
+    /// Path of the highlighted crash report
+    pub fn selected_crash(&self) -> Option<&CrashReport> {
+        self.crashes.get(self.crash_index)
+    }
+
+    /// Move the crash selection; returns true if it changed
+    pub fn select_crash(&mut self, delta: isize) -> bool {
+        let last = self.crashes.len().saturating_sub(1);
+        let index = self.crash_index.saturating_add_signed(delta).min(last);
+        let changed = index != self.crash_index;
+        self.crash_index = index;
+        if changed {
+            self.crash_scroll = 0;
+        }
+        changed
+    }
+
+    /// Toggle between the list and the inspector for the selected process
+    pub fn toggle_inspector(&mut self) {
+        self.view = match self.view {
+            ProcessesView::Inspector => ProcessesView::List,
+            _ => ProcessesView::Inspector,
         };
+        self.inspector_scroll = 0;
     }
 }
 
@@ -161,18 +376,125 @@ impl ProcessesTabState {
 // Logs Tab State
 // ============================================================================
 
+/// View mode for the Logs tab
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogsView {
+    #[default]
+    Entries,
+    /// Error entries grouped by exception
+    Exceptions,
+    /// Entries of the exception group being drilled into
+    Occurrences,
+}
+
 /// State for the Logs tab
 #[derive(Debug, Default)]
 pub struct LogsTabState {
+    pub view: LogsView,
     pub search_query: String,
     pub filter_level: Option<LogLevel>,
     pub scroll_offset: usize,
     pub input_mode: bool,
     pub selected_file: Option<String>,
     pub available_files: Vec<String>,
+    /// Show stack traces and context under every entry instead of a line count
+    pub expanded: bool,
+    /// Entries expanded or collapsed on their own, against `expanded`
+    toggled: HashSet<u64>,
+    /// Find mode highlights matches in place; filter mode hides other entries
+    pub find_mode: bool,
+    /// Treat the query as a regular expression instead of plain text
+    pub regex: bool,
+    pub case_sensitive: bool,
+    /// Compiled search query, rebuilt by `update_search`
+    search: Option<Result<Regex, regex::Error>>,
+    /// Id of the selected entry, moved with j/k and n/N; `None` follows the tail
+    pub selected_entry: Option<u64>,
+    /// Show the selected entry in the detail pane instead of the list
+    pub detail_open: bool,
+    pub detail_scroll: u16,
+    /// Highlighted `file:line` reference of the detail pane
+    pub detail_ref: usize,
+    /// Rows of the log list shown at the last draw
+    pub view_rows: usize,
+    /// Furthest the detail pane could scroll at the last draw
+    pub detail_max_scroll: u16,
+    pub exception_sort: ExceptionSort,
+    /// Highlighted row of the exception list
+    pub exception_index: usize,
+    /// Group shown in the occurrences view, by its stable index
+    pub open_exception: Option<usize>,
+    pub occurrence_index: usize,
+    pub time_filter: TimeFilter,
+    /// Custom time range being typed, `FROM..TO`
+    pub range_input: Option<String>,
+    /// Show entry times as `3m ago`
+    pub relative_times: bool,
 }
 
 impl LogsTabState {
+    /// Whether an entry shows its trace
+    pub fn is_expanded(&self, log: &LogLine) -> bool {
+        self.expanded != self.toggled.contains(&log.id)
+    }
+
+    /// Expand or collapse one entry's trace
+    pub fn toggle_expanded(&mut self, id: u64) {
+        if !self.toggled.remove(&id) {
+            self.toggled.insert(id);
+        }
+    }
+
+    /// Expand or collapse every entry's trace
+    pub fn toggle_expand_all(&mut self) {
+        self.expanded = !self.expanded;
+        self.toggled.clear();
+    }
+
+    /// Show the exception groups, or go back to the entries
+    pub fn toggle_exceptions(&mut self) {
+        self.view = match self.view {
+            LogsView::Entries => LogsView::Exceptions,
+            _ => LogsView::Entries,
+        };
+        self.exception_index = 0;
+    }
+
+    /// Compiled search query, `None` when there is no query
+    pub fn search_pattern(&self) -> Option<&Result<Regex, regex::Error>> {
+        self.search.as_ref()
+    }
+
+    /// Recompile the search query after it or its options changed
+    pub fn update_search(&mut self) {
+        if self.search_query.is_empty() {
+            self.search = None;
+            return;
+        }
+        let pattern = if self.regex {
+            self.search_query.clone()
+        } else {
+            regex::escape(&self.search_query)
+        };
+        self.search = Some(
+            RegexBuilder::new(&pattern)
+                .case_insensitive(!self.case_sensitive)
+                .build(),
+        );
+    }
+
+    /// Short description of the search options, e.g. `Find · regex · Aa`
+    pub fn search_options(&self) -> String {
+        let mut parts = vec![if self.find_mode { "Find" } else { "Filter" }];
+        if self.regex {
+            parts.push("regex");
+        }
+        if self.case_sensitive {
+            parts.push("Aa");
+        }
+        parts.join(" · ")
+    }
+
     pub fn cycle_filter(&mut self) {
         self.filter_level = match self.filter_level {
             None => Some(LogLevel::Debug),
@@ -301,22 +623,12 @@ impl ArtisanTabState {
         &self,
         user_args: &str,
         favorites: &[String],
-        is_sail: bool,
+        console: &ConsoleBinary,
     ) -> Option<ResolvedCommand> {
         let filtered = self.filtered_commands_with_favorites(favorites);
         let (cmd, _) = filtered.get(self.selected_command)?;
 
-        let (command, mut args) = if is_sail {
-            (
-                "./vendor/bin/sail".to_string(),
-                vec!["artisan".to_string(), cmd.name.clone()],
-            )
-        } else {
-            (
-                "php".to_string(),
-                vec!["artisan".to_string(), cmd.name.clone()],
-            )
-        };
+        let (command, mut args) = console.invocation(std::slice::from_ref(&cmd.name));
 
         if !user_args.is_empty() {
             for arg in user_args.split_whitespace() {
@@ -327,7 +639,7 @@ impl ArtisanTabState {
         args.push("--ansi".to_string());
 
         Some(ResolvedCommand {
-            display_name: format!("artisan {}", cmd.name),
+            display_name: format!("{} {}", console.name(), cmd.name),
             command,
             args,
         })
@@ -421,7 +733,7 @@ impl MakeTabState {
     pub fn current_command_display(
         &self,
         favorites: &[String],
-        is_sail: bool,
+        console: &ConsoleBinary,
     ) -> Vec<(String, String, bool)> {
         self.filtered_commands_with_favorites(favorites)
             .iter()
@@ -439,12 +751,7 @@ impl MakeTabState {
                         }
                     })
                     .unwrap_or_else(|| cmd.name.clone());
-                let full_command = if is_sail {
-                    format!("sail artisan {}", cmd.name)
-                } else {
-                    format!("php artisan {}", cmd.name)
-                };
-                (display_name, full_command, *is_fav)
+                (display_name, console.display(&cmd.name), *is_fav)
             })
             .collect()
     }
@@ -453,22 +760,12 @@ impl MakeTabState {
         &self,
         user_args: &str,
         favorites: &[String],
-        is_sail: bool,
+        console: &ConsoleBinary,
     ) -> Option<ResolvedCommand> {
         let filtered = self.filtered_commands_with_favorites(favorites);
         let (cmd, _) = filtered.get(self.selected_command)?;
 
-        let (command, mut args) = if is_sail {
-            (
-                "./vendor/bin/sail".to_string(),
-                vec!["artisan".to_string(), cmd.name.clone()],
-            )
-        } else {
-            (
-                "php".to_string(),
-                vec!["artisan".to_string(), cmd.name.clone()],
-            )
-        };
+        let (command, mut args) = console.invocation(std::slice::from_ref(&cmd.name));
 
         if !user_args.is_empty() {
             for arg in user_args.split_whitespace() {
@@ -625,7 +922,10 @@ impl QualityTabState {
 // Config Tab State
 // ============================================================================
 
-use crate::config::{CustomProcess, CustomTool, OverrideConfig, RestartPolicy};
+use crate::config::{
+    CustomProcess, CustomTool, LogSourceConfig, OutputConfig, OverrideConfig, RestartPolicy,
+    SailConfig,
+};
 
 /// Available configuration sections
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -806,6 +1106,8 @@ pub struct OverrideDraft {
     pub working_dir: String,
     pub env: Vec<(String, String)>,
     pub restart_policy: RestartPolicy,
+    /// Not editable in the Config tab; carried over on save
+    pub output: Option<OutputConfig>,
 }
 
 impl OverrideDraft {
@@ -820,17 +1122,13 @@ impl OverrideDraft {
                 .map(|e| e.into_iter().collect())
                 .unwrap_or_default(),
             restart_policy: cfg.restart_policy.unwrap_or_default(),
+            output: cfg.output.clone(),
         }
     }
 
     pub fn to_override(&self) -> Option<OverrideConfig> {
         // Only create override if something is actually set
-        if self.command.is_empty()
-            && self.args.is_empty()
-            && self.working_dir.is_empty()
-            && self.env.is_empty()
-            && self.restart_policy == RestartPolicy::Never
-        {
+        if self.is_empty() {
             return None;
         }
 
@@ -860,6 +1158,7 @@ impl OverrideDraft {
             } else {
                 Some(self.restart_policy)
             },
+            output: self.output.clone(),
         })
     }
 
@@ -869,6 +1168,7 @@ impl OverrideDraft {
             && self.working_dir.is_empty()
             && self.env.is_empty()
             && self.restart_policy == RestartPolicy::Never
+            && self.output.is_none()
     }
 }
 
@@ -884,6 +1184,8 @@ pub struct CustomProcessDraft {
     pub working_dir: String,
     pub env: Vec<(String, String)>,
     pub restart_policy: RestartPolicy,
+    /// Not editable in the Config tab; carried over on save
+    pub output: Option<OutputConfig>,
 }
 
 impl CustomProcessDraft {
@@ -902,6 +1204,7 @@ impl CustomProcessDraft {
                 .map(|e| e.into_iter().collect())
                 .unwrap_or_default(),
             restart_policy: cp.restart_policy.unwrap_or_default(),
+            output: cp.output.clone(),
         }
     }
 
@@ -932,6 +1235,7 @@ impl CustomProcessDraft {
             } else {
                 Some(self.restart_policy)
             },
+            output: self.output.clone(),
         }
     }
 
@@ -991,7 +1295,14 @@ impl CustomToolDraft {
 pub struct LogsDraft {
     pub max_lines: String,
     pub files: Vec<String>,
+    /// Not editable in the Config tab, carried through on save
+    pub exclude: Option<Vec<String>>,
     pub default_filter: String,
+    /// Not editable in the Config tab, carried through on save
+    pub timezone: Option<String>,
+    pub relative_times: Option<bool>,
+    pub formats: HashMap<String, String>,
+    pub sources: Vec<LogSourceConfig>,
 }
 
 /// Editable copy of quality config
@@ -1006,20 +1317,27 @@ pub struct QualityDraft {
 #[derive(Debug, Clone, Default)]
 pub struct ConfigDraft {
     pub sail: Option<bool>,
+    /// Sail options not editable in the Config tab, preserved on save
+    pub sail_settings: SailConfig,
     pub disabled: DisabledDraft,
     pub overrides: HashMap<String, OverrideDraft>,
     pub custom: Vec<CustomProcessDraft>,
     pub quality: QualityDraft,
     pub logs: LogsDraft,
     pub artisan_favorites: Vec<String>,
+    /// Not editable in the Config tab; carried over on save
+    pub console_binary: Option<String>,
     pub make_favorites: Vec<String>,
+    /// Not editable in the Config tab; carried over on save
+    pub editor: Option<String>,
 }
 
 impl ConfigDraft {
     pub fn from_config(config: Option<&LaramuxConfig>) -> Self {
         match config {
             Some(cfg) => Self {
-                sail: cfg.sail,
+                sail: cfg.sail.enabled,
+                sail_settings: cfg.sail.clone(),
                 disabled: DisabledDraft {
                     serve: cfg.disabled.serve,
                     vite: cfg.disabled.vite,
@@ -1059,10 +1377,17 @@ impl ConfigDraft {
                         .map(|n| n.to_string())
                         .unwrap_or_default(),
                     files: cfg.logs.files.clone().unwrap_or_default(),
+                    exclude: cfg.logs.exclude.clone(),
                     default_filter: cfg.logs.default_filter.clone().unwrap_or_default(),
+                    timezone: cfg.logs.timezone.clone(),
+                    relative_times: cfg.logs.relative_times,
+                    formats: cfg.logs.formats.clone(),
+                    sources: cfg.logs.sources.clone(),
                 },
                 artisan_favorites: cfg.artisan.favorites.clone(),
+                console_binary: cfg.artisan.console_binary.clone(),
                 make_favorites: cfg.make.favorites.clone(),
+                editor: cfg.editor.clone(),
             },
             None => Self::default(),
         }
@@ -1072,7 +1397,10 @@ impl ConfigDraft {
         use crate::config::{ArtisanConfig, DisabledConfig, LogConfig, MakeConfig, QualityConfig};
 
         LaramuxConfig {
-            sail: self.sail,
+            sail: SailConfig {
+                enabled: self.sail,
+                ..self.sail_settings.clone()
+            },
             disabled: DisabledConfig {
                 serve: self.disabled.serve,
                 vite: self.disabled.vite,
@@ -1108,18 +1436,25 @@ impl ConfigDraft {
                 } else {
                     Some(self.logs.files.clone())
                 },
+                exclude: self.logs.exclude.clone(),
                 default_filter: if self.logs.default_filter.is_empty() {
                     None
                 } else {
                     Some(self.logs.default_filter.clone())
                 },
+                timezone: self.logs.timezone.clone(),
+                relative_times: self.logs.relative_times,
+                formats: self.logs.formats.clone(),
+                sources: self.logs.sources.clone(),
             },
             artisan: ArtisanConfig {
                 favorites: self.artisan_favorites.clone(),
+                console_binary: self.console_binary.clone(),
             },
             make: MakeConfig {
                 favorites: self.make_favorites.clone(),
             },
+            editor: self.editor.clone(),
         }
     }
 
@@ -1156,6 +1491,19 @@ pub struct App {
     /// Whether Laravel Sail is detected (commands run through Docker)
     pub is_sail: bool,
 
+    /// Whether the project is a Laravel app
+    pub is_laravel: bool,
+
+    /// Whether any log files are watched; without them the Logs tab is hidden
+    pub has_log_sources: bool,
+
+    /// Whether supervisord can be controlled over RPC. Without it, stopping
+    /// and restarting a supervised process only detaches and reconnects.
+    pub has_supervisor: bool,
+
+    /// Console application behind the Artisan and Make tabs, hidden when absent
+    pub console: Option<ConsoleBinary>,
+
     /// Currently active tab
     pub active_tab: Tab,
 
@@ -1186,8 +1534,17 @@ pub struct App {
     /// Laravel log lines (ring buffer)
     pub log_lines: VecDeque<LogLine>,
 
+    /// Id for the next log entry
+    next_log_id: u64,
+    /// Error entries grouped by exception, for the Logs tab's exception view
+    pub exception_groups: ExceptionGroups,
+    /// Older entries paged in from disk
+    pub log_history: LogHistoryState,
+
     /// Maximum number of log lines to keep
     pub max_log_lines: usize,
+    /// Timezone of log timestamps without a zone
+    pub log_timezone: TimeZone,
 
     /// Working directory (Laravel project root)
     pub working_dir: PathBuf,
@@ -1198,6 +1555,12 @@ pub struct App {
     /// Status message to display
     pub status_message: Option<String>,
 
+    /// Highlighted format of the export menu, when it is open
+    pub export_menu: Option<usize>,
+
+    /// File reference to open in the editor, taken by the main loop
+    editor_request: Option<FileRef>,
+
     /// Process registry for metadata lookup
     pub registry: ProcessRegistry,
 
@@ -1209,12 +1572,22 @@ pub struct App {
 
     /// System resource statistics
     pub system_stats: SystemStats,
+
+    /// Last known state of the Sail container (None outside Sail mode)
+    pub sail_state: Option<ContainerState>,
+
+    /// PID of the laramux instance managing the processes, when this session only watches
+    pub attached_to: Option<u32>,
 }
 
 impl App {
     pub fn new(working_dir: PathBuf) -> Self {
         Self {
             is_sail: false,
+            is_laravel: true,
+            has_log_sources: false,
+            has_supervisor: false,
+            console: Some(ConsoleBinary::new(ARTISAN, false)),
             active_tab: Tab::default(),
             processes_tab: ProcessesTabState::default(),
             logs_tab: LogsTabState::default(),
@@ -1225,14 +1598,22 @@ impl App {
             processes: HashMap::new(),
             process_order: Vec::new(),
             log_lines: VecDeque::with_capacity(DEFAULT_MAX_LOG_LINES),
+            next_log_id: FIRST_LIVE_LOG_ID,
+            exception_groups: ExceptionGroups::default(),
+            log_history: LogHistoryState::default(),
             max_log_lines: DEFAULT_MAX_LOG_LINES,
+            log_timezone: TimeZone::UTC,
             working_dir,
             should_quit: false,
             status_message: None,
+            export_menu: None,
+            editor_request: None,
             registry: ProcessRegistry::new(),
             config: None,
             config_error: None,
             system_stats: SystemStats::default(),
+            sail_state: None,
+            attached_to: None,
         }
     }
 
@@ -1249,6 +1630,8 @@ impl App {
         if let Some(ref cfg) = config {
             // Set max log lines
             self.max_log_lines = cfg.log_max_lines();
+            self.log_timezone = cfg.log_timezone();
+            self.logs_tab.relative_times = cfg.log_relative_times();
 
             // Apply default log filter
             if let Some(filter) = cfg.default_log_filter() {
@@ -1331,13 +1714,131 @@ impl App {
     /// Add output to a process
     pub fn add_process_output(&mut self, id: &ProcessId, line: String, is_stderr: bool) {
         if let Some(process) = self.processes.get_mut(id) {
-            let output_line = if is_stderr {
+            let mut output_line = if is_stderr {
                 OutputLine::stderr(line)
             } else {
                 OutputLine::stdout(line)
             };
+            process.config.output_rules.apply(&mut output_line);
             process.add_output(output_line);
         }
+        // Output arriving in the open output view is read right away
+        if self.processes_tab.is_output_view() && self.selected_id() == Some(id) {
+            self.mark_selected_read();
+        }
+    }
+
+    /// Reset the selected process's unread error and warning counters
+    pub fn mark_selected_read(&mut self) {
+        if let Some(process) = self.selected_process_mut() {
+            process.mark_read();
+        }
+    }
+
+    /// Scroll the output view to the next (or previous) error line, leaving
+    /// its stack trace in view below it
+    pub fn jump_to_error(&mut self, forward: bool) {
+        let raw = self.processes_tab.raw_output;
+        let cursor = self.processes_tab.error_cursor;
+        let run_index = self.viewed_run_index().unwrap_or(usize::MAX);
+        let Some(process) = self.selected_process_mut() else {
+            return;
+        };
+
+        let visible: Vec<(u64, &OutputLine)> = process
+            .numbered_run_output(run_index)
+            .filter(|(_, line)| raw || !line.hidden)
+            .collect();
+        let errors: Vec<usize> = visible
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, line))| line.is_error && !line.in_trace)
+            .map(|(pos, _)| pos)
+            .collect();
+        if errors.is_empty() {
+            return;
+        }
+
+        let current = cursor.and_then(|c| errors.iter().position(|&pos| visible[pos].0 == c));
+        let next = match (current, forward) {
+            (Some(i), true) => (i + 1) % errors.len(),
+            (Some(i), false) => (i + errors.len() - 1) % errors.len(),
+            (None, true) => 0,
+            (None, false) => errors.len() - 1,
+        };
+        let pos = errors[next];
+        let trace_len = visible[pos + 1..]
+            .iter()
+            .take(MAX_JUMP_TRACE_LINES)
+            .take_while(|(_, line)| line.in_trace)
+            .count();
+        let number = visible[pos].0;
+        let scroll_offset = visible.len() - 1 - (pos + trace_len);
+
+        process.scroll_offset = scroll_offset;
+        self.processes_tab.error_cursor = Some(number);
+    }
+
+    /// Start a new output segment for a freshly spawned process
+    pub fn begin_process_run(&mut self, id: &ProcessId, pid: Option<u32>) {
+        if let Some(process) = self.processes.get_mut(id) {
+            process.begin_run(pid);
+            process.scroll_offset = 0;
+        }
+    }
+
+    /// Close the current run of a process, returning a copy of it
+    pub fn end_process_run(
+        &mut self,
+        id: &ProcessId,
+        exit: ExitReason,
+        requested: bool,
+    ) -> Option<RunRecord> {
+        self.processes
+            .get_mut(id)
+            .and_then(|p| p.end_run(exit, requested))
+            .map(|run| run.clone())
+    }
+
+    /// Attach a written crash report to a run
+    pub fn set_run_crash_report(&mut self, id: &ProcessId, number: u32, path: PathBuf) {
+        if let Some(run) = self
+            .processes
+            .get_mut(id)
+            .and_then(|p| p.runs.iter_mut().find(|r| r.number == number))
+        {
+            run.crash_report = Some(path);
+        }
+    }
+
+    /// Index of the run shown in the output view, if the process has any runs
+    pub fn viewed_run_index(&self) -> Option<usize> {
+        let process = self.selected_process()?;
+        match self.processes_tab.viewed_run {
+            Some(number) => process.runs.iter().position(|r| r.number == number),
+            None => process.runs.len().checked_sub(1),
+        }
+    }
+
+    /// Step the output view to an older (-1) or newer (+1) run
+    pub fn step_viewed_run(&mut self, delta: isize) {
+        let Some(current) = self.viewed_run_index() else {
+            return;
+        };
+        let Some(process) = self.selected_process() else {
+            return;
+        };
+        let last = process.runs.len() - 1;
+        let index = current.saturating_add_signed(delta).min(last);
+        self.processes_tab.viewed_run = if index == last {
+            None
+        } else {
+            Some(process.runs[index].number)
+        };
+        self.processes_tab.error_cursor = None;
+        if let Some(process) = self.selected_process_mut() {
+            process.scroll_offset = 0;
+        }
     }
 
     /// Update process status
@@ -1347,6 +1848,23 @@ impl App {
         }
     }
 
+    /// Update the restart state mirrored from the process manager
+    pub fn set_restart_state(&mut self, id: &ProcessId, state: Option<RestartState>) {
+        if let Some(process) = self.processes.get_mut(id) {
+            process.restart_state = state;
+        }
+    }
+
+    /// Attach supervisord's program states to the supervised processes
+    pub fn apply_supervisor_status(&mut self, infos: &[SupervisorInfo]) {
+        for process in self.processes.values_mut() {
+            let Some(program) = process.config.supervisor_program.as_deref() else {
+                continue;
+            };
+            process.supervisor = infos.iter().find(|i| i.group == program).cloned();
+        }
+    }
+
     /// Set process PID
     pub fn set_process_pid(&mut self, id: &ProcessId, pid: Option<u32>) {
         if let Some(process) = self.processes.get_mut(id) {
@@ -1355,7 +1873,7 @@ impl App {
     }
 
     /// Add log lines from Laravel log
-    pub fn add_log_lines(&mut self, entries: Vec<crate::log::LogEntry>) {
+    pub fn add_log_lines(&mut self, entries: Vec<LogEntry>) {
         for entry in entries {
             // Track available files
             if !self.logs_tab.available_files.contains(&entry.file) {
@@ -1363,38 +1881,273 @@ impl App {
                 self.logs_tab.available_files.sort();
             }
 
-            let level = Self::parse_log_level(&entry.content);
-            let log_line = LogLine {
-                content: entry.content,
-                level,
-                file: entry.file,
+            let (line, starts_entry) = parse_log_line(entry, &self.log_timezone);
+            let line = if starts_entry {
+                Some(line)
+            } else {
+                attach_continuation(self.log_lines.iter_mut().rev(), line)
+            };
+            if let Some(line) = line {
+                self.push_log_line(line);
+            }
+        }
+    }
+
+    /// Add a page of older entries above the loaded ones. Each file's page
+    /// comes in order; pages of different files are merged by timestamp.
+    pub fn prepend_log_history(&mut self, entries: Vec<LogEntry>) {
+        self.log_history.pending = false;
+        if entries.is_empty() {
+            self.log_history.exhausted = true;
+            self.set_status("Reached the start of the log files");
+            return;
+        }
+
+        let mut page: Vec<(i64, LogLine)> = Vec::new();
+        let mut file_start = 0;
+        for entry in entries {
+            let (line, starts_entry) = parse_log_line(entry, &self.log_timezone);
+            if page.last().is_some_and(|(_, last)| last.file != line.file) {
+                file_start = page.len();
+            }
+            let file_page = &mut page[file_start..];
+            let line = if starts_entry {
+                Some(line)
+            } else {
+                attach_continuation(file_page.iter_mut().rev().map(|(_, l)| l), line)
             };
+            if let Some(line) = line {
+                // Lines without a timestamp sort with the entry before them
+                let key = line
+                    .timestamp
+                    .or_else(|| file_page.last().map(|(key, _)| *key))
+                    .unwrap_or_default();
+                page.push((key, line));
+            }
+        }
+        page.sort_by_key(|(key, _)| *key);
+
+        // Ids count down from the oldest loaded entry, so they keep
+        // increasing along the buffer
+        let first_id = self
+            .log_lines
+            .front()
+            .map_or(self.next_log_id, |log| log.id);
+        let count = page.len();
+        for (i, (_, mut line)) in page.into_iter().enumerate().rev() {
+            line.id = first_id - (count - i) as u64;
+            let start = self
+                .log_history
+                .paged_start
+                .entry(line.file.clone())
+                .or_insert(line.offset);
+            *start = (*start).min(line.offset);
+            self.log_lines.push_front(line);
+        }
+        self.log_history.lines += count;
+    }
+
+    /// Ask for older entries when the Logs tab is scrolled to the top
+    fn check_log_history(&mut self) {
+        if self.log_history.pending || self.log_history.exhausted {
+            return;
+        }
+        let rows: usize = self
+            .filtered_logs()
+            .iter()
+            .map(|log| log.row_count(self.logs_tab.is_expanded(log)))
+            .sum();
+        if self.logs_tab.scroll_offset + self.logs_tab.view_rows < rows {
+            return;
+        }
+
+        let files = match &self.logs_tab.selected_file {
+            Some(file) => vec![file.clone()],
+            None => self.logs_tab.available_files.clone(),
+        };
+        let anchors = files
+            .into_iter()
+            .map(|file| {
+                let history = &self.log_history;
+                let offset = history
+                    .paged_start
+                    .get(&file)
+                    .or_else(|| history.tail_start.get(&file))
+                    .copied();
+                (file, offset)
+            })
+            .collect();
+        self.log_history.request = Some(HistoryRequest { anchors });
+        self.log_history.pending = true;
+    }
 
-            if self.log_lines.len() >= self.max_log_lines {
-                self.log_lines.pop_front();
+    /// Request for older log entries, to send to the log watcher
+    pub fn take_log_history_request(&mut self) -> Option<HistoryRequest> {
+        self.log_history.request.take()
+    }
+
+    fn push_log_line(&mut self, mut log_line: LogLine) {
+        // Paged-in history stays until the tail is followed again
+        if self.log_lines.len() - self.log_history.lines >= self.max_log_lines {
+            self.log_lines.remove(self.log_history.lines);
+        }
+        log_line.id = self.next_log_id;
+        self.next_log_id += 1;
+        self.log_history
+            .tail_start
+            .entry(log_line.file.clone())
+            .or_insert(log_line.offset);
+
+        if log_line.level.is_error() {
+            if let Some(exception) =
+                crate::log::detail::entry_exception(&log_line.content, &log_line.continuation)
+            {
+                self.exception_groups
+                    .record(log_line.id, &log_line.time, exception);
             }
-            self.log_lines.push_back(log_line);
         }
+
+        self.log_lines.push_back(log_line);
+    }
+
+    /// Log entry by id, if it is still in the buffer
+    fn log_by_id(&self, id: u64) -> Option<&LogLine> {
+        // Ids increase along the buffer
+        let index = self
+            .log_lines
+            .binary_search_by_key(&id, |log| log.id)
+            .ok()?;
+        self.log_lines.get(index)
+    }
+
+    /// Exception groups in the Logs tab's sort order, with their stable index
+    pub fn exception_rows(&self) -> Vec<(usize, &ExceptionGroup)> {
+        self.exception_groups.sorted(self.logs_tab.exception_sort)
+    }
+
+    /// Move the exception list selection
+    pub fn select_exception(&mut self, delta: isize) {
+        let last = self.exception_groups.len().saturating_sub(1);
+        let tab = &mut self.logs_tab;
+        tab.exception_index = tab.exception_index.saturating_add_signed(delta).min(last);
+    }
+
+    /// Drill into the highlighted exception group
+    pub fn open_exception_group(&mut self) {
+        let Some(&(index, _)) = self.exception_rows().get(self.logs_tab.exception_index) else {
+            return;
+        };
+        self.logs_tab.open_exception = Some(index);
+        self.logs_tab.occurrence_index = 0;
+        self.logs_tab.view = LogsView::Occurrences;
+    }
+
+    /// Entries of the open exception group still in the buffer, newest first
+    pub fn exception_occurrences(&self) -> Vec<&LogLine> {
+        let Some(group) = self
+            .logs_tab
+            .open_exception
+            .and_then(|index| self.exception_groups.get(index))
+        else {
+            return Vec::new();
+        };
+        group
+            .occurrences
+            .iter()
+            .rev()
+            .filter_map(|&id| self.log_by_id(id))
+            .collect()
+    }
+
+    /// Move the occurrence list selection
+    pub fn select_occurrence(&mut self, delta: isize) {
+        let last = self.exception_occurrences().len().saturating_sub(1);
+        let tab = &mut self.logs_tab;
+        tab.occurrence_index = tab.occurrence_index.saturating_add_signed(delta).min(last);
     }
 
-    /// Parse log level from a Laravel log line
-    fn parse_log_level(line: &str) -> LogLevel {
-        // Laravel log format: [YYYY-MM-DD HH:MM:SS] environment.LEVEL: message
-        // Example: [2024-01-26 10:30:45] local.INFO: Test message
-        if let Some(bracket_end) = line.find("] ") {
-            let after_bracket = &line[bracket_end + 2..];
-            if let Some(colon_pos) = after_bracket.find(':') {
-                let env_level = &after_bracket[..colon_pos];
-                if let Some(dot_pos) = env_level.rfind('.') {
-                    return LogLevel::from_str(&env_level[dot_pos + 1..]);
+    /// Open the highlighted occurrence in the detail pane
+    pub fn open_occurrence_detail(&mut self) {
+        let Some(id) = self
+            .exception_occurrences()
+            .get(self.logs_tab.occurrence_index)
+            .map(|log| log.id)
+        else {
+            return;
+        };
+        self.logs_tab.selected_entry = Some(id);
+        self.logs_tab.detail_open = true;
+        self.logs_tab.detail_scroll = 0;
+        self.logs_tab.detail_ref = 0;
+    }
+
+    /// Latest time any process was started or restarted on request, for the
+    /// time filter. Automatic restarts after a crash don't count, so a
+    /// crash-looping worker doesn't keep moving the window.
+    pub fn last_process_start(&self) -> Option<i64> {
+        self.processes
+            .values()
+            .filter_map(|process| {
+                let mut start = None;
+                let mut previous: Option<&RunRecord> = None;
+                for run in &process.runs {
+                    if previous.is_none_or(|p| p.requested) {
+                        start = Some(run.started_at);
+                    }
+                    previous = Some(run);
                 }
+                start
+            })
+            .filter_map(|started| started.duration_since(UNIX_EPOCH).ok())
+            .map(|since| since.as_secs() as i64)
+            .max()
+    }
+
+    /// Step through the time filter presets
+    pub fn cycle_time_filter(&mut self) {
+        let tab = &mut self.logs_tab;
+        tab.time_filter = tab.time_filter.next();
+        tab.selected_entry = None;
+        tab.scroll_offset = 0;
+        if tab.time_filter == TimeFilter::SinceRestart && self.last_process_start().is_none() {
+            self.set_status("No process has started yet; showing all entries");
+        }
+    }
+
+    /// Apply the typed custom range, e.g. `10:00..10:30` or `2h`. An empty
+    /// range shows all entries again.
+    pub fn apply_time_range(&mut self) {
+        let Some(input) = self.logs_tab.range_input.take() else {
+            return;
+        };
+        if input.trim().is_empty() {
+            self.logs_tab.time_filter = TimeFilter::All;
+            return;
+        }
+        match parse_range(&input, unix_now(), &self.log_timezone) {
+            Some(filter) => {
+                self.logs_tab.time_filter = filter;
+                self.logs_tab.selected_entry = None;
+                self.logs_tab.scroll_offset = 0;
             }
+            None => self.set_status(format!(
+                "Invalid time range '{}': use FROM..TO with dates, times or ages like 30m",
+                input
+            )),
         }
-        LogLevel::Unknown
     }
 
-    /// Get filtered log lines based on search query, filter level, and selected file
+    /// Get filtered log lines based on search query, filter level, time
+    /// filter and selected file
     pub fn filtered_logs(&self) -> Vec<&LogLine> {
+        let search = self
+            .logs_tab
+            .search_pattern()
+            .filter(|_| !self.logs_tab.find_mode);
+        let (from, to) = self
+            .logs_tab
+            .time_filter
+            .bounds(unix_now(), self.last_process_start());
         self.log_lines
             .iter()
             .filter(|log| {
@@ -1405,6 +2158,15 @@ impl App {
                     }
                 }
 
+                // Filter by time (entries without a timestamp always pass)
+                if let Some(timestamp) = log.timestamp {
+                    if from.is_some_and(|from| timestamp < from)
+                        || to.is_some_and(|to| timestamp > to)
+                    {
+                        return false;
+                    }
+                }
+
                 // Filter by level (Unknown logs always pass the level filter)
                 if let Some(min_level) = self.logs_tab.filter_level {
                     if log.level != LogLevel::Unknown {
@@ -1427,10 +2189,9 @@ impl App {
                     }
                 }
 
-                // Filter by search query
-                if !self.logs_tab.search_query.is_empty() {
-                    let query = self.logs_tab.search_query.to_lowercase();
-                    if !log.content.to_lowercase().contains(&query) {
+                // Filter by search query (find mode only highlights)
+                if let Some(Ok(pattern)) = search {
+                    if !log.is_match(pattern) {
                         return false;
                     }
                 }
@@ -1440,10 +2201,186 @@ impl App {
             .collect()
     }
 
+    /// Scroll to the next (or previous) entry matching the find query,
+    /// keeping a few rows below it in view
+    pub fn jump_to_log_match(&mut self, forward: bool) {
+        let Some(Ok(pattern)) = self.logs_tab.search_pattern().cloned() else {
+            return;
+        };
+        let cursor = self.logs_tab.selected_entry;
+        let visible = self.filtered_logs();
+        let matches: Vec<usize> = visible
+            .iter()
+            .enumerate()
+            .filter(|(_, log)| log.is_match(&pattern))
+            .map(|(pos, _)| pos)
+            .collect();
+        if matches.is_empty() {
+            return;
+        }
+
+        let current = cursor.and_then(|id| matches.iter().position(|&pos| visible[pos].id == id));
+        let next = match (current, forward) {
+            (Some(i), true) => (i + 1) % matches.len(),
+            (Some(i), false) => (i + matches.len() - 1) % matches.len(),
+            (None, true) => 0,
+            (None, false) => matches.len() - 1,
+        };
+        let pos = matches[next];
+        let rows_below = Self::log_rows_below(&visible, pos, &self.logs_tab);
+        let id = visible[pos].id;
+
+        self.logs_tab.scroll_offset = rows_below.saturating_sub(LOG_MATCH_CONTEXT_ROWS);
+        self.logs_tab.selected_entry = Some(id);
+    }
+
+    /// Rows from an entry's header to the bottom of the filtered list,
+    /// i.e. the scroll offset that puts the header on the last row
+    fn log_rows_below(visible: &[&LogLine], pos: usize, tab: &LogsTabState) -> usize {
+        visible[pos..]
+            .iter()
+            .map(|log| log.row_count(tab.is_expanded(log)))
+            .sum::<usize>()
+            - 1
+    }
+
+    /// Move the log selection by `delta` entries, scrolling to keep it in view.
+    /// Moving up without a selection starts at the newest entry.
+    pub fn select_log_entry(&mut self, delta: isize) {
+        let selected = self.logs_tab.selected_entry;
+        let visible = self.filtered_logs();
+        if visible.is_empty() {
+            return;
+        }
+
+        let current = selected.and_then(|id| visible.iter().position(|log| log.id == id));
+        let pos = match current {
+            Some(pos) => pos.saturating_add_signed(delta).min(visible.len() - 1),
+            None if delta < 0 => visible.len() - 1,
+            None => return,
+        };
+        let rows_below = Self::log_rows_below(&visible, pos, &self.logs_tab);
+        let id = visible[pos].id;
+
+        let view_rows = self.logs_tab.view_rows.max(1);
+        let offset = &mut self.logs_tab.scroll_offset;
+        if rows_below < *offset {
+            *offset = rows_below;
+        } else if rows_below >= *offset + view_rows {
+            *offset = rows_below + 1 - view_rows;
+        }
+        self.logs_tab.selected_entry = Some(id);
+        if pos == 0 {
+            self.check_log_history();
+        }
+    }
+
+    /// The selected log entry, if it is still in the buffer
+    pub fn selected_log(&self) -> Option<&LogLine> {
+        self.log_by_id(self.logs_tab.selected_entry?)
+    }
+
+    /// Open the detail pane for the selected entry, or the newest one
+    pub fn open_log_detail(&mut self) {
+        if self.selected_log().is_none() {
+            self.logs_tab.selected_entry = None;
+            self.select_log_entry(-1);
+        }
+        if self.selected_log().is_some() {
+            self.logs_tab.detail_open = true;
+            self.logs_tab.detail_scroll = 0;
+            self.logs_tab.detail_ref = 0;
+        }
+    }
+
+    /// `file:line` references in the entry shown in the detail pane
+    pub fn detail_file_refs(&self) -> Vec<FileRef> {
+        let Some(log) = self.selected_log() else {
+            return Vec::new();
+        };
+        let lines = std::iter::once(&log.content).chain(&log.continuation);
+        find_file_refs(lines.map(String::as_str), &self.working_dir)
+    }
+
+    /// Move the highlighted reference of the detail pane
+    pub fn select_detail_ref(&mut self, delta: isize) {
+        let last = self.detail_file_refs().len().saturating_sub(1);
+        let tab = &mut self.logs_tab;
+        tab.detail_ref = tab.detail_ref.saturating_add_signed(delta).min(last);
+    }
+
+    /// Open the highlighted reference of the detail pane in the editor
+    pub fn open_detail_ref(&mut self) {
+        match self
+            .detail_file_refs()
+            .into_iter()
+            .nth(self.logs_tab.detail_ref)
+        {
+            Some(file_ref) => self.editor_request = Some(file_ref),
+            None => self.set_status("No file:line reference in this entry"),
+        }
+    }
+
+    /// Open the first reference of the error selected with n/N in the
+    /// output view, or the most recent reference in the output
+    pub fn open_output_file_ref(&mut self) {
+        let raw = self.processes_tab.raw_output;
+        let cursor = self.processes_tab.error_cursor;
+        let run_index = self.viewed_run_index().unwrap_or(usize::MAX);
+        let Some(process) = self.selected_process() else {
+            return;
+        };
+
+        let visible: Vec<(u64, &OutputLine)> = process
+            .numbered_run_output(run_index)
+            .filter(|(_, line)| raw || !line.hidden)
+            .collect();
+        let error = cursor.and_then(|c| visible.iter().position(|(number, _)| *number == c));
+        let file_ref = match error {
+            Some(pos) => {
+                let trace = visible[pos + 1..]
+                    .iter()
+                    .take_while(|(_, line)| line.in_trace);
+                let lines = std::iter::once(&visible[pos]).chain(trace);
+                find_file_refs(
+                    lines.map(|(_, line)| line.content.as_str()),
+                    &self.working_dir,
+                )
+                .into_iter()
+                .next()
+            }
+            None => visible.iter().rev().find_map(|(_, line)| {
+                find_file_refs([line.content.as_str()], &self.working_dir)
+                    .into_iter()
+                    .next()
+            }),
+        };
+
+        match file_ref {
+            Some(file_ref) => self.editor_request = Some(file_ref),
+            None => self.set_status("No file:line reference in the output"),
+        }
+    }
+
+    /// Reference to open in the editor, for the main loop
+    pub fn take_editor_request(&mut self) -> Option<FileRef> {
+        self.editor_request.take()
+    }
+
     /// Clear all log lines
     pub fn clear_logs(&mut self) {
         self.log_lines.clear();
+        self.log_history = LogHistoryState::default();
+        self.logs_tab.toggled.clear();
         self.logs_tab.scroll_offset = 0;
+        self.logs_tab.selected_entry = None;
+        self.logs_tab.detail_open = false;
+        self.exception_groups.clear();
+        self.logs_tab.exception_index = 0;
+        self.logs_tab.open_exception = None;
+        if self.logs_tab.view == LogsView::Occurrences {
+            self.logs_tab.view = LogsView::Exceptions;
+        }
     }
 
     /// Clear output for the selected process
@@ -1451,6 +2388,7 @@ impl App {
         if let Some(process) = self.selected_process_mut() {
             process.clear_output();
         }
+        self.processes_tab.error_cursor = None;
     }
 
     /// Set a status message
@@ -1468,6 +2406,15 @@ impl App {
         self.should_quit = true;
     }
 
+    /// Switch the output view between filtered and raw output
+    pub fn toggle_raw_output(&mut self) {
+        self.processes_tab.raw_output = !self.processes_tab.raw_output;
+        self.processes_tab.error_cursor = None;
+        if let Some(process) = self.selected_process_mut() {
+            process.scroll_offset = 0;
+        }
+    }
+
     /// Scroll selected process output up
     pub fn scroll_output_up(&mut self, amount: usize) {
         if let Some(process) = self.selected_process_mut() {
@@ -1485,6 +2432,35 @@ impl App {
     /// Scroll log pane up
     pub fn scroll_log_up(&mut self, amount: usize) {
         self.logs_tab.scroll_offset = self.logs_tab.scroll_offset.saturating_add(amount);
+        self.check_log_history();
+    }
+
+    /// Scroll to the oldest loaded entry, paging in older ones from disk
+    pub fn scroll_log_to_top(&mut self) {
+        self.logs_tab.scroll_offset = self
+            .filtered_logs()
+            .iter()
+            .map(|log| log.row_count(self.logs_tab.is_expanded(log)))
+            .sum();
+        self.check_log_history();
+    }
+
+    /// Follow new entries again, dropping the history paged in
+    pub fn scroll_log_to_bottom(&mut self) {
+        self.logs_tab.scroll_offset = 0;
+        self.logs_tab.selected_entry = None;
+        let history = std::mem::take(&mut self.log_history.lines);
+        self.log_lines.drain(..history);
+        self.log_history.paged_start.clear();
+        self.log_history.exhausted = false;
+    }
+
+    /// Expand or collapse the selected entry's trace
+    pub fn toggle_selected_expanded(&mut self) {
+        match self.logs_tab.selected_entry {
+            Some(id) => self.logs_tab.toggle_expanded(id),
+            None => self.set_status("Select an entry with j/k to expand it, or press z for all"),
+        }
     }
 
     /// Scroll log pane down
@@ -1493,15 +2469,212 @@ impl App {
     }
 
     // Tab navigation
+
+    /// Whether a tab applies to this project: Logs needs log sources, the
+    /// Artisan and Make tabs need a console application
+    pub fn tab_available(&self, tab: Tab) -> bool {
+        match tab {
+            Tab::Logs => self.has_log_sources,
+            Tab::Artisan | Tab::Make => self.console.is_some(),
+            _ => true,
+        }
+    }
+
+    /// Tabs shown for this project, in display order
+    pub fn visible_tabs(&self) -> Vec<Tab> {
+        Tab::all()
+            .iter()
+            .copied()
+            .filter(|tab| self.tab_available(*tab))
+            .collect()
+    }
+
+    /// Tab label, naming the Artisan tab after a non-Artisan console
+    pub fn tab_name(&self, tab: Tab) -> &'static str {
+        match (tab, &self.console) {
+            (Tab::Artisan, Some(console)) if !console.is_artisan() => "Console",
+            _ => tab.name(),
+        }
+    }
+
     pub fn next_tab(&mut self) {
-        self.active_tab = self.active_tab.next();
+        let mut tab = self.active_tab.next();
+        while !self.tab_available(tab) {
+            tab = tab.next();
+        }
+        self.active_tab = tab;
     }
 
     pub fn previous_tab(&mut self) {
-        self.active_tab = self.active_tab.previous();
+        let mut tab = self.active_tab.previous();
+        while !self.tab_available(tab) {
+            tab = tab.previous();
+        }
+        self.active_tab = tab;
     }
 
     pub fn go_to_tab(&mut self, tab: Tab) {
-        self.active_tab = tab;
+        if self.tab_available(tab) {
+            self.active_tab = tab;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::log::format::LogFormat;
+
+    fn app_with_logs(messages: &[&str]) -> App {
+        let mut app = App::new(PathBuf::from("."));
+        let entries = messages
+            .iter()
+            .enumerate()
+            .map(|(i, message)| LogEntry {
+                content: format!("[2024-01-15 10:30:0{}] local.ERROR: {}", i, message),
+                file: "laravel.log".to_string(),
+                offset: i as u64 * 100,
+                format: LogFormat::Laravel,
+            })
+            .collect();
+        app.add_log_lines(entries);
+        app
+    }
+
+    fn is_match(tab: &LogsTabState, text: &str) -> bool {
+        tab.search_pattern()
+            .unwrap()
+            .as_ref()
+            .unwrap()
+            .is_match(text)
+    }
+
+    #[test]
+    fn test_search_pattern() {
+        let mut tab = LogsTabState::default();
+        tab.update_search();
+        assert!(tab.search_pattern().is_none());
+
+        // Plain text is escaped and case-insensitive
+        tab.search_query = "a.b".to_string();
+        tab.update_search();
+        assert!(is_match(&tab, "A.B"));
+        assert!(!is_match(&tab, "axb"));
+
+        tab.regex = true;
+        tab.update_search();
+        assert!(is_match(&tab, "AXB"));
+
+        tab.case_sensitive = true;
+        tab.update_search();
+        assert!(is_match(&tab, "axb"));
+        assert!(!is_match(&tab, "AXB"));
+
+        // An unbalanced group is only an error as a regex
+        tab.search_query = "(".to_string();
+        tab.update_search();
+        assert!(tab.search_pattern().unwrap().is_err());
+        tab.regex = false;
+        tab.update_search();
+        assert!(is_match(&tab, "f("));
+    }
+
+    #[test]
+    fn test_jump_to_log_match_wraps() {
+        let mut app = app_with_logs(&["boom one", "quiet", "boom two"]);
+        app.logs_tab.find_mode = true;
+        app.logs_tab.search_query = "boom".to_string();
+        app.logs_tab.update_search();
+        let ids: Vec<u64> = app.filtered_logs().iter().map(|log| log.id).collect();
+        assert_eq!(ids.len(), 3);
+
+        // Starts at the most recent hit, skipping entries that don't match
+        app.jump_to_log_match(false);
+        assert_eq!(app.logs_tab.selected_entry, Some(ids[2]));
+        app.jump_to_log_match(false);
+        assert_eq!(app.logs_tab.selected_entry, Some(ids[0]));
+        app.jump_to_log_match(false);
+        assert_eq!(app.logs_tab.selected_entry, Some(ids[2]));
+        app.jump_to_log_match(true);
+        assert_eq!(app.logs_tab.selected_entry, Some(ids[0]));
+    }
+
+    #[test]
+    fn test_last_process_start_ignores_crash_restarts() {
+        use crate::process::types::ProcessKind;
+        use std::time::Duration;
+
+        let mut app = App::new(PathBuf::from("."));
+        assert_eq!(app.last_process_start(), None);
+
+        let config = ProcessConfig::new(ProcessKind::Queue, "php", PathBuf::from("."));
+        let id = config.id.clone();
+        app.register_process(config);
+        let process = app.processes.get_mut(&id).unwrap();
+        // Started, restarted on request, then crashed twice
+        let requested = [true, false, false, false];
+        for (i, requested) in requested.into_iter().enumerate() {
+            process.begin_run(None);
+            process.runs.back_mut().unwrap().started_at =
+                UNIX_EPOCH + Duration::from_secs(1_000 + i as u64 * 100);
+            process.end_run(ExitReason::Code(1), requested);
+        }
+        assert_eq!(app.last_process_start(), Some(1_100));
+    }
+
+    #[test]
+    fn test_log_history_anchors_survive_eviction() {
+        let mut app = app_with_logs(&["first", "second"]);
+        app.max_log_lines = 2;
+        let worker = (0..2)
+            .map(|i| LogEntry {
+                content: format!("[2024-01-15 10:31:0{}] local.INFO: job {}", i, i),
+                file: "worker.log".to_string(),
+                offset: 500 + i * 50,
+                format: LogFormat::Laravel,
+            })
+            .collect();
+        app.add_log_lines(worker);
+        assert!(app.log_lines.iter().all(|log| log.file == "worker.log"));
+
+        // The evicted file pages back from its first entry, not from the end
+        app.logs_tab.view_rows = 100;
+        app.scroll_log_up(1);
+        let request = app.take_log_history_request().unwrap();
+        assert_eq!(
+            request.anchors,
+            vec![
+                ("laravel.log".to_string(), Some(0)),
+                ("worker.log".to_string(), Some(500)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_expand_selected_entry() {
+        let mut app = app_with_logs(&["first", "second"]);
+        let ids: Vec<u64> = app.log_lines.iter().map(|log| log.id).collect();
+        let expanded = |app: &App| -> Vec<bool> {
+            app.log_lines
+                .iter()
+                .map(|log| app.logs_tab.is_expanded(log))
+                .collect()
+        };
+
+        // Without a selection nothing changes
+        app.toggle_selected_expanded();
+        assert_eq!(expanded(&app), vec![false, false]);
+
+        app.logs_tab.selected_entry = Some(ids[1]);
+        app.toggle_selected_expanded();
+        assert_eq!(expanded(&app), vec![false, true]);
+
+        // Expanding all resets single toggles; collapsing one still works
+        app.logs_tab.toggle_expand_all();
+        assert_eq!(expanded(&app), vec![true, true]);
+        app.toggle_selected_expanded();
+        assert_eq!(expanded(&app), vec![true, false]);
+        app.logs_tab.toggle_expand_all();
+        assert_eq!(expanded(&app), vec![false, false]);
     }
 }
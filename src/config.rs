@@ -1,15 +1,22 @@
 use std::collections::{HashMap, HashSet};
 use std::path::Path;
 
+use jiff::tz::TimeZone;
+use regex::Regex;
 use serde::Deserialize;
 
 use crate::error::{LaraMuxError, Result};
+use crate::log::format::{LogFormat, BUILTIN_FORMATS, FORMAT_GROUPS};
+use crate::log::sources::validate_glob;
+use crate::log::time::parse_timezone;
+use crate::process::output::HighlightStyle;
+use crate::process::types::ProcessKind;
 
 /// Reserved hotkeys that cannot be assigned to custom processes
-const RESERVED_HOTKEYS: &[char] = &['r', 'c'];
+const RESERVED_HOTKEYS: &[char] = &['r', 'c', 'i'];
 
 /// Configuration file name
-const CONFIG_FILE: &str = ".laramux.json";
+pub const CONFIG_FILE: &str = ".laramux.json";
 
 /// Default max log lines
 const DEFAULT_MAX_LOG_LINES: u32 = 100;
@@ -64,6 +71,32 @@ pub struct OverrideConfig {
     pub env: Option<HashMap<String, String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub restart_policy: Option<RestartPolicy>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<OutputConfig>,
+}
+
+/// Output filtering and highlighting rules for a process
+#[derive(Debug, Clone, Default, Deserialize, serde::Serialize)]
+pub struct OutputConfig {
+    /// Lines matching any of these regexes are hidden in the filtered view
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hide: Vec<String>,
+    /// Lines matching a rule are painted with its style (first match wins)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub highlight: Vec<HighlightRule>,
+    /// Regexes marking a line as an error, replacing the built-in heuristic
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub errors: Option<Vec<String>>,
+    /// Regexes marking a line as a warning
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warnings: Option<Vec<String>>,
+}
+
+/// A regex mapped to a highlight style
+#[derive(Debug, Clone, Deserialize, serde::Serialize)]
+pub struct HighlightRule {
+    pub pattern: String,
+    pub style: String,
 }
 
 /// Configuration for a custom process
@@ -84,6 +117,8 @@ pub struct CustomProcess {
     pub env: Option<HashMap<String, String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub restart_policy: Option<RestartPolicy>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<OutputConfig>,
 }
 
 fn is_true(b: &bool) -> bool {
@@ -122,6 +157,10 @@ pub struct ArtisanConfig {
     /// List of favorite artisan command names (e.g., "migrate:fresh", "cache:clear")
     #[serde(default)]
     pub favorites: Vec<String>,
+    /// Symfony Console script to list and run commands with, relative to the
+    /// project root (e.g., "bin/console"); detected when not set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub console_binary: Option<String>,
 }
 
 /// Make commands configuration
@@ -137,18 +176,141 @@ pub struct MakeConfig {
 pub struct LogConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_lines: Option<u32>,
+    /// Extra log files or glob patterns, relative to the project root
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub files: Option<Vec<String>>,
+    /// Glob patterns of log files to skip
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exclude: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_filter: Option<String>,
+    /// UTC offset of log timestamps that carry no zone, e.g. `+01:00`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /// Show entry times as `3m ago` on startup
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relative_times: Option<bool>,
+    /// User-defined formats: a regex with named groups, keyed by name
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub formats: HashMap<String, String>,
+    /// Log files read with a format other than the default
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sources: Vec<LogSourceConfig>,
+}
+
+/// A log file or glob pattern and the format its lines are in
+#[derive(Debug, Clone, Deserialize, serde::Serialize)]
+pub struct LogSourceConfig {
+    pub files: String,
+    /// A built-in format or a key of `logs.formats`
+    pub format: String,
+}
+
+/// Default seconds to wait for the Sail container to become healthy
+pub const DEFAULT_SAIL_HEALTH_TIMEOUT: u64 = 60;
+
+/// Sail configuration.
+///
+/// Accepts either a plain boolean (the original `"sail": true` form, which only
+/// overrides detection) or an object with the full set of options.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize)]
+pub struct SailConfig {
+    /// Override Sail auto-detection: None = auto-detect, Some(true) = force, Some(false) = disable
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Run `sail up -d` when the container is down: None = ask, Some(true) = always, Some(false) = never
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_up: Option<bool>,
+    /// Seconds to wait for the container to report healthy after `sail up -d`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_timeout: Option<u64>,
+    /// Map supervisor program names to built-in process kinds (e.g. "laravel-worker": "queue")
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub programs: HashMap<String, String>,
+    /// Offer to patch supervisord.conf so programs logging to /dev/stdout get their own log files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub patch_supervisor_logs: Option<bool>,
+}
+
+impl SailConfig {
+    /// Whether only the detection override is set (serialized back as a plain bool)
+    fn is_plain(&self) -> bool {
+        self.auto_up.is_none()
+            && self.health_timeout.is_none()
+            && self.programs.is_empty()
+            && self.patch_supervisor_logs.is_none()
+    }
+}
+
+impl<'de> Deserialize<'de> for SailConfig {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(deny_unknown_fields)]
+        struct SailObject {
+            #[serde(default)]
+            enabled: Option<bool>,
+            #[serde(default)]
+            auto_up: Option<bool>,
+            #[serde(default)]
+            health_timeout: Option<u64>,
+            #[serde(default)]
+            programs: HashMap<String, String>,
+            #[serde(default)]
+            patch_supervisor_logs: Option<bool>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum SailRepr {
+            Flag(bool),
+            Object(SailObject),
+        }
+
+        Ok(match SailRepr::deserialize(deserializer)? {
+            SailRepr::Flag(enabled) => SailConfig {
+                enabled: Some(enabled),
+                ..Default::default()
+            },
+            SailRepr::Object(obj) => SailConfig {
+                enabled: obj.enabled,
+                auto_up: obj.auto_up,
+                health_timeout: obj.health_timeout,
+                programs: obj.programs,
+                patch_supervisor_logs: obj.patch_supervisor_logs,
+            },
+        })
+    }
+}
+
+fn serialize_sail<S>(sail: &SailConfig, serializer: S) -> std::result::Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    use serde::Serialize;
+
+    match (sail.is_plain(), sail.enabled) {
+        (true, Some(enabled)) => serializer.serialize_bool(enabled),
+        _ => sail.serialize(serializer),
+    }
+}
+
+fn is_default_sail(s: &SailConfig) -> bool {
+    *s == SailConfig::default()
 }
 
 /// Main configuration structure
 #[derive(Debug, Default, Deserialize, serde::Serialize)]
 pub struct LaramuxConfig {
-    /// Override Sail auto-detection: None = auto-detect, Some(true) = force, Some(false) = disable
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub sail: Option<bool>,
+    /// Sail detection override and container lifecycle options
+    #[serde(
+        default,
+        skip_serializing_if = "is_default_sail",
+        serialize_with = "serialize_sail"
+    )]
+    pub sail: SailConfig,
     #[serde(default, skip_serializing_if = "is_default_disabled")]
     pub disabled: DisabledConfig,
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
@@ -163,6 +325,9 @@ pub struct LaramuxConfig {
     pub artisan: ArtisanConfig,
     #[serde(default, skip_serializing_if = "is_default_make")]
     pub make: MakeConfig,
+    /// Command template for opening `file:line` references
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub editor: Option<String>,
 }
 
 fn is_default_disabled(d: &DisabledConfig) -> bool {
@@ -174,11 +339,18 @@ fn is_default_quality(q: &QualityConfig) -> bool {
 }
 
 fn is_default_logs(l: &LogConfig) -> bool {
-    l.max_lines.is_none() && l.files.is_none() && l.default_filter.is_none()
+    l.max_lines.is_none()
+        && l.files.is_none()
+        && l.exclude.is_none()
+        && l.default_filter.is_none()
+        && l.timezone.is_none()
+        && l.relative_times.is_none()
+        && l.formats.is_empty()
+        && l.sources.is_empty()
 }
 
 fn is_default_artisan(a: &ArtisanConfig) -> bool {
-    a.favorites.is_empty()
+    a.favorites.is_empty() && a.console_binary.is_none()
 }
 
 fn is_default_make(m: &MakeConfig) -> bool {
@@ -293,6 +465,10 @@ impl LaramuxConfig {
             if let Some(ref env) = process.env {
                 Self::validate_env_keys(env, &format!("custom process '{}'", process.name))?;
             }
+
+            if let Some(ref output) = process.output {
+                Self::validate_output(output, &format!("custom.{}.output", process.name))?;
+            }
         }
 
         // Validate override configs
@@ -303,6 +479,9 @@ impl LaramuxConfig {
             if let Some(ref env) = override_cfg.env {
                 Self::validate_env_keys(env, &format!("override '{}'", name))?;
             }
+            if let Some(ref output) = override_cfg.output {
+                Self::validate_output(output, &format!("overrides.{}.output", name))?;
+            }
         }
 
         // Validate quality config
@@ -342,6 +521,20 @@ impl LaramuxConfig {
             }
         }
 
+        for (field, patterns) in [("files", &self.logs.files), ("exclude", &self.logs.exclude)] {
+            for (i, pattern) in patterns.iter().flatten().enumerate() {
+                if let Err(e) = validate_glob(pattern) {
+                    return Err(LaraMuxError::ConfigValidation(format!(
+                        "Invalid glob at logs.{}[{}]: '{}' ({})",
+                        field,
+                        i,
+                        pattern,
+                        e.kind()
+                    )));
+                }
+            }
+        }
+
         if let Some(ref filter) = self.logs.default_filter {
             let lower = filter.to_lowercase();
             if !VALID_LOG_LEVELS.contains(&lower.as_str()) {
@@ -353,6 +546,108 @@ impl LaramuxConfig {
             }
         }
 
+        if let Some(ref timezone) = self.logs.timezone {
+            if parse_timezone(timezone).is_none() {
+                return Err(LaraMuxError::ConfigValidation(format!(
+                    "logs.timezone must be 'UTC', an offset such as '+02:00' or a zone from the system's zoneinfo database such as 'Europe/Lisbon', got '{}'",
+                    timezone
+                )));
+            }
+        }
+
+        for (name, pattern) in &self.logs.formats {
+            if BUILTIN_FORMATS.contains(&name.as_str()) {
+                return Err(LaraMuxError::ConfigValidation(format!(
+                    "logs.formats.{} shadows a built-in format",
+                    name
+                )));
+            }
+            let regex = Regex::new(pattern).map_err(|e| {
+                LaraMuxError::ConfigValidation(format!(
+                    "Invalid regex at logs.formats.{}: {}",
+                    name, e
+                ))
+            })?;
+            if let Some(group) = regex
+                .capture_names()
+                .flatten()
+                .find(|group| !FORMAT_GROUPS.contains(group))
+            {
+                return Err(LaraMuxError::ConfigValidation(format!(
+                    "logs.formats.{} has unknown group '{}'. Valid groups: {}",
+                    name,
+                    group,
+                    FORMAT_GROUPS.join(", ")
+                )));
+            }
+        }
+
+        for (i, source) in self.logs.sources.iter().enumerate() {
+            if let Err(e) = validate_glob(&source.files) {
+                return Err(LaraMuxError::ConfigValidation(format!(
+                    "Invalid glob at logs.sources[{}].files: '{}' ({})",
+                    i,
+                    source.files,
+                    e.kind()
+                )));
+            }
+            if !BUILTIN_FORMATS.contains(&source.format.as_str())
+                && !self.logs.formats.contains_key(&source.format)
+            {
+                return Err(LaraMuxError::ConfigValidation(format!(
+                    "logs.sources[{}].format must be one of {} or a key of logs.formats, got '{}'",
+                    i,
+                    BUILTIN_FORMATS.join(", "),
+                    source.format
+                )));
+            }
+        }
+
+        // Validate sail config
+        if let Some(timeout) = self.sail.health_timeout {
+            if !(1..=600).contains(&timeout) {
+                return Err(LaraMuxError::ConfigValidation(format!(
+                    "sail.health_timeout must be between 1 and 600 seconds, got {}",
+                    timeout
+                )));
+            }
+        }
+
+        for (program, kind) in &self.sail.programs {
+            if ProcessKind::from_config_name(kind).is_none() {
+                let valid: Vec<&str> = ProcessKind::all().iter().map(|k| k.config_name()).collect();
+                return Err(LaraMuxError::ConfigValidation(format!(
+                    "sail.programs.{} must be one of {}, got '{}'",
+                    program,
+                    valid.join(", "),
+                    kind
+                )));
+            }
+        }
+
+        if let Some(ref console_binary) = self.artisan.console_binary {
+            let path = Path::new(console_binary);
+            let escapes_root = path.is_absolute()
+                || path
+                    .components()
+                    .any(|c| matches!(c, std::path::Component::ParentDir));
+            if console_binary.is_empty() || escapes_root {
+                return Err(LaraMuxError::ConfigValidation(format!(
+                    "artisan.console_binary must be a path inside the project, got '{}'",
+                    console_binary
+                )));
+            }
+        }
+
+        if let Some(ref editor) = self.editor {
+            if !editor.contains("{file}") {
+                return Err(LaraMuxError::ConfigValidation(format!(
+                    "editor must contain a {{file}} placeholder, got '{}'",
+                    editor
+                )));
+            }
+        }
+
         // Validate artisan favorites (no empty strings, no duplicates)
         let mut seen = HashSet::new();
         for fav in &self.artisan.favorites {
@@ -432,6 +727,40 @@ impl LaramuxConfig {
         Ok(())
     }
 
+    /// Validate output rules: every pattern must compile and styles must be known
+    fn validate_output(output: &OutputConfig, context: &str) -> Result<()> {
+        let check_pattern = |pattern: &str, key: String| {
+            regex::Regex::new(pattern).map(|_| ()).map_err(|e| {
+                LaraMuxError::ConfigValidation(format!(
+                    "Invalid regex at {}: '{}' ({})",
+                    key, pattern, e
+                ))
+            })
+        };
+
+        for (i, pattern) in output.hide.iter().enumerate() {
+            check_pattern(pattern, format!("{}.hide[{}]", context, i))?;
+        }
+        for (i, rule) in output.highlight.iter().enumerate() {
+            check_pattern(&rule.pattern, format!("{}.highlight[{}]", context, i))?;
+            if HighlightStyle::from_name(&rule.style).is_none() {
+                return Err(LaraMuxError::ConfigValidation(format!(
+                    "Invalid style '{}' at {}.highlight[{}]. Valid styles: {}",
+                    rule.style,
+                    context,
+                    i,
+                    HighlightStyle::NAMES.join(", ")
+                )));
+            }
+        }
+        for (field, patterns) in [("errors", &output.errors), ("warnings", &output.warnings)] {
+            for (i, pattern) in patterns.iter().flatten().enumerate() {
+                check_pattern(pattern, format!("{}.{}[{}]", context, field, i))?;
+            }
+        }
+        Ok(())
+    }
+
     /// Check if a built-in process is disabled
     pub fn is_disabled(&self, name: &str) -> bool {
         match name.to_lowercase().as_str() {
@@ -459,16 +788,50 @@ impl LaramuxConfig {
         self.logs.max_lines.unwrap_or(DEFAULT_MAX_LOG_LINES) as usize
     }
 
-    /// Get additional log files to watch
+    /// Get additional log files or glob patterns to watch
     pub fn additional_log_files(&self) -> &[String] {
         self.logs.files.as_deref().unwrap_or(&[])
     }
 
+    /// Get glob patterns of log files to skip
+    pub fn log_exclude(&self) -> &[String] {
+        self.logs.exclude.as_deref().unwrap_or(&[])
+    }
+
     /// Get the default log filter level
     pub fn default_log_filter(&self) -> Option<&str> {
         self.logs.default_filter.as_deref()
     }
 
+    /// Timezone of log timestamps without a zone, UTC by default
+    pub fn log_timezone(&self) -> TimeZone {
+        self.logs
+            .timezone
+            .as_deref()
+            .and_then(parse_timezone)
+            .unwrap_or(TimeZone::UTC)
+    }
+
+    /// Whether log entry times start out relative
+    pub fn log_relative_times(&self) -> bool {
+        self.logs.relative_times.unwrap_or(false)
+    }
+
+    /// Log file patterns with their configured format, in config order
+    pub fn log_source_formats(&self) -> Vec<(String, LogFormat)> {
+        self.logs
+            .sources
+            .iter()
+            .filter_map(|source| {
+                let format = LogFormat::builtin(&source.format).or_else(|| {
+                    let pattern = self.logs.formats.get(&source.format)?;
+                    LogFormat::custom(&source.format, pattern).ok()
+                })?;
+                Some((source.files.clone(), format))
+            })
+            .collect()
+    }
+
     /// Check if a quality/testing tool is disabled
     pub fn is_tool_disabled(&self, name: &str) -> bool {
         let name_lower = name.to_lowercase();
@@ -499,11 +862,46 @@ impl LaramuxConfig {
             .filter(|t| t.category == "testing")
     }
 
+    /// Get the Sail detection override (None = auto-detect)
+    pub fn sail_override(&self) -> Option<bool> {
+        self.sail.enabled
+    }
+
+    /// Get the seconds to wait for the Sail container to become healthy
+    pub fn sail_health_timeout(&self) -> u64 {
+        self.sail
+            .health_timeout
+            .unwrap_or(DEFAULT_SAIL_HEALTH_TIMEOUT)
+    }
+
+    /// Get the built-in kind a supervisor program is explicitly mapped to
+    pub fn sail_program_kind(&self, program: &str) -> Option<ProcessKind> {
+        self.sail
+            .programs
+            .get(program)
+            .and_then(|kind| ProcessKind::from_config_name(kind))
+    }
+
+    /// Whether laramux may patch the container's supervisor config for per-program logs
+    pub fn sail_patch_supervisor_logs(&self) -> bool {
+        self.sail.patch_supervisor_logs.unwrap_or(false)
+    }
+
     /// Get favorite artisan command names
     pub fn artisan_favorites(&self) -> &[String] {
         &self.artisan.favorites
     }
 
+    /// Configured console script for the Artisan and Make tabs
+    pub fn console_binary(&self) -> Option<&str> {
+        self.artisan.console_binary.as_deref()
+    }
+
+    /// Command template for opening `file:line` references in an editor
+    pub fn editor(&self) -> Option<&str> {
+        self.editor.as_deref()
+    }
+
     /// Check if an artisan command is a favorite
     #[allow(dead_code)]
     pub fn is_artisan_favorite(&self, command: &str) -> bool {
@@ -641,6 +1039,18 @@ mod tests {
         );
         let result = LaramuxConfig::load(dir.path());
         assert!(result.is_err());
+
+        // The process inspector's key
+        write_config(
+            dir.path(),
+            r#"{
+                "custom": [
+                    {"name": "test", "display_name": "Test", "command": "echo", "hotkey": "i"}
+                ]
+            }"#,
+        );
+        let result = LaramuxConfig::load(dir.path());
+        assert!(result.unwrap_err().to_string().contains("reserved"));
     }
 
     #[test]
@@ -910,6 +1320,70 @@ mod tests {
         assert!(result.unwrap_err().to_string().contains("valid log level"));
     }
 
+    #[test]
+    fn test_log_timezone() {
+        let dir = TempDir::new().unwrap();
+        write_config(dir.path(), r#"{"logs": {"timezone": "-05:00"}}"#);
+        let config = LaramuxConfig::load(dir.path()).unwrap().unwrap();
+        assert_eq!(config.log_timezone(), TimeZone::fixed(jiff::tz::offset(-5)));
+
+        write_config(dir.path(), r#"{"logs": {"timezone": "Mars/Olympus_Mons"}}"#);
+        let result = LaramuxConfig::load(dir.path());
+        assert!(result.unwrap_err().to_string().contains("logs.timezone"));
+    }
+
+    #[test]
+    fn test_log_sources() {
+        let dir = TempDir::new().unwrap();
+        write_config(
+            dir.path(),
+            r#"{"logs": {
+                "formats": {"vite": "^(?P<level>\\w+) (?P<message>.*)$"},
+                "sources": [
+                    {"files": "storage/logs/nginx/*.log", "format": "nginx"},
+                    {"files": "storage/logs/vite.log", "format": "vite"}
+                ]
+            }}"#,
+        );
+        let config = LaramuxConfig::load(dir.path()).unwrap().unwrap();
+        let formats: Vec<_> = config
+            .log_source_formats()
+            .into_iter()
+            .map(|(files, format)| (files, format.name().to_string()))
+            .collect();
+        assert_eq!(
+            formats,
+            vec![
+                ("storage/logs/nginx/*.log".to_string(), "nginx".to_string()),
+                ("storage/logs/vite.log".to_string(), "vite".to_string()),
+            ]
+        );
+
+        write_config(
+            dir.path(),
+            r#"{"logs": {"sources": [{"files": "a.log", "format": "apache"}]}}"#,
+        );
+        let result = LaramuxConfig::load(dir.path());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("logs.sources[0].format"));
+
+        write_config(
+            dir.path(),
+            r#"{"logs": {"formats": {"vite": "(?P<lvl>\\w+)"}}}"#,
+        );
+        let result = LaramuxConfig::load(dir.path());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("unknown group 'lvl'"));
+
+        write_config(dir.path(), r#"{"logs": {"formats": {"nginx": ".*"}}}"#);
+        let result = LaramuxConfig::load(dir.path());
+        assert!(result.unwrap_err().to_string().contains("shadows"));
+    }
+
     #[test]
     fn test_restart_policy_deserialization() {
         let dir = TempDir::new().unwrap();
@@ -938,6 +1412,98 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_output_rules_validation() {
+        let dir = TempDir::new().unwrap();
+        write_config(
+            dir.path(),
+            r#"{"overrides": {"vite": {"output": {
+                "hide": ["hmr update"],
+                "highlight": [{"pattern": "ready in", "style": "success"}],
+                "errors": ["\\[vite\\] Internal server error"]
+            }}}}"#,
+        );
+        let config = LaramuxConfig::load(dir.path()).unwrap().unwrap();
+        let output = config
+            .get_override("vite")
+            .unwrap()
+            .output
+            .as_ref()
+            .unwrap();
+        assert_eq!(output.hide, vec!["hmr update"]);
+        assert!(output.warnings.is_none());
+
+        write_config(
+            dir.path(),
+            r#"{"overrides": {"queue": {"output": {"hide": ["ok", "(unclosed"]}}}}"#,
+        );
+        let err = LaramuxConfig::load(dir.path()).unwrap_err().to_string();
+        assert!(err.contains("overrides.queue.output.hide[1]"));
+
+        write_config(
+            dir.path(),
+            r#"{"custom": [{"name": "ssr", "display_name": "SSR", "command": "node",
+                "output": {"highlight": [{"pattern": "x", "style": "purple"}]}}]}"#,
+        );
+        let err = LaramuxConfig::load(dir.path()).unwrap_err().to_string();
+        assert!(err.contains("Invalid style 'purple' at custom.ssr.output.highlight[0]"));
+    }
+
+    #[test]
+    fn test_sail_bool_config() {
+        let dir = TempDir::new().unwrap();
+        write_config(dir.path(), r#"{"sail": true}"#);
+        let config = LaramuxConfig::load(dir.path()).unwrap().unwrap();
+        assert_eq!(config.sail_override(), Some(true));
+        assert_eq!(config.sail.auto_up, None);
+        assert_eq!(config.sail_health_timeout(), 60);
+
+        // Plain overrides round-trip as a bool
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(json, r#"{"sail":true}"#);
+    }
+
+    #[test]
+    fn test_sail_object_config() {
+        let dir = TempDir::new().unwrap();
+        write_config(
+            dir.path(),
+            r#"{"sail": {"auto_up": true, "health_timeout": 120}}"#,
+        );
+        let config = LaramuxConfig::load(dir.path()).unwrap().unwrap();
+        assert_eq!(config.sail_override(), None);
+        assert_eq!(config.sail.auto_up, Some(true));
+        assert_eq!(config.sail_health_timeout(), 120);
+
+        write_config(dir.path(), r#"{"sail": {"health_timeout": 0}}"#);
+        let result = LaramuxConfig::load(dir.path());
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("health_timeout"));
+    }
+
+    #[test]
+    fn test_sail_program_mapping() {
+        let dir = TempDir::new().unwrap();
+        write_config(
+            dir.path(),
+            r#"{"sail": {"programs": {"laravel-worker": "queue"}}}"#,
+        );
+        let config = LaramuxConfig::load(dir.path()).unwrap().unwrap();
+        assert_eq!(
+            config.sail_program_kind("laravel-worker"),
+            Some(ProcessKind::Queue)
+        );
+        assert_eq!(config.sail_program_kind("scheduler"), None);
+
+        write_config(
+            dir.path(),
+            r#"{"sail": {"programs": {"scheduler": "cron"}}}"#,
+        );
+        let result = LaramuxConfig::load(dir.path());
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("sail.programs"));
+    }
+
     #[test]
     fn test_artisan_favorites() {
         let dir = TempDir::new().unwrap();
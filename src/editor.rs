@@ -0,0 +1,250 @@
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+use crate::process::supervisor::CONTAINER_PROJECT_ROOT;
+
+/// Editor used when neither `editor` nor `$VISUAL`/`$EDITOR` is set
+pub const DEFAULT_EDITOR: &str = "code -g {file}:{line}";
+
+/// Editors that run in the terminal, so laramux steps aside while they are open
+const TERMINAL_EDITORS: &[&str] = &[
+    "vi", "vim", "nvim", "nano", "micro", "hx", "helix", "kak", "joe", "ne", "mg", "pico",
+];
+
+/// GUI editors that open `{file}:{line}` given as one argument
+const FILE_LINE_EDITORS: &[&str] = &["subl", "sublime_text", "zed"];
+
+/// GUI editors that need `-g` to read `{file}:{line}`
+const GOTO_EDITORS: &[&str] = &["code", "code-insiders", "codium", "cursor", "windsurf"];
+
+/// A `file:line` reference found in a log entry or process output
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileRef {
+    /// Path on the host, with Sail container paths mapped to the project root
+    pub path: PathBuf,
+    pub line: u32,
+}
+
+impl FileRef {
+    /// Path relative to the project root when inside it, e.g. `app/Foo.php:42`
+    pub fn display(&self, working_dir: &Path) -> String {
+        let path = self.path.strip_prefix(working_dir).unwrap_or(&self.path);
+        format!("{}:{}", path.display(), self.line)
+    }
+}
+
+/// Matches `path/File.php:42`, `path/File.php(42)` (PHP stack frames) and
+/// `path/File.php on line 42` (PHP errors). The path needs a directory part.
+fn file_ref_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| {
+        Regex::new(
+            r"((?:\.{0,2}/)?(?:[\w.\-]+/)+[\w.\-]+\.[A-Za-z]\w*)(?::(\d+)|\((\d+)\)| on line (\d+))",
+        )
+        .unwrap()
+    })
+}
+
+/// File references in the lines, in order of appearance and without repeats
+pub fn find_file_refs<'a>(
+    lines: impl IntoIterator<Item = &'a str>,
+    working_dir: &Path,
+) -> Vec<FileRef> {
+    let mut refs: Vec<FileRef> = Vec::new();
+    for line in lines {
+        // JSON context escapes slashes in some formatters
+        let line = line.replace(r"\/", "/");
+        for caps in file_ref_regex().captures_iter(&line) {
+            let Some(number) = (2..=4).find_map(|i| caps.get(i)) else {
+                continue;
+            };
+            let Ok(number) = number.as_str().parse() else {
+                continue;
+            };
+            let file_ref = FileRef {
+                path: resolve_path(&caps[1], working_dir),
+                line: number,
+            };
+            if !refs.contains(&file_ref) {
+                refs.push(file_ref);
+            }
+        }
+    }
+    refs
+}
+
+/// Map a path from a log or process output to the host: Sail container
+/// paths and relative paths resolve against the project root
+fn resolve_path(path: &str, working_dir: &Path) -> PathBuf {
+    if let Some(rest) = path.strip_prefix(CONTAINER_PROJECT_ROOT) {
+        working_dir.join(rest)
+    } else if Path::new(path).is_absolute() {
+        PathBuf::from(path)
+    } else {
+        working_dir.join(path.trim_start_matches("./"))
+    }
+}
+
+/// A command that opens a file in the editor
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EditorCommand {
+    pub program: String,
+    pub args: Vec<String>,
+    /// Runs in the terminal rather than its own window
+    pub terminal: bool,
+}
+
+/// Build the editor command for a reference from a template such as
+/// `phpstorm --line {line} {file}`. Words are split on whitespace, and
+/// `$VAR` words expand to the variable's words. Without a template,
+/// `$VISUAL` or `$EDITOR` is used, then `code`.
+pub fn editor_command(template: Option<&str>, file_ref: &FileRef) -> Option<EditorCommand> {
+    let env = |name: &str| std::env::var(name).ok().filter(|v| !v.trim().is_empty());
+    let fallback = ["VISUAL", "EDITOR"]
+        .into_iter()
+        .find_map(|name| env(name).map(|value| env_editor_template(name, &value)));
+    let template = template
+        .map(String::from)
+        .or(fallback)
+        .unwrap_or_else(|| DEFAULT_EDITOR.to_string());
+
+    let file = file_ref.path.to_string_lossy();
+    let line = file_ref.line.to_string();
+    let mut words = template.split_whitespace().flat_map(|word| {
+        let expanded = match word.strip_prefix('$') {
+            Some(name) => env(name.trim_matches(['{', '}'])).unwrap_or_default(),
+            None => word.to_string(),
+        };
+        expanded
+            .split_whitespace()
+            .map(|w| w.replace("{file}", &file).replace("{line}", &line))
+            .collect::<Vec<_>>()
+    });
+
+    let program = words.next()?;
+    let args: Vec<String> = words.collect();
+    let name = Path::new(&program)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let terminal = TERMINAL_EDITORS.contains(&name.as_str())
+        || (name.starts_with("emacs") && args.iter().any(|a| a == "-nw" || a == "-t"));
+
+    Some(EditorCommand {
+        program,
+        args,
+        terminal,
+    })
+}
+
+/// Template for an editor named by `$VISUAL` or `$EDITOR`. Only terminal
+/// editors and emacs take `+{line}`; GUI editors get the line in their own
+/// syntax when it is known, or just the file.
+fn env_editor_template(var: &str, value: &str) -> String {
+    let program = value.split_whitespace().next().unwrap_or_default();
+    let name = Path::new(program)
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let args = if TERMINAL_EDITORS.contains(&name.as_str()) || name.starts_with("emacs") {
+        "+{line} {file}"
+    } else if GOTO_EDITORS.contains(&name.as_str()) {
+        "-g {file}:{line}"
+    } else if FILE_LINE_EDITORS.contains(&name.as_str()) {
+        "{file}:{line}"
+    } else {
+        "{file}"
+    };
+    format!("${} {}", var, args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_file_refs() {
+        let root = Path::new("/home/dev/shop");
+        let refs = find_file_refs(
+            [
+                r#"local.ERROR: boom {"exception":"[object] (RuntimeException(code: 0): boom at /var/www/html/app/Jobs/Sync.php:42)"#,
+                "#0 /var/www/html/vendor/laravel/framework/src/Illuminate/Bus/Dispatcher.php(128): App\\Jobs\\Sync->handle()",
+                "#1 /var/www/html/app/Jobs/Sync.php(42): run()",
+                "PHP Fatal error:  Uncaught Error in /srv/other/index.php on line 7",
+                "  at resources/js/app.js:15:3",
+                "GET /api/orders 200",
+            ],
+            root,
+        );
+        let shown: Vec<String> = refs.iter().map(|r| r.display(root)).collect();
+        assert_eq!(
+            shown,
+            vec![
+                "app/Jobs/Sync.php:42",
+                "vendor/laravel/framework/src/Illuminate/Bus/Dispatcher.php:128",
+                "/srv/other/index.php:7",
+                "resources/js/app.js:15",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_editor_command() {
+        let file_ref = FileRef {
+            path: PathBuf::from("/home/dev/shop/app/Foo.php"),
+            line: 42,
+        };
+        assert_eq!(
+            editor_command(Some("phpstorm --line {line} {file}"), &file_ref),
+            Some(EditorCommand {
+                program: "phpstorm".to_string(),
+                args: vec![
+                    "--line".to_string(),
+                    "42".to_string(),
+                    "/home/dev/shop/app/Foo.php".to_string()
+                ],
+                terminal: false,
+            })
+        );
+
+        let vim = editor_command(Some("/usr/bin/nvim +{line} {file}"), &file_ref).unwrap();
+        assert!(vim.terminal);
+        assert_eq!(vim.args[0], "+42");
+
+        let code = editor_command(Some(DEFAULT_EDITOR), &file_ref).unwrap();
+        assert_eq!(code.args, vec!["-g", "/home/dev/shop/app/Foo.php:42"]);
+    }
+
+    #[test]
+    fn test_editor_from_env() {
+        let file_ref = FileRef {
+            path: PathBuf::from("/home/dev/shop/app/Foo.php"),
+            line: 42,
+        };
+        std::env::remove_var("VISUAL");
+        std::env::set_var("EDITOR", "code --wait");
+        let code = editor_command(None, &file_ref).unwrap();
+        std::env::remove_var("EDITOR");
+        assert_eq!(code.program, "code");
+        assert_eq!(
+            code.args,
+            vec!["--wait", "-g", "/home/dev/shop/app/Foo.php:42"]
+        );
+
+        assert_eq!(
+            env_editor_template("EDITOR", "vim"),
+            "$EDITOR +{line} {file}"
+        );
+        assert_eq!(
+            env_editor_template("VISUAL", "emacsclient -c"),
+            "$VISUAL +{line} {file}"
+        );
+        assert_eq!(
+            env_editor_template("VISUAL", "subl -w"),
+            "$VISUAL {file}:{line}"
+        );
+        assert_eq!(env_editor_template("EDITOR", "gedit"), "$EDITOR {file}");
+    }
+}
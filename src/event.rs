@@ -4,7 +4,9 @@ use crossterm::event::KeyEvent;
 
 use crate::app::SystemStats;
 use crate::log::LogEntry;
-use crate::process::types::ProcessId;
+use crate::process::sail::ContainerState;
+use crate::process::supervisor::{SupervisorAction, SupervisorInfo};
+use crate::process::types::{ExitReason, ProcessId};
 
 /// Events that can occur in the application
 #[derive(Debug, Clone)]
@@ -19,10 +21,15 @@ pub enum Event {
         is_stderr: bool,
     },
 
+    /// A managed process was spawned
+    ProcessStarted { id: ProcessId, pid: Option<u32> },
+
     /// A managed process has exited
     ProcessExited {
         id: ProcessId,
-        exit_code: Option<i32>,
+        exit: ExitReason,
+        /// Killed by laramux (stop, restart, quit) rather than exiting on its own
+        requested: bool,
     },
 
     /// Request to auto-restart a process (after backoff delay)
@@ -31,6 +38,9 @@ pub enum Event {
     /// New content from log files
     LogUpdate(Vec<LogEntry>),
 
+    /// Older log entries paged in from disk, empty at the start of the files
+    LogHistory(Vec<LogEntry>),
+
     /// Terminal resize event
     Resize(u16, u16),
 
@@ -45,4 +55,26 @@ pub enum Event {
 
     /// System stats update
     SystemStatsUpdate(SystemStats),
+
+    /// The Sail application container changed state
+    SailStateChanged(ContainerState),
+
+    /// A terminal editor opened from a stack trace exited
+    EditorExited {
+        program: String,
+        result: std::result::Result<std::process::ExitStatus, String>,
+    },
+
+    /// A background `sail up -d` finished
+    SailUpFinished(std::result::Result<(), String>),
+
+    /// Latest program states reported by supervisord
+    SupervisorStatus(Vec<SupervisorInfo>),
+
+    /// A supervisor start/stop/restart finished
+    SupervisorActionFinished {
+        id: ProcessId,
+        action: SupervisorAction,
+        result: std::result::Result<(), String>,
+    },
 }
@@ -0,0 +1,202 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use serde_json::json;
+
+use crate::app::LogLine;
+use crate::error::Result;
+use crate::process::crash::format_utc;
+use crate::process::output::plain_text;
+use crate::process::types::OutputLine;
+
+/// Directory exports are written to, relative to the project root
+pub const EXPORT_DIR: &str = ".laramux/exports";
+
+/// File format of an export
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Text with ANSI escape codes stripped
+    Plain,
+    /// Text as received, colors included
+    Ansi,
+    /// One JSON object per line
+    JsonLines,
+}
+
+impl ExportFormat {
+    pub fn all() -> &'static [ExportFormat] {
+        &[
+            ExportFormat::Plain,
+            ExportFormat::Ansi,
+            ExportFormat::JsonLines,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ExportFormat::Plain => "Plain text",
+            ExportFormat::Ansi => "ANSI",
+            ExportFormat::JsonLines => "JSON lines",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            ExportFormat::Plain => "Colors stripped, for bug reports",
+            ExportFormat::Ansi => "Colors kept, view with less -R",
+            ExportFormat::JsonLines => "One object per line, for jq",
+        }
+    }
+
+    fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Plain => "log",
+            ExportFormat::Ansi => "ansi.log",
+            ExportFormat::JsonLines => "jsonl",
+        }
+    }
+}
+
+/// Render log entries, continuation lines included
+pub fn render_logs(logs: &[&LogLine], format: ExportFormat) -> String {
+    let mut out = String::new();
+    for log in logs {
+        match format {
+            ExportFormat::JsonLines => {
+                let record = json!({
+                    "timestamp": log.time,
+                    "level": log.level.name().to_lowercase(),
+                    "file": log.file,
+                    "content": plain_text(&log.content),
+                    "continuation": log.continuation.iter().map(|l| plain_text(l)).collect::<Vec<_>>(),
+                });
+                out.push_str(&record.to_string());
+                out.push('\n');
+            }
+            _ => {
+                for line in std::iter::once(&log.content).chain(&log.continuation) {
+                    out.push_str(&text(line, format));
+                    out.push('\n');
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Render process output lines
+pub fn render_output<'a>(
+    lines: impl IntoIterator<Item = &'a OutputLine>,
+    format: ExportFormat,
+) -> String {
+    let mut out = String::new();
+    for line in lines {
+        match format {
+            ExportFormat::JsonLines => {
+                let record = json!({
+                    "stream": if line.is_stderr { "stderr" } else { "stdout" },
+                    "error": line.is_error,
+                    "content": plain_text(&line.content),
+                });
+                out.push_str(&record.to_string());
+            }
+            _ => out.push_str(&text(&line.content, format)),
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn text(line: &str, format: ExportFormat) -> String {
+    match format {
+        ExportFormat::Ansi => line.to_string(),
+        _ => plain_text(line),
+    }
+}
+
+/// Write an export to `.laramux/exports/<name>-<timestamp>.<ext>`.
+/// Returns the path of the new file.
+pub fn write_export(
+    working_dir: &Path,
+    name: &str,
+    format: ExportFormat,
+    content: &str,
+) -> Result<PathBuf> {
+    let dir = working_dir.join(EXPORT_DIR);
+    fs::create_dir_all(&dir)?;
+
+    // 2024-01-15 10:30:45 UTC -> 20240115-103045
+    let stamp: String = format_utc(SystemTime::now())
+        .trim_end_matches(" UTC")
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            '-' | ':' => None,
+            c => Some(c),
+        })
+        .collect();
+    let name: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+
+    let path = dir.join(format!("{}-{}.{}", name, stamp, format.extension()));
+    fs::write(&path, content)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::LogLevel;
+    use crate::log::format::LogFormat;
+
+    #[test]
+    fn test_render_and_write_export() {
+        let log = LogLine {
+            id: 0,
+            content: "[2024-01-15 10:30:45] local.ERROR: \u{1b}[31mboom\u{1b}[0m".to_string(),
+            level: LogLevel::Error,
+            time: "2024-01-15 10:30:45".to_string(),
+            channel: Some("local".to_string()),
+            message: "\u{1b}[31mboom\u{1b}[0m".to_string(),
+            file: "laravel.log".to_string(),
+            format: LogFormat::Laravel,
+            offset: 0,
+            timestamp: None,
+            continuation: vec!["#0 {main}".to_string()],
+        };
+        assert_eq!(
+            render_logs(&[&log], ExportFormat::Plain),
+            "[2024-01-15 10:30:45] local.ERROR: boom\n#0 {main}\n"
+        );
+        assert!(render_logs(&[&log], ExportFormat::Ansi).contains("\u{1b}[31m"));
+
+        let json: serde_json::Value =
+            serde_json::from_str(render_logs(&[&log], ExportFormat::JsonLines).trim()).unwrap();
+        assert_eq!(json["timestamp"], "2024-01-15 10:30:45");
+        assert_eq!(json["level"], "error");
+        assert_eq!(json["continuation"][0], "#0 {main}");
+
+        let output = [
+            OutputLine::stdout("Server running".to_string()),
+            OutputLine::stderr("\u{1b}[31mFatal error\u{1b}[0m".to_string()),
+        ];
+        let jsonl = render_output(&output, ExportFormat::JsonLines);
+        let lines: Vec<serde_json::Value> = jsonl
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(lines[1]["stream"], "stderr");
+        assert_eq!(lines[1]["content"], "Fatal error");
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_export(dir.path(), "queue:work", ExportFormat::Plain, "x\n").unwrap();
+        assert!(path.starts_with(dir.path().join(EXPORT_DIR)));
+        let file_name = path.file_name().unwrap().to_string_lossy().to_string();
+        assert!(file_name.starts_with("queue-work-"));
+        assert!(file_name.ends_with(".log"));
+        assert_eq!(fs::read_to_string(path).unwrap(), "x\n");
+    }
+}
@@ -0,0 +1,213 @@
+use serde_json::{Map, Value};
+
+use crate::log::parser::is_stack_trace_line;
+
+/// An exception serialized into a Laravel log context:
+/// `[object] (Class(code: 0): message at /path/File.php:12)`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExceptionInfo {
+    pub class: String,
+    pub message: String,
+    /// `file:line` where it was thrown
+    pub location: Option<String>,
+}
+
+/// A log entry taken apart for the detail pane
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LogDetail {
+    pub message: String,
+    /// Context object, without the exception it is split out into
+    pub context: Option<Map<String, Value>>,
+    pub exception: Option<ExceptionInfo>,
+    pub trace: Vec<String>,
+    /// Continuation lines that are neither context nor stack trace
+    pub other: Vec<String>,
+}
+
+impl LogDetail {
+    /// Parse an entry's message (the header after `env.LEVEL:`) and
+    /// continuation lines. Laravel writes the context JSON right after the
+    /// message, with the exception's stack trace spanning the following lines.
+    pub fn parse(message: &str, continuation: &[String]) -> Self {
+        let text = std::iter::once(message)
+            .chain(continuation.iter().map(String::as_str))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let Some((start, end, mut context)) = find_context(&text) else {
+            return Self::without_context(message, continuation);
+        };
+
+        let mut detail = Self {
+            message: text[..start].trim_end().to_string(),
+            ..Self::default()
+        };
+        if let Some(Value::String(exception)) = context.remove("exception") {
+            let (summary, trace) = exception
+                .split_once("[stacktrace]")
+                .unwrap_or((&exception, ""));
+            detail.exception = parse_exception(summary.trim());
+            detail.trace = trace_lines(trace);
+        }
+        detail.context = Some(context).filter(|c| !c.is_empty());
+        detail.other = text[end..]
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && *line != "[]")
+            .map(String::from)
+            .collect();
+        detail
+    }
+
+    fn without_context(message: &str, continuation: &[String]) -> Self {
+        let (trace, other) = continuation
+            .iter()
+            .cloned()
+            .partition(|line| is_stack_trace_line(line));
+        Self {
+            message: message.to_string(),
+            trace,
+            other,
+            ..Self::default()
+        }
+    }
+}
+
+/// Exception of an entry, read from its header line so it is known as soon
+/// as the entry arrives: Laravel's `"exception":"[object] (...)"` context, or
+/// the `exception.*` fields of a Monolog JSON record
+pub fn entry_exception(content: &str, continuation: &[String]) -> Option<ExceptionInfo> {
+    let field = |name: &str| {
+        continuation
+            .iter()
+            .find_map(|line| line.strip_prefix(name)?.strip_prefix(": "))
+    };
+    if let Some(class) = field("exception.class") {
+        return Some(ExceptionInfo {
+            class: class.to_string(),
+            message: field("exception.message").unwrap_or_default().to_string(),
+            location: field("exception.file").map(String::from),
+        });
+    }
+
+    let start = content.find(r#""exception":""#)? + r#""exception":"#.len();
+    let json = &content[start..];
+    // The whole string when it ends on this line, otherwise the summary
+    // before the stack trace that starts on the next one
+    let summary = match serde_json::Deserializer::from_str(json)
+        .into_iter::<String>()
+        .next()
+    {
+        Some(Ok(exception)) => exception,
+        _ => json[1..]
+            .replace(r"\\", r"\")
+            .replace(r"\/", "/")
+            .replace(r#"\""#, "\""),
+    };
+    let summary = summary.split("[stacktrace]").next().unwrap_or_default();
+    parse_exception(summary.trim())
+}
+
+/// First `{...}` object in the text that parses as JSON, as (start, end, object).
+/// Newlines inside strings (multi-line stack traces) are accepted.
+fn find_context(text: &str) -> Option<(usize, usize, Map<String, Value>)> {
+    let escaped = text.replace('\n', "\\n");
+    for (start, _) in text.match_indices('{') {
+        // Offsets in `escaped` shift by one per newline before them
+        let shift = text[..start].matches('\n').count();
+        let mut values =
+            serde_json::Deserializer::from_str(&escaped[start + shift..]).into_iter::<Value>();
+        if let Some(Ok(Value::Object(object))) = values.next() {
+            let end = original_offset(text, start + shift + values.byte_offset());
+            return Some((start, end, object));
+        }
+    }
+    None
+}
+
+/// Map an offset in the newline-escaped text back to the original text
+fn original_offset(text: &str, escaped_offset: usize) -> usize {
+    let mut escaped = 0;
+    for (i, c) in text.char_indices() {
+        if escaped >= escaped_offset {
+            return i;
+        }
+        escaped += if c == '\n' { 2 } else { c.len_utf8() };
+    }
+    text.len()
+}
+
+/// `[object] (Class(code: 0): message at /path/File.php:12)`
+fn parse_exception(summary: &str) -> Option<ExceptionInfo> {
+    let inner = summary
+        .strip_prefix("[object] (")
+        .and_then(|s| s.strip_suffix(')'))
+        .unwrap_or(summary);
+    let (class, rest) = inner.split_once("(code: ")?;
+    let rest = rest.split_once("): ").map_or("", |(_, rest)| rest);
+    let (message, location) = match rest.rsplit_once(" at ") {
+        Some((message, location)) if location.contains(':') => {
+            (message, Some(location.to_string()))
+        }
+        _ => (rest, None),
+    };
+    Some(ExceptionInfo {
+        class: class.to_string(),
+        message: message.to_string(),
+        location,
+    })
+}
+
+fn trace_lines(trace: &str) -> Vec<String> {
+    trace
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_laravel_exception() {
+        let continuation = vec![
+            "[stacktrace]".to_string(),
+            "#0 /var/www/html/app/Http/Controllers/OrderController.php(42): App\\\\Models\\\\Order::create()".to_string(),
+            "#1 {main}".to_string(),
+            "\"} []".to_string(),
+        ];
+        let detail = LogDetail::parse(
+            r#"Order failed {"userId":7,"exception":"[object] (Illuminate\\Database\\QueryException(code: 42S02): SQLSTATE[42S02]: Base table not found at /var/www/html/vendor/laravel/framework/src/Illuminate/Database/Connection.php:825)"#,
+            &continuation,
+        );
+
+        assert_eq!(detail.message, "Order failed");
+        assert_eq!(
+            detail.exception,
+            Some(ExceptionInfo {
+                class: "Illuminate\\Database\\QueryException".to_string(),
+                message: "SQLSTATE[42S02]: Base table not found".to_string(),
+                location: Some(
+                    "/var/www/html/vendor/laravel/framework/src/Illuminate/Database/Connection.php:825"
+                        .to_string()
+                ),
+            })
+        );
+        assert_eq!(detail.trace.len(), 2);
+        assert!(detail.trace[0].starts_with("#0 /var/www/html/app/Http"));
+        assert_eq!(detail.context.unwrap().get("userId"), Some(&Value::from(7)));
+        assert!(detail.other.is_empty());
+
+        // No context: continuation lines are split into trace and the rest
+        let detail = LogDetail::parse(
+            "Something odd",
+            &["Stack trace:".to_string(), "see docs".to_string()],
+        );
+        assert!(detail.context.is_none());
+        assert_eq!(detail.trace, vec!["Stack trace:"]);
+        assert_eq!(detail.other, vec!["see docs"]);
+    }
+}
@@ -0,0 +1,162 @@
+use std::cmp::Reverse;
+use std::collections::VecDeque;
+
+use crate::log::detail::ExceptionInfo;
+
+/// Occurrence ids kept per group for drill-down
+const MAX_GROUP_OCCURRENCES: usize = 200;
+
+/// Order of the exception groups
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExceptionSort {
+    /// Most occurrences first
+    #[default]
+    Count,
+    /// Most recently seen first
+    Recent,
+}
+
+impl ExceptionSort {
+    pub fn toggle(self) -> Self {
+        match self {
+            ExceptionSort::Count => ExceptionSort::Recent,
+            ExceptionSort::Recent => ExceptionSort::Count,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ExceptionSort::Count => "Count",
+            ExceptionSort::Recent => "Recent",
+        }
+    }
+}
+
+/// Occurrences of one exception class thrown from one place
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExceptionGroup {
+    pub class: String,
+    /// `file:line` where it was thrown
+    pub location: Option<String>,
+    pub count: usize,
+    pub first_seen: String,
+    pub last_seen: String,
+    pub latest_message: String,
+    /// Log entry ids, oldest first; older ones are dropped past the cap
+    pub occurrences: VecDeque<u64>,
+}
+
+/// Error-level entries grouped by exception class and origin. Counts
+/// cover every entry seen, not just those still in the log buffer.
+#[derive(Debug, Default)]
+pub struct ExceptionGroups {
+    groups: Vec<ExceptionGroup>,
+}
+
+impl ExceptionGroups {
+    /// Add an entry's exception to its group
+    pub fn record(&mut self, id: u64, timestamp: &str, exception: ExceptionInfo) {
+        let group = match self
+            .groups
+            .iter_mut()
+            .position(|g| g.class == exception.class && g.location == exception.location)
+        {
+            Some(index) => &mut self.groups[index],
+            None => {
+                self.groups.push(ExceptionGroup {
+                    class: exception.class,
+                    location: exception.location,
+                    count: 0,
+                    first_seen: timestamp.to_string(),
+                    last_seen: String::new(),
+                    latest_message: String::new(),
+                    occurrences: VecDeque::new(),
+                });
+                self.groups.last_mut().unwrap()
+            }
+        };
+
+        group.count += 1;
+        group.last_seen = timestamp.to_string();
+        group.latest_message = exception.message;
+        if group.occurrences.len() >= MAX_GROUP_OCCURRENCES {
+            group.occurrences.pop_front();
+        }
+        group.occurrences.push_back(id);
+    }
+
+    /// Groups with their stable index, in the given order
+    pub fn sorted(&self, sort: ExceptionSort) -> Vec<(usize, &ExceptionGroup)> {
+        let mut groups: Vec<_> = self.groups.iter().enumerate().collect();
+        match sort {
+            ExceptionSort::Count => groups.sort_by_key(|(_, group)| Reverse(group.count)),
+            ExceptionSort::Recent => {
+                groups.sort_by_key(|(_, group)| Reverse(group.occurrences.back().copied()))
+            }
+        }
+        groups
+    }
+
+    pub fn get(&self, index: usize) -> Option<&ExceptionGroup> {
+        self.groups.get(index)
+    }
+
+    pub fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    pub fn clear(&mut self) {
+        self.groups.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::log::detail::entry_exception;
+
+    #[test]
+    fn test_exception_groups() {
+        let query = r#"[2024-01-15 10:30:45] local.ERROR: SQLSTATE[42S02] {"exception":"[object] (Illuminate\\Database\\QueryException(code: 42S02): SQLSTATE[42S02]: Base table not found at /var/www/html/vendor/laravel/framework/src/Illuminate/Database/Connection.php:825)"#;
+        let exception = entry_exception(query, &[]).unwrap();
+        assert_eq!(exception.class, "Illuminate\\Database\\QueryException");
+        assert_eq!(
+            exception.location.as_deref(),
+            Some(
+                "/var/www/html/vendor/laravel/framework/src/Illuminate/Database/Connection.php:825"
+            )
+        );
+
+        let json_fields = vec![
+            "exception.class: RuntimeException".to_string(),
+            "exception.file: /app/Jobs/Sync.php:12".to_string(),
+            "exception.message: Timed out".to_string(),
+        ];
+        let timeout =
+            entry_exception("[2024-01-15 10:31:00] app.ERROR: Sync", &json_fields).unwrap();
+        assert_eq!(timeout.location.as_deref(), Some("/app/Jobs/Sync.php:12"));
+        assert!(entry_exception("[2024-01-15 10:31:00] app.ERROR: plain", &[]).is_none());
+
+        let mut groups = ExceptionGroups::default();
+        groups.record(1, "2024-01-15 10:30:45", exception.clone());
+        groups.record(2, "2024-01-15 10:31:00", timeout);
+        groups.record(3, "2024-01-15 10:32:00", exception);
+
+        let by_count = groups.sorted(ExceptionSort::Count);
+        assert_eq!(by_count.len(), 2);
+        let (_, query_group) = by_count[0];
+        assert_eq!(query_group.count, 2);
+        assert_eq!(query_group.first_seen, "2024-01-15 10:30:45");
+        assert_eq!(query_group.last_seen, "2024-01-15 10:32:00");
+        assert_eq!(query_group.occurrences, [1, 3]);
+
+        groups.record(
+            4,
+            "2024-01-15 10:33:00",
+            entry_exception("[x] app.ERROR: Sync", &json_fields).unwrap(),
+        );
+        let (index, recent) = groups.sorted(ExceptionSort::Recent)[0];
+        assert_eq!(recent.class, "RuntimeException");
+        assert_eq!(groups.get(index), Some(recent));
+    }
+}
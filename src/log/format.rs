@@ -0,0 +1,370 @@
+use std::sync::{Arc, OnceLock};
+
+use regex::{Captures, Regex};
+
+use crate::app::LogLevel;
+use crate::log::parser::{parse_entry_header, parse_json_record};
+
+/// Names of the formats that ship with laramux, for config validation
+pub const BUILTIN_FORMATS: &[&str] = &["auto", "laravel", "json", "nginx", "php-fpm", "syslog"];
+
+/// Named groups a custom format may capture
+pub const FORMAT_GROUPS: &[&str] = &["time", "level", "channel", "message"];
+
+/// A log line that starts an entry, split into its parts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedEntry {
+    /// Timestamp as written, empty when the format has none
+    pub time: String,
+    pub level: LogLevel,
+    pub channel: Option<String>,
+    pub message: String,
+    /// Text to show instead of the line, for records that are not text
+    pub header: Option<String>,
+    /// Extra `key: value` lines carried by the record
+    pub fields: Vec<String>,
+}
+
+/// A user-defined format: a regex with named groups
+#[derive(Debug)]
+pub struct CustomFormat {
+    pub name: String,
+    regex: Regex,
+}
+
+/// How the lines of a log source are parsed. Lines the format doesn't
+/// recognize continue the entry before them.
+#[derive(Debug, Clone, Default)]
+pub enum LogFormat {
+    /// Laravel headers or Monolog JSON records, whichever a line is
+    #[default]
+    Auto,
+    /// `[2024-01-15 10:30:45] local.ERROR: message`
+    Laravel,
+    /// Monolog `JsonFormatter` records, one per line
+    Json,
+    /// nginx error logs, and access logs with the level taken from the status
+    Nginx,
+    /// php-fpm's own log and PHP's `error_log`
+    PhpFpm,
+    /// BSD syslog (`Jan 15 10:30:45 host app[12]: message`) or RFC 5424
+    Syslog,
+    Custom(Arc<CustomFormat>),
+}
+
+impl LogFormat {
+    /// A built-in format by its config name
+    pub fn builtin(name: &str) -> Option<Self> {
+        Some(match name {
+            "auto" => LogFormat::Auto,
+            "laravel" => LogFormat::Laravel,
+            "json" => LogFormat::Json,
+            "nginx" => LogFormat::Nginx,
+            "php-fpm" => LogFormat::PhpFpm,
+            "syslog" => LogFormat::Syslog,
+            _ => return None,
+        })
+    }
+
+    /// A format from a regex with `time`, `level`, `channel` and `message`
+    /// groups, all optional. Without `message` the whole line is the message.
+    pub fn custom(name: &str, pattern: &str) -> Result<Self, regex::Error> {
+        Ok(LogFormat::Custom(Arc::new(CustomFormat {
+            name: name.to_string(),
+            regex: Regex::new(pattern)?,
+        })))
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            LogFormat::Auto => "auto",
+            LogFormat::Laravel => "laravel",
+            LogFormat::Json => "json",
+            LogFormat::Nginx => "nginx",
+            LogFormat::PhpFpm => "php-fpm",
+            LogFormat::Syslog => "syslog",
+            LogFormat::Custom(format) => &format.name,
+        }
+    }
+
+    /// Parse a line that starts an entry; `None` for continuation lines
+    pub fn parse(&self, line: &str) -> Option<ParsedEntry> {
+        match self {
+            LogFormat::Auto => parse_laravel(line).or_else(|| parse_json(line)),
+            LogFormat::Laravel => parse_laravel(line),
+            LogFormat::Json => parse_json(line),
+            LogFormat::Nginx => parse_nginx(line),
+            LogFormat::PhpFpm => parse_php_fpm(line),
+            LogFormat::Syslog => parse_syslog(line),
+            LogFormat::Custom(format) => parse_custom(&format.regex, line),
+        }
+    }
+}
+
+fn entry(time: &str, level: LogLevel, channel: Option<&str>, message: &str) -> ParsedEntry {
+    ParsedEntry {
+        time: time.to_string(),
+        level,
+        channel: channel.map(String::from),
+        message: message.to_string(),
+        header: None,
+        fields: Vec::new(),
+    }
+}
+
+fn group<'a>(caps: &Captures<'a>, name: &str) -> Option<&'a str> {
+    caps.name(name).map(|m| m.as_str())
+}
+
+fn parse_laravel(line: &str) -> Option<ParsedEntry> {
+    let header = parse_entry_header(line)?;
+    Some(entry(
+        header.time,
+        LogLevel::from_str(header.level),
+        Some(header.channel),
+        header.message,
+    ))
+}
+
+fn parse_json(line: &str) -> Option<ParsedEntry> {
+    let record = parse_json_record(line)?;
+    Some(ParsedEntry {
+        time: record.datetime.unwrap_or_default(),
+        level: LogLevel::from_str(&record.level),
+        channel: Some(record.channel),
+        message: record.message,
+        header: Some(record.header),
+        fields: record.fields,
+    })
+}
+
+/// `2024/01/15 10:30:45 [error] 31#31: *1 open() failed` and the combined
+/// access log format, `1.2.3.4 - - [15/Jan/2024:10:30:45 +0000] "GET / HTTP/1.1" 200 ...`
+fn parse_nginx(line: &str) -> Option<ParsedEntry> {
+    static ERROR: OnceLock<Regex> = OnceLock::new();
+    static ACCESS: OnceLock<Regex> = OnceLock::new();
+    let error = ERROR.get_or_init(|| {
+        Regex::new(
+            r"^(?<time>\d{4}/\d{2}/\d{2} \d{2}:\d{2}:\d{2}) \[(?<level>\w+)\] (?<message>.*)$",
+        )
+        .unwrap()
+    });
+    if let Some(caps) = error.captures(line) {
+        return Some(entry(
+            group(&caps, "time")?,
+            LogLevel::from_str(group(&caps, "level")?),
+            None,
+            group(&caps, "message")?,
+        ));
+    }
+
+    let access = ACCESS.get_or_init(|| {
+        Regex::new(
+            r#"^(?<client>\S+) \S+ \S+ \[(?<time>[^\]]+)\] "(?<request>[^"]*)" (?<status>\d{3}) "#,
+        )
+        .unwrap()
+    });
+    let caps = access.captures(line)?;
+    let status = group(&caps, "status")?;
+    let level = match status.as_bytes()[0] {
+        b'5' => LogLevel::Error,
+        b'4' => LogLevel::Warning,
+        _ => LogLevel::Info,
+    };
+    let message = format!(
+        "{} {} ({})",
+        status,
+        group(&caps, "request")?,
+        group(&caps, "client")?
+    );
+    Some(entry(group(&caps, "time")?, level, None, &message))
+}
+
+/// `[15-Jan-2024 10:30:45] WARNING: [pool www] child 12 said into stderr`
+/// and `[15-Jan-2024 10:30:45 UTC] PHP Fatal error:  Uncaught Exception`
+fn parse_php_fpm(line: &str) -> Option<ParsedEntry> {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    let regex = REGEX.get_or_init(|| {
+        Regex::new(
+            r"^\[(?<time>\d{2}-[A-Za-z]{3}-\d{4} \d{2}:\d{2}:\d{2}(?: \S+)?)\] (?:(?<level>[A-Z]+): |PHP (?<php>[A-Za-z ]+?):\s+)?(?<message>.*)$",
+        )
+        .unwrap()
+    });
+    let caps = regex.captures(line)?;
+    let (level, channel) = match (group(&caps, "level"), group(&caps, "php")) {
+        (Some(level), _) => (LogLevel::from_str(level), "fpm"),
+        (None, Some(kind)) => {
+            let kind = kind.to_lowercase();
+            let level = if kind.contains("fatal") || kind.contains("parse") {
+                LogLevel::Critical
+            } else if kind.contains("warning") {
+                LogLevel::Warning
+            } else if kind.contains("notice") || kind.contains("deprecated") {
+                LogLevel::Notice
+            } else {
+                LogLevel::Error
+            };
+            (level, "php")
+        }
+        (None, None) => (LogLevel::Info, "fpm"),
+    };
+    Some(entry(
+        group(&caps, "time")?,
+        level,
+        Some(channel),
+        group(&caps, "message")?,
+    ))
+}
+
+/// `<11>Jan 15 10:30:45 web1 cron[123]: message`, with the level taken from
+/// the priority when there is one, and RFC 5424's ISO timestamps
+fn parse_syslog(line: &str) -> Option<ParsedEntry> {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    let regex = REGEX.get_or_init(|| {
+        Regex::new(
+            r"^(?:<(?<pri>\d{1,3})>\d? ?)?(?<time>[A-Z][a-z]{2} [ \d]\d \d{2}:\d{2}:\d{2}|\d{4}-\d{2}-\d{2}T\S+) \S+ (?<channel>[^:\[\s]+)(?:\[\d+\])?:? (?<message>.*)$",
+        )
+        .unwrap()
+    });
+    let caps = regex.captures(line)?;
+    let level = match group(&caps, "pri").and_then(|pri| pri.parse::<u8>().ok()) {
+        Some(pri) => match pri % 8 {
+            0 => LogLevel::Emergency,
+            1 => LogLevel::Alert,
+            2 => LogLevel::Critical,
+            3 => LogLevel::Error,
+            4 => LogLevel::Warning,
+            5 => LogLevel::Notice,
+            6 => LogLevel::Info,
+            _ => LogLevel::Debug,
+        },
+        None => LogLevel::Info,
+    };
+    Some(entry(
+        group(&caps, "time")?,
+        level,
+        group(&caps, "channel"),
+        group(&caps, "message")?,
+    ))
+}
+
+fn parse_custom(regex: &Regex, line: &str) -> Option<ParsedEntry> {
+    let caps = regex.captures(line)?;
+    let level = group(&caps, "level").map_or(LogLevel::Info, LogLevel::from_str);
+    Some(entry(
+        group(&caps, "time").unwrap_or_default(),
+        level,
+        group(&caps, "channel"),
+        group(&caps, "message").unwrap_or(line),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parsed(format: &LogFormat, line: &str) -> (String, LogLevel, Option<String>, String) {
+        let entry = format.parse(line).unwrap();
+        (entry.time, entry.level, entry.channel, entry.message)
+    }
+
+    #[test]
+    fn test_builtin_formats() {
+        let laravel = "[2024-01-15 10:30:45] local.ERROR: boom";
+        assert_eq!(
+            parsed(&LogFormat::Auto, laravel),
+            (
+                "2024-01-15 10:30:45".to_string(),
+                LogLevel::Error,
+                Some("local".to_string()),
+                "boom".to_string()
+            )
+        );
+        assert!(LogFormat::Auto.parse("#0 {main}").is_none());
+        let json = LogFormat::Json
+            .parse(r#"{"message":"Queued","level_name":"NOTICE","datetime":"2024-01-15T10:30:45+00:00"}"#)
+            .unwrap();
+        assert_eq!(json.time, "2024-01-15T10:30:45+00:00");
+        assert_eq!(
+            json.header.as_deref(),
+            Some("[2024-01-15 10:30:45] app.NOTICE: Queued")
+        );
+
+        let nginx = LogFormat::Nginx;
+        let (time, level, _, message) = parsed(
+            &nginx,
+            "2024/01/15 10:30:45 [crit] 31#31: *1 connect() to unix:/run/php/php-fpm.sock failed",
+        );
+        assert_eq!(time, "2024/01/15 10:30:45");
+        assert_eq!(level, LogLevel::Critical);
+        assert!(message.starts_with("31#31"));
+        let (time, level, _, message) = parsed(
+            &nginx,
+            r#"172.18.0.1 - - [15/Jan/2024:10:30:45 +0000] "GET /api/orders HTTP/1.1" 502 157 "-" "curl/8.4.0""#,
+        );
+        assert_eq!(time, "15/Jan/2024:10:30:45 +0000");
+        assert_eq!(level, LogLevel::Error);
+        assert_eq!(message, "502 GET /api/orders HTTP/1.1 (172.18.0.1)");
+
+        let fpm = LogFormat::PhpFpm;
+        let (_, level, channel, message) = parsed(
+            &fpm,
+            "[15-Jan-2024 10:30:45] WARNING: [pool www] server reached pm.max_children setting (5)",
+        );
+        assert_eq!(level, LogLevel::Warning);
+        assert_eq!(channel.as_deref(), Some("fpm"));
+        assert!(message.starts_with("[pool www]"));
+        let (time, level, channel, message) = parsed(
+            &fpm,
+            "[15-Jan-2024 10:30:45 UTC] PHP Fatal error:  Uncaught Exception: boom in /var/www/html/index.php:3",
+        );
+        assert_eq!(time, "15-Jan-2024 10:30:45 UTC");
+        assert_eq!(level, LogLevel::Critical);
+        assert_eq!(channel.as_deref(), Some("php"));
+        assert_eq!(
+            message,
+            "Uncaught Exception: boom in /var/www/html/index.php:3"
+        );
+        assert!(fpm.parse("PHP Stack trace:").is_none());
+
+        let (time, level, channel, message) = parsed(
+            &LogFormat::Syslog,
+            "<11>Jan 15 10:30:45 web1 supervisord[7]: exited: queue (exit status 1)",
+        );
+        assert_eq!(time, "Jan 15 10:30:45");
+        assert_eq!(level, LogLevel::Error);
+        assert_eq!(channel.as_deref(), Some("supervisord"));
+        assert_eq!(message, "exited: queue (exit status 1)");
+    }
+
+    #[test]
+    fn test_custom_format() {
+        let supervisor = LogFormat::custom(
+            "supervisor",
+            r"^(?<time>\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}),\d+ (?<level>[A-Z]+) (?<message>.*)$",
+        )
+        .unwrap();
+        assert_eq!(supervisor.name(), "supervisor");
+        assert_eq!(
+            parsed(
+                &supervisor,
+                "2024-01-15 10:30:45,123 WARN exited: queue (exit status 1; not expected)"
+            ),
+            (
+                "2024-01-15 10:30:45".to_string(),
+                LogLevel::Warning,
+                None,
+                "exited: queue (exit status 1; not expected)".to_string()
+            )
+        );
+
+        // Without a message group the whole line is the message
+        let vite =
+            LogFormat::custom("vite", r"^\d{1,2}:\d{2}:\d{2} [AP]M \[(?<channel>\w+)\]").unwrap();
+        let (_, level, channel, message) = parsed(&vite, "10:30:45 AM [vite] hmr update /app.js");
+        assert_eq!(level, LogLevel::Info);
+        assert_eq!(channel.as_deref(), Some("vite"));
+        assert_eq!(message, "10:30:45 AM [vite] hmr update /app.js");
+        assert!(vite.parse("  at foo (app.js:1:1)").is_none());
+    }
+}
@@ -0,0 +1,174 @@
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::Path;
+
+use crate::log::format::LogFormat;
+
+/// Bytes read per step when reading a file backwards
+const CHUNK_SIZE: u64 = 64 * 1024;
+
+/// Lines scanned for an entry header before a file is treated as plain lines
+const HEADERLESS_SCAN_LINES: usize = 1000;
+
+/// Entries loaded per file when the Logs tab pages back
+pub const HISTORY_PAGE_ENTRIES: usize = 50;
+
+/// Older entries wanted by the Logs tab: for each file, the byte offset of
+/// its oldest loaded entry, or `None` to page back from what was read so far
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HistoryRequest {
+    pub anchors: Vec<(String, Option<u64>)>,
+}
+
+/// Lines of a file from an offset backwards, each with the offset it starts
+/// at. Only the chunks needed are read, so the tail of a large file is cheap.
+pub struct ReverseLines {
+    file: File,
+    /// Start of the bytes not read yet
+    pos: u64,
+    /// Read bytes not returned yet: the file range `pos..pos + buf.len()`
+    buf: Vec<u8>,
+    done: bool,
+}
+
+impl ReverseLines {
+    pub fn new(path: &Path, end: u64) -> io::Result<Self> {
+        let file = File::open(path)?;
+        let end = end.min(file.metadata()?.len());
+        Ok(Self {
+            file,
+            pos: end,
+            buf: Vec::new(),
+            done: false,
+        })
+    }
+
+    fn read_chunk(&mut self) -> io::Result<()> {
+        let start = self.pos.saturating_sub(CHUNK_SIZE);
+        let mut chunk = vec![0; (self.pos - start) as usize];
+        self.file.seek(SeekFrom::Start(start))?;
+        self.file.read_exact(&mut chunk)?;
+        chunk.extend_from_slice(&self.buf);
+        self.buf = chunk;
+        self.pos = start;
+        Ok(())
+    }
+}
+
+impl Iterator for ReverseLines {
+    type Item = io::Result<(u64, String)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(newline) = self.buf.iter().rposition(|&b| b == b'\n') {
+                let line = String::from_utf8_lossy(&self.buf[newline + 1..]).into_owned();
+                let offset = self.pos + newline as u64 + 1;
+                self.buf.truncate(newline);
+                return Some(Ok((offset, line)));
+            }
+            if self.pos == 0 {
+                if self.done {
+                    return None;
+                }
+                self.done = true;
+                let line = String::from_utf8_lossy(&self.buf).into_owned();
+                self.buf.clear();
+                return Some(Ok((0, line)));
+            }
+            if let Err(e) = self.read_chunk() {
+                self.done = true;
+                self.pos = 0;
+                return Some(Err(e));
+            }
+        }
+    }
+}
+
+/// The non-empty lines of the last `n` entries that end before `end`, oldest
+/// first, each with its byte offset. Entries start with a line the format
+/// recognizes; files without any give their last `n` lines. Returns nothing
+/// once the start of the file is reached.
+pub fn read_entries_before(
+    path: &Path,
+    end: u64,
+    n: usize,
+    format: &LogFormat,
+) -> io::Result<Vec<(u64, String)>> {
+    let mut lines = Vec::new();
+    let mut entries = 0;
+    for line in ReverseLines::new(path, end)? {
+        let (offset, line) = line?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            continue;
+        }
+        let starts_entry = format.parse(line).is_some();
+        lines.push((offset, line.to_string()));
+
+        if starts_entry {
+            entries += 1;
+            if entries == n {
+                break;
+            }
+        } else if entries == 0 && lines.len() >= HEADERLESS_SCAN_LINES {
+            break;
+        }
+    }
+    if entries == 0 {
+        // Not a Laravel log: plain lines count as entries
+        lines.truncate(n);
+    }
+    lines.reverse();
+    Ok(lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_entries_before() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("laravel.log");
+        let mut content = String::new();
+        for i in 0..3000 {
+            content.push_str(&format!("[2024-01-15 10:30:45] local.INFO: entry {}\n", i));
+        }
+        content.push_str("[2024-01-15 10:31:00] local.ERROR: boom\n#0 {main}\n\n");
+        std::fs::write(&path, &content).unwrap();
+        let len = content.len() as u64;
+
+        // The tail spans chunk boundaries and keeps the trace with its header
+        let page = read_entries_before(&path, len, 3, &LogFormat::Auto).unwrap();
+        let lines: Vec<&str> = page.iter().map(|(_, line)| line.as_str()).collect();
+        assert_eq!(
+            lines,
+            vec![
+                "[2024-01-15 10:30:45] local.INFO: entry 2998",
+                "[2024-01-15 10:30:45] local.INFO: entry 2999",
+                "[2024-01-15 10:31:00] local.ERROR: boom",
+                "#0 {main}",
+            ]
+        );
+
+        // Paging back from the oldest loaded entry
+        let older = read_entries_before(&path, page[0].0, 2, &LogFormat::Auto).unwrap();
+        assert_eq!(older[1].1, "[2024-01-15 10:30:45] local.INFO: entry 2997");
+        assert_eq!(&content[older[1].0 as usize..][..10], "[2024-01-1");
+
+        let first = read_entries_before(&path, older[0].0, 10, &LogFormat::Auto).unwrap();
+        assert_eq!(first.len(), 10);
+        assert_eq!(first[0].1, "[2024-01-15 10:30:45] local.INFO: entry 2986");
+        assert!(read_entries_before(&path, 0, 10, &LogFormat::Auto)
+            .unwrap()
+            .is_empty());
+
+        // Plain files give their last lines
+        let plain = dir.path().join("worker.log");
+        let lines: String = (0..1500).map(|i| format!("line {}\n", i)).collect();
+        std::fs::write(&plain, lines).unwrap();
+        let page = read_entries_before(&plain, u64::MAX, 2, &LogFormat::Auto).unwrap();
+        assert_eq!(page[0].1, "line 1498");
+        assert_eq!(page[1].1, "line 1499");
+    }
+}
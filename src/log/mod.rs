@@ -1,4 +1,11 @@
+pub mod detail;
+pub mod exceptions;
+pub mod format;
+pub mod history;
 pub mod parser;
+pub mod sources;
+pub mod time;
 pub mod watcher;
 
-pub use watcher::{find_log_dir, LogEntry, LogWatcher};
+pub use sources::{LogSources, DEFAULT_LOG_GLOB};
+pub use watcher::{LogEntry, LogWatcher};
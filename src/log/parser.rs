@@ -1,4 +1,95 @@
-#![allow(dead_code)]
+use serde_json::{Map, Value};
+
+/// A record written by Monolog's `JsonFormatter`
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonRecord {
+    /// Laravel-style header line: `[YYYY-MM-DD HH:MM:SS] channel.LEVEL: message`
+    pub header: String,
+    pub level: String,
+    pub channel: String,
+    pub message: String,
+    /// `datetime` as written, with its fraction and zone
+    pub datetime: Option<String>,
+    /// `context` and `extra` fields as `key: value` lines, nested keys dotted
+    pub fields: Vec<String>,
+}
+
+/// Parse a JSON log line with `message` and `level_name` (or a numeric
+/// Monolog `level`), as written by Monolog's `JsonFormatter`
+pub fn parse_json_record(line: &str) -> Option<JsonRecord> {
+    if !line.starts_with('{') {
+        return None;
+    }
+    let Value::Object(record) = serde_json::from_str(line).ok()? else {
+        return None;
+    };
+
+    let message = record.get("message")?.as_str()?;
+    let level = match record.get("level_name").and_then(Value::as_str) {
+        Some(name) => name.to_uppercase(),
+        None => monolog_level_name(record.get("level")?.as_u64()?)?.to_string(),
+    };
+    let channel = record
+        .get("channel")
+        .and_then(Value::as_str)
+        .unwrap_or("app");
+    let datetime = record.get("datetime").and_then(Value::as_str);
+    // 2024-01-15T10:30:45.123456+00:00 -> 2024-01-15 10:30:45
+    let shown = datetime
+        .and_then(|dt| dt.get(..19))
+        .map(|dt| dt.replacen('T', " ", 1))
+        .unwrap_or_default();
+
+    let mut fields = Vec::new();
+    for section in ["context", "extra"] {
+        if let Some(Value::Object(values)) = record.get(section) {
+            flatten_fields(values, "", &mut fields);
+        }
+    }
+
+    Some(JsonRecord {
+        header: format!("[{}] {}.{}: {}", shown, channel, level, message),
+        level,
+        channel: channel.to_string(),
+        message: message.to_string(),
+        datetime: datetime.map(String::from),
+        fields,
+    })
+}
+
+fn monolog_level_name(level: u64) -> Option<&'static str> {
+    Some(match level {
+        100 => "DEBUG",
+        200 => "INFO",
+        250 => "NOTICE",
+        300 => "WARNING",
+        400 => "ERROR",
+        500 => "CRITICAL",
+        550 => "ALERT",
+        600 => "EMERGENCY",
+        _ => return None,
+    })
+}
+
+/// Flatten nested objects and arrays into `a.b[0]: value` lines
+fn flatten_fields(values: &Map<String, Value>, prefix: &str, fields: &mut Vec<String>) {
+    for (key, value) in values {
+        flatten_value(&format!("{}{}", prefix, key), value, fields);
+    }
+}
+
+fn flatten_value(key: &str, value: &Value, fields: &mut Vec<String>) {
+    match value {
+        Value::Object(values) => flatten_fields(values, &format!("{}.", key), fields),
+        Value::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                flatten_value(&format!("{}[{}]", key, i), item, fields);
+            }
+        }
+        Value::String(s) => fields.push(format!("{}: {}", key, s)),
+        other => fields.push(format!("{}: {}", key, other)),
+    }
+}
 
 /// Detect if a line is part of a stack trace
 pub fn is_stack_trace_line(line: &str) -> bool {
@@ -17,38 +108,43 @@ pub fn is_stack_trace_line(line: &str) -> bool {
         || trimmed.starts_with("at ") && trimmed.contains("::")
 }
 
-/// Detect error level from Laravel log line
-pub fn detect_error_level(line: &str) -> Option<&str> {
-    // Laravel log format: [YYYY-MM-DD HH:MM:SS] environment.LEVEL: message
-    if let Some(bracket_end) = line.find(']') {
-        let after_bracket = &line[bracket_end + 1..];
-        if let Some(colon) = after_bracket.find(':') {
-            let level_part = after_bracket[..colon].trim();
-            if let Some(dot) = level_part.rfind('.') {
-                let level = &level_part[dot + 1..];
-                return Some(level);
-            }
-        }
-    }
-    None
+/// The parts of a Laravel entry header,
+/// `[YYYY-MM-DD HH:MM:SS] channel.LEVEL: message`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EntryHeader<'a> {
+    pub time: &'a str,
+    pub channel: &'a str,
+    pub level: &'a str,
+    pub message: &'a str,
 }
 
-/// Check if a log line indicates an error
-pub fn is_error_line(line: &str) -> bool {
-    if let Some(level) = detect_error_level(line) {
-        let level_lower = level.to_lowercase();
-        matches!(
-            level_lower.as_str(),
-            "error" | "critical" | "alert" | "emergency"
-        )
-    } else {
-        // Fallback to content-based detection
-        let lower = line.to_lowercase();
-        lower.contains("exception")
-            || lower.contains("error:")
-            || lower.contains("fatal")
-            || lower.contains("[error]")
+/// Parse a line that starts a Laravel log entry. Stack frames, JSON context
+/// and `[stacktrace]` blocks that follow it return `None`.
+pub fn parse_entry_header(line: &str) -> Option<EntryHeader<'_>> {
+    let rest = line.strip_prefix('[')?;
+    let date = rest.get(..10)?.as_bytes();
+    let is_date = date.iter().enumerate().all(|(i, b)| match i {
+        4 | 7 => *b == b'-',
+        _ => b.is_ascii_digit(),
+    });
+    if !is_date {
+        return None;
     }
+
+    let (time, after_bracket) = rest.split_once("] ")?;
+    let (channel_level, message) = after_bracket.split_once(':')?;
+    let (channel, level) = channel_level.rsplit_once('.')?;
+    let is_word = |s: &str| {
+        !s.is_empty()
+            && s.chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    };
+    (is_word(channel) && is_word(level)).then(|| EntryHeader {
+        time,
+        channel,
+        level,
+        message: message.trim(),
+    })
 }
 
 #[cfg(test)]
@@ -56,12 +152,57 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_detect_error_level() {
-        let line = "[2024-01-15 10:30:45] local.ERROR: Test error message";
-        assert_eq!(detect_error_level(line), Some("ERROR"));
+    fn test_parse_entry_header() {
+        assert_eq!(
+            parse_entry_header("[2024-01-15 10:30:45] local.ERROR: Test error message"),
+            Some(EntryHeader {
+                time: "2024-01-15 10:30:45",
+                channel: "local",
+                level: "ERROR",
+                message: "Test error message",
+            })
+        );
+        let header =
+            parse_entry_header("[2024-01-15T10:30:45.123456+00:00] production.INFO: Started");
+        assert_eq!(header.map(|h| h.level), Some("INFO"));
+        assert_eq!(parse_entry_header("[stacktrace]"), None);
+        assert_eq!(
+            parse_entry_header("#0 /var/www/vendor/laravel/framework/src/Foo.php(12): bar()"),
+            None
+        );
+        assert_eq!(
+            parse_entry_header("[previous exception] [object] (Foo: x)"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_json_record() {
+        let line = r#"{"message":"Payment failed","context":{"order_id":42,"exception":{"class":"RuntimeException","trace":["app/Pay.php:12"]}},"level":400,"level_name":"ERROR","channel":"production","datetime":"2024-01-15T10:30:45.123456+00:00","extra":{}}"#;
+        let record = parse_json_record(line).unwrap();
+        assert_eq!(
+            record.header,
+            "[2024-01-15 10:30:45] production.ERROR: Payment failed"
+        );
+        assert_eq!(record.level, "ERROR");
+        assert_eq!(
+            record.fields,
+            vec![
+                "exception.class: RuntimeException",
+                "exception.trace[0]: app/Pay.php:12",
+                "order_id: 42",
+            ]
+        );
 
-        let line = "[2024-01-15 10:30:45] production.INFO: Application started";
-        assert_eq!(detect_error_level(line), Some("INFO"));
+        // Numeric level only
+        let record = parse_json_record(r#"{"message":"Queued","level":250}"#).unwrap();
+        assert_eq!(record.level, "NOTICE");
+
+        assert_eq!(parse_json_record(r#"{"foo":"bar"}"#), None);
+        assert_eq!(
+            parse_json_record("[2024-01-15 10:30:45] local.INFO: x"),
+            None
+        );
     }
 
     #[test]
@@ -72,14 +213,4 @@ mod tests {
         assert!(is_stack_trace_line("Stack trace:"));
         assert!(!is_stack_trace_line("Normal log message"));
     }
-
-    #[test]
-    fn test_is_error_line() {
-        assert!(is_error_line(
-            "[2024-01-15 10:30:45] local.ERROR: Test error"
-        ));
-        assert!(!is_error_line(
-            "[2024-01-15 10:30:45] local.INFO: Test info"
-        ));
-    }
 }
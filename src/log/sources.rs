@@ -0,0 +1,349 @@
+use std::path::{Component, Path, PathBuf};
+
+use globset::{escape, Glob, GlobBuilder, GlobMatcher, GlobSet, GlobSetBuilder};
+use notify::RecursiveMode;
+
+use crate::log::format::LogFormat;
+
+/// Laravel's log files, including per-channel subdirectories
+pub const DEFAULT_LOG_GLOB: &str = "storage/logs/**/*.log";
+
+/// Log files to watch: glob patterns relative to the project root, minus
+/// exclude patterns. Files created later that match are picked up too.
+#[derive(Debug, Clone)]
+pub struct LogSources {
+    include: GlobSet,
+    exclude: GlobSet,
+    /// Directories to watch: the literal prefix of each pattern
+    roots: Vec<(PathBuf, RecursiveMode)>,
+    /// Format of the files matching each pattern, first match wins
+    formats: Vec<(GlobMatcher, LogFormat)>,
+}
+
+impl LogSources {
+    pub fn new(
+        working_dir: &Path,
+        patterns: &[String],
+        excludes: &[String],
+    ) -> Result<Self, globset::Error> {
+        let mut include = GlobSetBuilder::new();
+        let mut roots: Vec<(PathBuf, RecursiveMode)> = Vec::new();
+        for pattern in patterns {
+            let pattern = Anchored::new(working_dir, pattern);
+            include.add(pattern.glob()?);
+
+            let (root, mode) = pattern.watch_root();
+            match roots.iter_mut().find(|(dir, _)| *dir == root) {
+                Some(existing) if mode == RecursiveMode::Recursive => existing.1 = mode,
+                Some(_) => {}
+                None => roots.push((root, mode)),
+            }
+        }
+
+        let mut exclude = GlobSetBuilder::new();
+        for pattern in excludes {
+            exclude.add(Anchored::new(working_dir, pattern).glob()?);
+        }
+
+        Ok(Self {
+            include: include.build()?,
+            exclude: exclude.build()?,
+            roots,
+            formats: Vec::new(),
+        })
+    }
+
+    /// Assign formats to the files matching each pattern. Other files use
+    /// the default format, which reads Laravel and JSON entries.
+    pub fn with_formats(
+        mut self,
+        working_dir: &Path,
+        formats: Vec<(String, LogFormat)>,
+    ) -> Result<Self, globset::Error> {
+        for (pattern, format) in formats {
+            let matcher = Anchored::new(working_dir, &pattern)
+                .glob()?
+                .compile_matcher();
+            self.formats.push((matcher, format));
+        }
+        Ok(self)
+    }
+
+    /// How a file's lines are parsed
+    pub fn format(&self, path: &Path) -> LogFormat {
+        self.formats
+            .iter()
+            .find(|(matcher, _)| matcher.is_match(path))
+            .map(|(_, format)| format.clone())
+            .unwrap_or_default()
+    }
+
+    /// Whether a file is a log source
+    pub fn matches(&self, path: &Path) -> bool {
+        self.include.is_match(path) && !self.exclude.is_match(path)
+    }
+
+    /// Directories to watch and whether to recurse into them. A directory
+    /// that doesn't exist yet is stood in for by its nearest existing
+    /// ancestor, so its creation is noticed and the watch can move down.
+    pub fn watch_roots(&self) -> Vec<(PathBuf, RecursiveMode)> {
+        let mut roots: Vec<(PathBuf, RecursiveMode)> = Vec::new();
+        for (dir, mode) in &self.roots {
+            let (dir, mode) = if dir.is_dir() {
+                (dir.as_path(), *mode)
+            } else {
+                let Some(ancestor) = dir.ancestors().find(|a| a.is_dir()) else {
+                    continue;
+                };
+                (ancestor, RecursiveMode::NonRecursive)
+            };
+            match roots.iter_mut().find(|(existing, _)| existing == dir) {
+                Some(existing) if mode == RecursiveMode::Recursive => existing.1 = mode,
+                Some(_) => {}
+                None => roots.push((dir.to_path_buf(), mode)),
+            }
+        }
+        roots
+    }
+
+    /// Matching files that exist now, sorted
+    pub fn existing_files(&self) -> Vec<PathBuf> {
+        let mut files = Vec::new();
+        for (dir, mode) in self.roots.iter().filter(|(dir, _)| dir.is_dir()) {
+            collect_files(dir, *mode == RecursiveMode::Recursive, &mut files);
+        }
+        files.retain(|file| self.matches(file));
+        files.sort();
+        files.dedup();
+        files
+    }
+
+    /// Name shown in the Logs tab: the path below its watched directory,
+    /// e.g. `laravel.log` or `horizon/horizon.log`
+    pub fn display_name(&self, path: &Path) -> String {
+        self.roots
+            .iter()
+            .filter_map(|(dir, _)| path.strip_prefix(dir).ok())
+            .min_by_key(|rest| rest.components().count())
+            .unwrap_or_else(|| Path::new(path.file_name().unwrap_or_default()))
+            .display()
+            .to_string()
+    }
+}
+
+/// Check a glob pattern, for config validation
+pub fn validate_glob(pattern: &str) -> Result<(), globset::Error> {
+    Glob::new(pattern).map(|_| ())
+}
+
+/// A pattern joined to the project root. The part of the path that comes
+/// from the root is matched literally, so a project in `[acme]/shop` or
+/// `{work}/app` doesn't read as a glob.
+struct Anchored {
+    path: PathBuf,
+    /// Leading components taken from the project root
+    literal: usize,
+}
+
+impl Anchored {
+    fn new(working_dir: &Path, pattern: &str) -> Self {
+        let base = normalize(working_dir);
+        let path = normalize(&working_dir.join(pattern));
+        let literal = base
+            .iter()
+            .zip(path.iter())
+            .take_while(|(a, b)| a == b)
+            .count();
+        Self { path, literal }
+    }
+
+    fn glob(&self) -> Result<Glob, globset::Error> {
+        let pattern: PathBuf = self
+            .path
+            .iter()
+            .enumerate()
+            .map(|(i, component)| {
+                let component = component.to_string_lossy();
+                if i < self.literal {
+                    escape(&component)
+                } else {
+                    component.into_owned()
+                }
+            })
+            .collect();
+        GlobBuilder::new(&pattern.to_string_lossy())
+            .literal_separator(true)
+            .build()
+    }
+
+    /// Directory holding the pattern's matches: its longest literal prefix.
+    /// Only patterns with `**` or wildcard directories need a recursive watch.
+    fn watch_root(&self) -> (PathBuf, RecursiveMode) {
+        let components: Vec<&str> = self
+            .path
+            .iter()
+            .map(|c| c.to_str().unwrap_or_default())
+            .collect();
+        let literal = components
+            .iter()
+            .enumerate()
+            .skip(self.literal)
+            .position(|(_, c)| is_glob(c))
+            .map(|pos| pos + self.literal)
+            .unwrap_or(components.len().saturating_sub(1));
+
+        let root: PathBuf = self.path.iter().take(literal).collect();
+        let mode = if components.len() - literal > 1 {
+            RecursiveMode::Recursive
+        } else {
+            RecursiveMode::NonRecursive
+        };
+        (root, mode)
+    }
+}
+
+fn is_glob(component: &str) -> bool {
+    component.contains(['*', '?', '[', '{'])
+}
+
+/// Resolve `.` and `..` without touching the filesystem, so `../shared/logs`
+/// patterns match the paths reported for their files
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+fn collect_files(dir: &Path, recursive: bool, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.filter_map(|e| e.ok()) {
+        let path = entry.path();
+        if path.is_dir() {
+            if recursive {
+                collect_files(&path, recursive, files);
+            }
+        } else {
+            files.push(path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_sources() {
+        let project = tempfile::tempdir().unwrap();
+        let root = project.path().join("app");
+        let logs = root.join("storage/logs");
+        std::fs::create_dir_all(logs.join("horizon")).unwrap();
+        std::fs::create_dir_all(project.path().join("shared/logs")).unwrap();
+        for file in [
+            "storage/logs/laravel-2024-01-15.log",
+            "storage/logs/horizon/horizon.log",
+            "storage/logs/debug-sql.log",
+            "storage/logs/notes.txt",
+            "../shared/logs/api.log",
+        ] {
+            std::fs::write(root.join(file), "").unwrap();
+        }
+
+        let sources = LogSources::new(
+            &root,
+            &[
+                DEFAULT_LOG_GLOB.to_string(),
+                "../shared/logs/*.log".to_string(),
+            ],
+            &["storage/logs/debug-*.log".to_string()],
+        )
+        .unwrap()
+        .with_formats(
+            &root,
+            vec![("../shared/logs/*.log".to_string(), LogFormat::Nginx)],
+        )
+        .unwrap();
+
+        let names: Vec<String> = sources
+            .existing_files()
+            .iter()
+            .map(|file| sources.display_name(file))
+            .collect();
+        assert_eq!(
+            names,
+            vec!["horizon/horizon.log", "laravel-2024-01-15.log", "api.log"]
+        );
+
+        // Tomorrow's daily file is picked up once it appears
+        assert!(sources.matches(&logs.join("laravel-2024-01-16.log")));
+        assert!(!sources.matches(&project.path().join("shared/logs/old/api.log")));
+
+        assert_eq!(
+            sources
+                .format(&project.path().join("shared/logs/api.log"))
+                .name(),
+            "nginx"
+        );
+        assert_eq!(sources.format(&logs.join("laravel.log")).name(), "auto");
+    }
+
+    #[test]
+    fn test_log_sources_in_glob_like_project_dir() {
+        let project = tempfile::tempdir().unwrap();
+        for dir in ["[acme]/shop", "{work}/app"] {
+            let root = project.path().join(dir);
+            let logs = root.join("storage/logs");
+            std::fs::create_dir_all(&logs).unwrap();
+            std::fs::write(logs.join("laravel.log"), "").unwrap();
+
+            let sources = LogSources::new(&root, &[DEFAULT_LOG_GLOB.to_string()], &[]).unwrap();
+            assert_eq!(sources.existing_files(), vec![logs.join("laravel.log")]);
+            assert!(sources.matches(&logs.join("worker.log")));
+        }
+    }
+
+    #[test]
+    fn test_watch_roots_of_missing_dirs() {
+        let project = tempfile::tempdir().unwrap();
+        let root = project.path().join("app");
+        std::fs::create_dir_all(root.join("storage/logs")).unwrap();
+        let sources = LogSources::new(
+            &root,
+            &[
+                DEFAULT_LOG_GLOB.to_string(),
+                "storage/logs/horizon/*.log".to_string(),
+                "../shared/logs/*.log".to_string(),
+            ],
+            &[],
+        )
+        .unwrap();
+
+        // Missing directories are watched through their nearest ancestor
+        assert_eq!(
+            sources.watch_roots(),
+            vec![
+                (root.join("storage/logs"), RecursiveMode::Recursive),
+                (project.path().to_path_buf(), RecursiveMode::NonRecursive),
+            ]
+        );
+
+        std::fs::create_dir_all(project.path().join("shared/logs")).unwrap();
+        assert_eq!(
+            sources.watch_roots()[1],
+            (
+                project.path().join("shared/logs"),
+                RecursiveMode::NonRecursive
+            )
+        );
+    }
+}
@@ -0,0 +1,405 @@
+use std::sync::OnceLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use jiff::civil::DateTime;
+use jiff::tz::{Offset, TimeZone};
+use jiff::Timestamp;
+use regex::Regex;
+
+/// Windows of the time filter presets, in seconds
+const PRESETS: &[i64] = &[5 * 60, 15 * 60, 60 * 60, 24 * 60 * 60];
+
+/// Which log entries the Logs tab shows by time
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeFilter {
+    #[default]
+    All,
+    /// The last given number of seconds
+    Last(i64),
+    /// Since a managed process last started
+    SinceRestart,
+    /// Between two instants; an open end is unbounded
+    Range { from: Option<i64>, to: Option<i64> },
+}
+
+impl TimeFilter {
+    /// Next preset: all, 5m, 15m, 1h, 24h, since restart, then all again
+    pub fn next(self) -> Self {
+        match self {
+            TimeFilter::All | TimeFilter::Range { .. } => TimeFilter::Last(PRESETS[0]),
+            TimeFilter::Last(secs) => match PRESETS.iter().position(|&p| p == secs) {
+                Some(i) if i + 1 < PRESETS.len() => TimeFilter::Last(PRESETS[i + 1]),
+                _ => TimeFilter::SinceRestart,
+            },
+            TimeFilter::SinceRestart => TimeFilter::All,
+        }
+    }
+
+    /// Label for the Logs title, e.g. `Last 15m` or `10:00:00 → now`
+    pub fn name(self, zone: &TimeZone) -> String {
+        match self {
+            TimeFilter::All => "All time".to_string(),
+            TimeFilter::Last(secs) => format!("Last {}", format_span(secs)),
+            TimeFilter::SinceRestart => "Since restart".to_string(),
+            TimeFilter::Range { from, to } => {
+                let bound = |time: Option<i64>, open: &str| match time {
+                    Some(time) => format_datetime(time, zone),
+                    None => open.to_string(),
+                };
+                format!("{} → {}", bound(from, "start"), bound(to, "now"))
+            }
+        }
+    }
+
+    /// Start and end of the window, given the current time and the last
+    /// process start
+    pub fn bounds(self, now: i64, restarted: Option<i64>) -> (Option<i64>, Option<i64>) {
+        match self {
+            TimeFilter::All => (None, None),
+            TimeFilter::Last(secs) => (Some(now - secs), None),
+            TimeFilter::SinceRestart => (restarted, None),
+            TimeFilter::Range { from, to } => (from, to),
+        }
+    }
+}
+
+/// Current time as seconds since the Unix epoch
+pub fn unix_now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() as i64)
+}
+
+/// Parse a UTC offset such as `UTC`, `Z`, `+02:00`, `-0530` or `+2` into seconds
+pub fn parse_utc_offset(spec: &str) -> Option<i32> {
+    let spec = spec.trim();
+    if spec.eq_ignore_ascii_case("utc") || spec == "Z" {
+        return Some(0);
+    }
+    let sign = match spec.as_bytes().first()? {
+        b'+' => 1,
+        b'-' => -1,
+        _ => return None,
+    };
+    let rest = &spec[1..];
+    let (hours, minutes) = match rest.split_once(':') {
+        Some(parts) => parts,
+        None if rest.len() == 4 => rest.split_at(2),
+        None => (rest, "0"),
+    };
+    let number = |s: &str| {
+        (!s.is_empty() && s.len() <= 2 && s.bytes().all(|b| b.is_ascii_digit()))
+            .then(|| s.parse::<i32>().ok())
+            .flatten()
+    };
+    let (hours, minutes) = (number(hours)?, number(minutes)?);
+    (hours <= 14 && minutes < 60).then_some(sign * (hours * 3600 + minutes * 60))
+}
+
+/// Timezone of log timestamps without one: `UTC`, an offset such as
+/// `+02:00`, or an IANA zone name such as `Europe/Lisbon`
+pub fn parse_timezone(spec: &str) -> Option<TimeZone> {
+    match parse_utc_offset(spec) {
+        Some(offset) => Some(TimeZone::fixed(Offset::from_seconds(offset).ok()?)),
+        None => TimeZone::get(spec.trim()).ok(),
+    }
+}
+
+const MONTHS: &[&str] = &[
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+
+/// `2024-01-15 10:30:45`, `2024-01-15T10:30:45.123456+00:00`, `2024/01/15 10:30`
+/// or `2024-01-15`, with an optional `Z`, `UTC` or `±HH:MM` zone
+fn datetime_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| {
+        Regex::new(
+            r"^(?<year>\d{4})[-/](?<month>\d{2})[-/](?<day>\d{2})(?:[T ](?<hour>\d{2}):(?<minute>\d{2})(?::(?<second>\d{2})(?:[.,]\d+)?)?)?\s*(?<zone>Z|UTC|[+-]\d{2}(?::?\d{2})?)?$",
+        )
+        .unwrap()
+    })
+}
+
+/// `15/Jan/2024:10:30:45 +0000` (nginx access logs) and
+/// `15-Jan-2024 10:30:45 UTC` (php-fpm and PHP's error log)
+fn month_name_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| {
+        Regex::new(
+            r"^(?<day>\d{1,2})[-/ ](?<month>[A-Za-z]{3})[-/ ](?<year>\d{4})[: ](?<hour>\d{2}):(?<minute>\d{2}):(?<second>\d{2})(?:[.,]\d+)?(?:\s+(?<zone>\S+))?$",
+        )
+        .unwrap()
+    })
+}
+
+/// `Jan 15 10:30:45`, syslog's timestamp without a year
+fn syslog_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| {
+        Regex::new(
+            r"^(?<month>[A-Za-z]{3})\s+(?<day>\d{1,2}) (?<hour>\d{2}):(?<minute>\d{2}):(?<second>\d{2})$",
+        )
+        .unwrap()
+    })
+}
+
+/// Parse a timestamp into seconds since the Unix epoch. Timestamps without
+/// a zone, such as Laravel's default `Y-m-d H:i:s`, are read in `zone`, and
+/// so are zones given by name other than UTC. Syslog timestamps, which have
+/// no year, are taken to be within the last year.
+pub fn parse_timestamp(text: &str, zone: &TimeZone) -> Option<i64> {
+    let text = text.trim();
+    let caps = datetime_regex()
+        .captures(text)
+        .or_else(|| month_name_regex().captures(text))
+        .or_else(|| syslog_regex().captures(text))?;
+    let field = |name: &str| {
+        caps.name(name)
+            .map_or(Some(0), |m| m.as_str().parse::<i64>().ok())
+    };
+    let month = match caps.name("month")?.as_str() {
+        digits if digits.bytes().all(|b| b.is_ascii_digit()) => digits.parse().ok()?,
+        name => {
+            let name = name.to_lowercase();
+            MONTHS.iter().position(|m| *m == name)? as i64 + 1
+        }
+    };
+    let (day, hour, minute, second) = (
+        field("day")?,
+        field("hour")?,
+        field("minute")?,
+        field("second")?,
+    );
+    // An explicit offset wins over the configured zone
+    let offset = match caps.name("zone").and_then(|z| parse_utc_offset(z.as_str())) {
+        Some(offset) => Some(TimeZone::fixed(Offset::from_seconds(offset).ok()?)),
+        None => None,
+    };
+    let zone = offset.as_ref().unwrap_or(zone);
+    let at = |year: i64| {
+        // Leap seconds are read as the second before
+        let datetime = DateTime::new(
+            i16::try_from(year).ok()?,
+            i8::try_from(month).ok()?,
+            i8::try_from(day).ok()?,
+            i8::try_from(hour).ok()?,
+            i8::try_from(minute).ok()?,
+            i8::try_from(second.min(59)).ok()?,
+            0,
+        )
+        .ok()?;
+        zone.to_timestamp(datetime).ok().map(|ts| ts.as_second())
+    };
+    match caps.name("year") {
+        Some(year) => at(year.as_str().parse().ok()?),
+        None => {
+            // A date more than a day ahead is from last year
+            let now = unix_now();
+            let year = i64::from(zone.to_datetime(Timestamp::from_second(now).ok()?).year());
+            let time = at(year)?;
+            if time > now + 86_400 {
+                at(year - 1)
+            } else {
+                Some(time)
+            }
+        }
+    }
+}
+
+/// Parse one end of a custom range: a date and time, a time of day today,
+/// or an age such as `30m`, `2h` or `1d` meaning that long ago
+pub fn parse_time_bound(text: &str, now: i64, zone: &TimeZone) -> Option<i64> {
+    let text = text.trim();
+    if let Some(unit) = text.chars().last().filter(|c| "smhd".contains(*c)) {
+        if let Ok(amount) = text[..text.len() - 1].parse::<i64>() {
+            let scale = match unit {
+                's' => 1,
+                'm' => 60,
+                'h' => 3600,
+                _ => 86_400,
+            };
+            return Some(now - amount * scale);
+        }
+    }
+    if !text.contains('-') {
+        // A time of day: prefix today's date in the log timezone
+        let today = format_datetime(now, zone);
+        return parse_timestamp(&format!("{} {}", today.get(..10)?, text), zone);
+    }
+    parse_timestamp(text, zone)
+}
+
+/// Parse a custom range, `FROM..TO` with either end optional, or `FROM`
+pub fn parse_range(text: &str, now: i64, zone: &TimeZone) -> Option<TimeFilter> {
+    let (from, to) = text.split_once("..").unwrap_or((text, ""));
+    let bound = |text: &str| match text.trim() {
+        "" => Some(None),
+        text => parse_time_bound(text, now, zone).map(Some),
+    };
+    let (from, to) = (bound(from)?, bound(to)?);
+    if from.is_none() && to.is_none() {
+        return None;
+    }
+    Some(TimeFilter::Range { from, to })
+}
+
+/// Format seconds since the epoch as `YYYY-MM-DD HH:MM:SS` in a timezone
+pub fn format_datetime(time: i64, zone: &TimeZone) -> String {
+    Timestamp::from_second(time)
+        .map(|ts| {
+            zone.to_datetime(ts)
+                .strftime("%Y-%m-%d %H:%M:%S")
+                .to_string()
+        })
+        .unwrap_or_default()
+}
+
+/// Largest whole unit of a span, e.g. `45s`, `3m`, `2h`, `5d`
+fn format_span(secs: i64) -> String {
+    match secs {
+        s if s < 60 => format!("{}s", s),
+        s if s < 3600 => format!("{}m", s / 60),
+        s if s <= 86_400 => format!("{}h", s / 3600),
+        s => format!("{}d", s / 86_400),
+    }
+}
+
+/// How long ago a timestamp was, e.g. `3m ago`
+pub fn format_relative(time: i64, now: i64) -> String {
+    match now - time {
+        age if age < 0 => format!("in {}", format_span(-age)),
+        age => format!("{} ago", format_span(age)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_timestamp() {
+        // 2024-01-15 10:30:45 UTC
+        let utc = 1_705_314_645;
+        let plus_one = parse_timezone("+01:00").unwrap();
+        assert_eq!(
+            parse_timestamp("2024-01-15 10:30:45", &TimeZone::UTC),
+            Some(utc)
+        );
+        assert_eq!(
+            parse_timestamp("2024-01-15T10:30:45.123456+00:00", &plus_one),
+            Some(utc)
+        );
+        assert_eq!(
+            parse_timestamp("2024-01-15T12:30:45+02:00", &TimeZone::UTC),
+            Some(utc)
+        );
+        // Naive timestamps are read in the configured zone
+        assert_eq!(parse_timestamp("2024-01-15 11:30:45", &plus_one), Some(utc));
+        assert_eq!(
+            parse_timestamp("2024-01-15 10:30", &TimeZone::UTC),
+            Some(utc - 45)
+        );
+        assert_eq!(
+            parse_timestamp("2024/01/15 10:30:45", &TimeZone::UTC),
+            Some(utc)
+        );
+        assert_eq!(
+            parse_timestamp("15/Jan/2024:12:30:45 +0200", &TimeZone::UTC),
+            Some(utc)
+        );
+        assert_eq!(
+            parse_timestamp("15-Jan-2024 10:30:45 UTC", &plus_one),
+            Some(utc)
+        );
+        assert_eq!(
+            parse_timestamp("15-Jan-2024 11:30:45 Europe/Lisbon", &plus_one),
+            Some(utc)
+        );
+        let syslog = parse_timestamp("Jan 15 10:30:45", &TimeZone::UTC).unwrap();
+        assert_eq!((syslog - utc) % 86_400, 0);
+        assert!(syslog <= unix_now() + 86_400);
+        assert_eq!(parse_timestamp("2024-13-15 10:30:45", &TimeZone::UTC), None);
+        assert_eq!(parse_timestamp("yesterday", &TimeZone::UTC), None);
+
+        assert_eq!(parse_utc_offset("UTC"), Some(0));
+        assert_eq!(parse_utc_offset("+02:00"), Some(7200));
+        assert_eq!(parse_utc_offset("-0530"), Some(-19_800));
+        assert_eq!(parse_utc_offset("+2"), Some(7200));
+        assert_eq!(parse_utc_offset("Europe/Lisbon"), None);
+
+        assert_eq!(format_datetime(utc, &plus_one), "2024-01-15 11:30:45");
+        assert_eq!(format_relative(utc - 180, utc), "3m ago");
+        assert_eq!(format_relative(utc - 7200, utc), "2h ago");
+    }
+
+    #[test]
+    fn test_named_timezone() {
+        // Needs the system's zoneinfo database
+        let Some(lisbon) = parse_timezone("Europe/Lisbon") else {
+            return;
+        };
+        // WET in winter, WEST in summer
+        assert_eq!(
+            parse_timestamp("2024-01-15 10:30:45", &lisbon),
+            Some(1_705_314_645)
+        );
+        assert_eq!(
+            parse_timestamp("2024-07-15 11:30:45", &lisbon),
+            Some(1_721_039_445)
+        );
+        assert_eq!(
+            format_datetime(1_721_039_445, &lisbon),
+            "2024-07-15 11:30:45"
+        );
+        assert_eq!(parse_timezone("Mars/Olympus_Mons"), None);
+    }
+
+    #[test]
+    fn test_time_filter() {
+        let now = 1_705_314_645;
+        let utc = TimeZone::UTC;
+        let mut filter = TimeFilter::All;
+        let mut names = Vec::new();
+        for _ in 0..6 {
+            filter = filter.next();
+            names.push(filter.name(&TimeZone::UTC));
+        }
+        assert_eq!(
+            names,
+            [
+                "Last 5m",
+                "Last 15m",
+                "Last 1h",
+                "Last 24h",
+                "Since restart",
+                "All time"
+            ]
+        );
+        assert_eq!(
+            TimeFilter::Last(300).bounds(now, None),
+            (Some(now - 300), None)
+        );
+        assert_eq!(
+            TimeFilter::SinceRestart.bounds(now, Some(now - 10)),
+            (Some(now - 10), None)
+        );
+
+        assert_eq!(
+            parse_range("2024-01-15 10:00..10:30", now, &utc),
+            Some(TimeFilter::Range {
+                from: Some(now - 1845),
+                to: Some(now - 45),
+            })
+        );
+        assert_eq!(
+            parse_range("2h", now, &utc),
+            Some(TimeFilter::Range {
+                from: Some(now - 7200),
+                to: None,
+            })
+        );
+        assert_eq!(parse_range("..", now, &utc), None);
+        assert_eq!(parse_range("soon", now, &utc), None);
+    }
+}
@@ -1,7 +1,7 @@
-use std::collections::HashSet;
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufRead, BufReader, Seek, SeekFrom};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use notify::{Config, RecommendedWatcher, RecursiveMode, Watcher};
 use tokio::sync::mpsc;
@@ -9,48 +9,53 @@ use tokio_util::sync::CancellationToken;
 
 use crate::error::Result;
 use crate::event::Event;
+use crate::log::format::LogFormat;
+use crate::log::history::{read_entries_before, HistoryRequest, HISTORY_PAGE_ENTRIES};
+use crate::log::sources::LogSources;
+
+/// Entries of each file loaded at startup
+const INITIAL_ENTRIES: usize = 5;
 
 /// A log line with its source file
 #[derive(Debug, Clone)]
 pub struct LogEntry {
     pub content: String,
     pub file: String,
+    /// Byte offset of the line in its file
+    pub offset: u64,
+    /// Format of the source file
+    pub format: LogFormat,
 }
 
-/// Watch Laravel log directory for changes
+/// Watch log sources for changes
 pub struct LogWatcher {
-    log_dir: PathBuf,
-    additional_files: Vec<PathBuf>,
+    sources: LogSources,
     event_tx: mpsc::Sender<Event>,
+    history_rx: mpsc::UnboundedReceiver<HistoryRequest>,
     cancel_token: CancellationToken,
 }
 
 impl LogWatcher {
     pub fn new(
-        log_dir: PathBuf,
+        sources: LogSources,
         event_tx: mpsc::Sender<Event>,
+        history_rx: mpsc::UnboundedReceiver<HistoryRequest>,
         cancel_token: CancellationToken,
     ) -> Self {
         Self {
-            log_dir,
-            additional_files: Vec::new(),
+            sources,
             event_tx,
+            history_rx,
             cancel_token,
         }
     }
 
-    /// Add additional log files to watch (paths relative to project root)
-    pub fn with_additional_files(mut self, files: Vec<PathBuf>) -> Self {
-        self.additional_files = files;
-        self
-    }
-
-    /// Start watching the log directory for any .log files
+    /// Start watching the log sources, including matching files created later
     pub async fn watch(self) -> Result<()> {
-        let log_dir = self.log_dir.clone();
-        let additional_files = self.additional_files.clone();
-        let event_tx = self.event_tx.clone();
-        let cancel_token = self.cancel_token.clone();
+        let sources = self.sources;
+        let event_tx = self.event_tx;
+        let mut history_rx = self.history_rx;
+        let cancel_token = self.cancel_token;
 
         // Create channel for file system events
         let (fs_tx, mut fs_rx) = mpsc::channel::<notify::Result<notify::Event>>(100);
@@ -63,88 +68,26 @@ impl LogWatcher {
             Config::default(),
         )?;
 
-        // Watch the log directory
-        watcher.watch(&log_dir, RecursiveMode::NonRecursive)?;
-
-        // Watch parent directories of additional files
-        let mut watched_dirs: HashSet<PathBuf> = HashSet::new();
-        watched_dirs.insert(log_dir.clone());
-
-        // Track which specific files we're watching (for additional files)
-        let watched_files: HashSet<PathBuf> = additional_files.iter().cloned().collect();
-
-        for file in &additional_files {
-            if let Some(parent) = file.parent() {
-                if parent.exists()
-                    && !watched_dirs.contains(parent)
-                    && watcher.watch(parent, RecursiveMode::NonRecursive).is_ok()
-                {
-                    watched_dirs.insert(parent.to_path_buf());
-                }
-            }
-        }
+        // Watch the directory each pattern lives in
+        let mut watched: HashMap<PathBuf, RecursiveMode> = HashMap::new();
+        update_watches(&mut watcher, &sources, &mut watched);
 
         // Track file positions for incremental reading (keyed by file path)
-        let mut file_positions: std::collections::HashMap<PathBuf, u64> =
-            std::collections::HashMap::new();
+        let mut file_positions: HashMap<PathBuf, u64> = HashMap::new();
 
         // Initialize positions for existing log files and load recent history
-        if let Ok(entries) = std::fs::read_dir(&log_dir) {
-            for entry in entries.filter_map(|e| e.ok()) {
-                let path = entry.path();
-                if path.extension().map(|ext| ext == "log").unwrap_or(false) {
-                    let file_name = path
-                        .file_name()
-                        .and_then(|n| n.to_str())
-                        .unwrap_or("unknown")
-                        .to_string();
-
-                    // Read last 5 lines as initial history
-                    if let Ok(recent_lines) = read_last_n_lines(&path, 5) {
-                        if !recent_lines.is_empty() {
-                            let entries: Vec<LogEntry> = recent_lines
-                                .into_iter()
-                                .map(|content| LogEntry {
-                                    content,
-                                    file: file_name.clone(),
-                                })
-                                .collect();
-                            let _ = event_tx.send(Event::LogUpdate(entries)).await;
-                        }
-                    }
-                    // Set position to end of file for future reads
-                    let pos = std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
-                    file_positions.insert(path, pos);
-                }
-            }
-        }
-
-        // Initialize positions for additional files
-        for file in &additional_files {
-            if file.exists() {
-                let file_name = file
-                    .file_name()
-                    .and_then(|n| n.to_str())
-                    .unwrap_or("unknown")
-                    .to_string();
-
-                // Read last 5 lines as initial history
-                if let Ok(recent_lines) = read_last_n_lines(file, 5) {
-                    if !recent_lines.is_empty() {
-                        let entries: Vec<LogEntry> = recent_lines
-                            .into_iter()
-                            .map(|content| LogEntry {
-                                content,
-                                file: file_name.clone(),
-                            })
-                            .collect();
-                        let _ = event_tx.send(Event::LogUpdate(entries)).await;
-                    }
+        for path in sources.existing_files() {
+            // Read from the end, so large files load as fast as small ones
+            let pos = std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+            let format = sources.format(&path);
+            if let Ok(recent_lines) = read_entries_before(&path, pos, INITIAL_ENTRIES, &format) {
+                let entries = to_entries(recent_lines, &sources.display_name(&path), &format);
+                if !entries.is_empty() {
+                    let _ = event_tx.send(Event::LogUpdate(entries)).await;
                 }
-                // Set position to end of file for future reads
-                let pos = std::fs::metadata(file).map(|m| m.len()).unwrap_or(0);
-                file_positions.insert(file.clone(), pos);
             }
+            // Set position to end of file for future reads
+            file_positions.insert(path, pos);
         }
 
         loop {
@@ -152,44 +95,52 @@ impl LogWatcher {
                 _ = cancel_token.cancelled() => {
                     break;
                 }
+                Some(request) = history_rx.recv() => {
+                    let entries = read_history(&sources, &file_positions, &request);
+                    let _ = event_tx.send(Event::LogHistory(entries)).await;
+                }
                 Some(event) = fs_rx.recv() => {
                     if let Ok(event) = event {
-                        // Process any .log file events
-                        for path in event.paths.iter() {
-                            let is_log_file = path.extension().map(|ext| ext == "log").unwrap_or(false);
-
-                            // Check if this is a file we should process:
-                            // 1. Any .log file in the main log directory
-                            // 2. Specifically watched additional files
-                            let in_log_dir = path.parent() == Some(&log_dir);
-                            let is_watched_file = watched_files.contains(path);
-                            let should_process = (is_log_file && in_log_dir) || is_watched_file;
-
-                            if should_process
-                                && matches!(event.kind, notify::EventKind::Modify(_) | notify::EventKind::Create(_))
-                            {
-                                let file_name = path
-                                    .file_name()
-                                    .and_then(|n| n.to_str())
-                                    .unwrap_or("unknown")
-                                    .to_string();
-
-                                // Get or create position tracker for this file
-                                let last_pos = file_positions.entry(path.clone()).or_insert(0);
+                        if !matches!(event.kind, notify::EventKind::Modify(_) | notify::EventKind::Create(_)) {
+                            continue;
+                        }
 
-                                // Read new content
-                                if let Ok(new_lines) = read_new_lines(path, last_pos) {
+                        // A watched directory was created: files may already be in it
+                        let created_dir = matches!(event.kind, notify::EventKind::Create(_))
+                            && event.paths.iter().any(|path| path.is_dir());
+                        if created_dir && update_watches(&mut watcher, &sources, &mut watched) {
+                            for path in sources.existing_files() {
+                                if file_positions.contains_key(&path) {
+                                    continue;
+                                }
+                                let mut pos = 0;
+                                if let Ok(new_lines) = read_new_lines(&path, &mut pos) {
                                     if !new_lines.is_empty() {
-                                        let entries: Vec<LogEntry> = new_lines
-                                            .into_iter()
-                                            .map(|content| LogEntry {
-                                                content,
-                                                file: file_name.clone(),
-                                            })
-                                            .collect();
+                                        let entries = to_entries(
+                                            new_lines,
+                                            &sources.display_name(&path),
+                                            &sources.format(&path),
+                                        );
                                         let _ = event_tx.send(Event::LogUpdate(entries)).await;
                                     }
                                 }
+                                file_positions.insert(path, pos);
+                            }
+                        }
+
+                        for path in event.paths.iter().filter(|path| sources.matches(path)) {
+                            let file_name = sources.display_name(path);
+
+                            // New files (e.g. the next daily log) are read from the start
+                            let last_pos = file_positions.entry(path.clone()).or_insert(0);
+
+                            // Read new content
+                            if let Ok(new_lines) = read_new_lines(path, last_pos) {
+                                if !new_lines.is_empty() {
+                                    let entries =
+                                        to_entries(new_lines, &file_name, &sources.format(path));
+                                    let _ = event_tx.send(Event::LogUpdate(entries)).await;
+                                }
                             }
                         }
                     }
@@ -201,26 +152,67 @@ impl LogWatcher {
     }
 }
 
-/// Read the last N non-empty lines from a file
-fn read_last_n_lines(path: &PathBuf, n: usize) -> std::io::Result<Vec<String>> {
-    let file = File::open(path)?;
-    let reader = BufReader::new(file);
+/// Watch the directories of the sources that aren't watched yet, moving
+/// down from an ancestor once a missing directory exists. Returns whether
+/// any watch was added.
+fn update_watches(
+    watcher: &mut RecommendedWatcher,
+    sources: &LogSources,
+    watched: &mut HashMap<PathBuf, RecursiveMode>,
+) -> bool {
+    let mut added = false;
+    for (dir, mode) in sources.watch_roots() {
+        let current = watched.get(&dir).copied();
+        if current == Some(mode) || current == Some(RecursiveMode::Recursive) {
+            continue;
+        }
+        if watcher.watch(&dir, mode).is_ok() {
+            watched.insert(dir, mode);
+            added = true;
+        }
+    }
+    added
+}
 
-    // Read all lines, keeping only non-empty ones
-    let lines: Vec<String> = reader
-        .lines()
-        .map_while(|l| l.ok())
-        .map(|l| l.trim_end().to_string())
-        .filter(|l| !l.is_empty())
-        .collect();
+fn to_entries(lines: Vec<(u64, String)>, file_name: &str, format: &LogFormat) -> Vec<LogEntry> {
+    lines
+        .into_iter()
+        .map(|(offset, content)| LogEntry {
+            content,
+            file: file_name.to_string(),
+            offset,
+            format: format.clone(),
+        })
+        .collect()
+}
 
-    // Return last N lines
-    let start = lines.len().saturating_sub(n);
-    Ok(lines[start..].to_vec())
+/// A page of older entries for each requested file. Files are named by
+/// their display name; without an anchor a file pages back from the
+/// position the watcher has read up to.
+fn read_history(
+    sources: &LogSources,
+    file_positions: &HashMap<PathBuf, u64>,
+    request: &HistoryRequest,
+) -> Vec<LogEntry> {
+    let mut entries = Vec::new();
+    for (name, anchor) in &request.anchors {
+        let Some((path, pos)) = file_positions
+            .iter()
+            .find(|(path, _)| sources.display_name(path) == *name)
+        else {
+            continue;
+        };
+        let end = anchor.unwrap_or(*pos);
+        let format = sources.format(path);
+        if let Ok(lines) = read_entries_before(path, end, HISTORY_PAGE_ENTRIES, &format) {
+            entries.extend(to_entries(lines, name, &format));
+        }
+    }
+    entries
 }
 
-/// Read new lines from file starting at given position
-fn read_new_lines(path: &PathBuf, last_pos: &mut u64) -> std::io::Result<Vec<String>> {
+/// Read new lines from file starting at given position, with their offsets
+fn read_new_lines(path: &Path, last_pos: &mut u64) -> std::io::Result<Vec<(u64, String)>> {
     let file = File::open(path)?;
     let metadata = file.metadata()?;
     let current_size = metadata.len();
@@ -240,25 +232,21 @@ fn read_new_lines(path: &PathBuf, last_pos: &mut u64) -> std::io::Result<Vec<Str
 
     let mut lines = Vec::new();
     let mut line = String::new();
+    let mut offset = *last_pos;
 
-    while reader.read_line(&mut line)? > 0 {
+    loop {
+        let read = reader.read_line(&mut line)?;
+        if read == 0 {
+            break;
+        }
         let trimmed = line.trim_end();
         if !trimmed.is_empty() {
-            lines.push(trimmed.to_string());
+            lines.push((offset, trimmed.to_string()));
         }
+        offset += read as u64;
         line.clear();
     }
 
-    *last_pos = current_size;
+    *last_pos = offset;
     Ok(lines)
 }
-
-/// Find Laravel log directory in project
-pub fn find_log_dir(working_dir: &std::path::Path) -> Option<PathBuf> {
-    let logs_dir = working_dir.join("storage/logs");
-    if logs_dir.exists() && logs_dir.is_dir() {
-        Some(logs_dir)
-    } else {
-        None
-    }
-}
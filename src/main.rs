@@ -1,7 +1,9 @@
 mod app;
 mod config;
+mod editor;
 mod error;
 mod event;
+mod export;
 mod log;
 mod process;
 mod tui;
@@ -15,7 +17,6 @@ use std::time::Duration;
 
 use crossterm::event::{Event as CrosstermEvent, EventStream, KeyCode, KeyEventKind, KeyModifiers};
 use futures::StreamExt;
-use sysinfo::System;
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
 use tokio::process::Command;
 use tokio::sync::{mpsc, Mutex};
@@ -24,13 +25,25 @@ use tokio_util::sync::CancellationToken;
 /// Sender for writing to a running command's stdin
 type CommandStdinWriter = Arc<Mutex<Option<tokio::process::ChildStdin>>>;
 
-use app::{App, ProcessesView};
+use app::{App, LogsView, ProcessesView};
 use config::LaramuxConfig;
+use editor::FileRef;
 use error::Result;
 use event::Event;
-use log::{find_log_dir, LogWatcher};
+use export::ExportFormat;
+use log::{LogSources, LogWatcher, DEFAULT_LOG_GLOB};
+use process::crash;
+use process::instance::{self, StartupMode};
+use process::sail;
+use process::stats;
+use process::supervisor::{self, SupervisorAction};
+use process::supervisor_patch;
 use process::types::OutputLine;
-use process::{discover_services, ProcessManager, ProcessStatus};
+use process::types::{ExitReason, ProcessId, ProcessSignal, RunRecord};
+use process::{
+    discover_services, find_project_root, is_laravel_root, is_sail_project, ProcessManager,
+    ProcessStatus,
+};
 use ui::tabs::Tab;
 
 const TICK_RATE: Duration = Duration::from_millis(100);
@@ -38,9 +51,11 @@ const TICK_RATE: Duration = Duration::from_millis(100);
 #[tokio::main]
 async fn main() -> Result<()> {
     let args: Vec<String> = std::env::args().collect();
+    let mut start_dir = None;
     if args.len() > 1 {
         match args[1].as_str() {
             "update" => return update::run_update().await,
+            "sail" => return run_sail_command(args.get(2).map(String::as_str)),
             "--version" | "-V" => {
                 println!("laramux {}", env!("CARGO_PKG_VERSION"));
                 return Ok(());
@@ -49,6 +64,11 @@ async fn main() -> Result<()> {
                 update::print_help();
                 return Ok(());
             }
+            "--dir" if args.len() == 3 => start_dir = Some(PathBuf::from(&args[2])),
+            "--dir" => {
+                eprintln!("Usage: laramux --dir <path>");
+                std::process::exit(1);
+            }
             arg => {
                 eprintln!("Unknown argument: {arg}");
                 std::process::exit(1);
@@ -56,16 +76,46 @@ async fn main() -> Result<()> {
         }
     }
 
+    // Search upwards from --dir or the current directory for the project root
+    let start_dir = match start_dir {
+        Some(dir) => match dir.canonicalize() {
+            Ok(dir) if dir.is_dir() => dir,
+            _ => {
+                eprintln!("Not a directory: {}", dir.display());
+                std::process::exit(1);
+            }
+        },
+        None => std::env::current_dir()?,
+    };
+    let working_dir = find_project_root(&start_dir);
+
     // Install panic hook for terminal restoration
     tui::install_panic_hook();
 
-    // Get working directory
-    let working_dir = std::env::current_dir()?;
-
     // Run the application
     run(working_dir).await
 }
 
+/// Handle `laramux sail <subcommand>`
+fn run_sail_command(subcommand: Option<&str>) -> Result<()> {
+    match subcommand {
+        Some("restore-supervisor") => {
+            let working_dir = find_project_root(&std::env::current_dir()?);
+            supervisor_patch::restore_config(&working_dir)?;
+            println!("Supervisor config restored and reloaded.");
+            Ok(())
+        }
+        Some(other) => {
+            eprintln!("Unknown sail command: {other}");
+            std::process::exit(1);
+        }
+        None => {
+            eprintln!("Usage: laramux sail restore-supervisor");
+            std::process::exit(1);
+        }
+    }
+}
+
 async fn run(working_dir: PathBuf) -> Result<()> {
     // Load configuration (optional)
     let (config, config_error) = match LaramuxConfig::load(&working_dir) {
@@ -77,6 +127,23 @@ async fn run(working_dir: PathBuf) -> Result<()> {
         }
     };
 
+    // Don't fight another laramux over the same project, and clean up after a dead one
+    // The lock is held until laramux exits
+    let (attached, _instance_lock) = match instance::prepare(&working_dir) {
+        StartupMode::Normal(lock) => (None, lock),
+        StartupMode::Attach(state) => (Some(state), None),
+        StartupMode::Abort => return Ok(()),
+    };
+
+    // Bring the Sail container up first so discovery can run inside it.
+    // Generic projects have no Sail container to manage.
+    let sail_state =
+        if is_laravel_root(&working_dir) && is_sail_project(&working_dir, config.as_ref()) {
+            Some(sail::ensure_container(&working_dir, config.as_ref()))
+        } else {
+            None
+        };
+
     // Discover available services
     let discovery_result = discover_services(&working_dir, config.as_ref())?;
     if discovery_result.configs.is_empty() {
@@ -92,19 +159,28 @@ async fn run(working_dir: PathBuf) -> Result<()> {
 
     // Initialize app state
     let mut app = App::new(working_dir.clone());
+    app.is_laravel = discovery_result.is_laravel;
+    app.console = discovery_result.console.clone();
     app.is_sail = discovery_result.is_sail;
+    if app.is_sail {
+        app.sail_state = sail_state;
+    }
     app.set_config(config);
     if let Some(error) = config_error {
         app.set_config_error(error);
     }
-    if discovery_result.is_sail {
-        if discovery_result.supervised_kinds.is_empty() {
+    if !discovery_result.is_laravel {
+        app.set_status("Laravel not detected — running custom and Procfile processes only");
+    } else if discovery_result.is_sail {
+        if !sail_state.is_some_and(|s| s.is_up()) {
+            app.set_status("Laravel Sail container is not running — press U to run sail up -d");
+        } else if discovery_result.supervised_ids.is_empty() {
             app.set_status("Laravel Sail detected — running in Docker mode");
         } else {
-            let names: Vec<&str> = discovery_result
-                .supervised_kinds
+            let names: Vec<String> = discovery_result
+                .supervised_ids
                 .iter()
-                .map(|k| k.display_name())
+                .map(|id| discovery_result.registry.display_name(id))
                 .collect();
             app.set_status(format!(
                 "Laravel Sail detected — {} supervised",
@@ -126,6 +202,14 @@ async fn run(working_dir: PathBuf) -> Result<()> {
     for config in discovery_result.configs {
         process_manager.register(config);
     }
+    let supervisor_client = discovery_result.supervisor;
+    app.has_supervisor = supervisor_client.is_some();
+    if let Some(ref client) = supervisor_client {
+        process_manager.set_supervisor(client.clone());
+    }
+    if attached.is_none() {
+        process_manager.track_state(working_dir.clone());
+    }
 
     // Command runner cancellation token (for cancelling running commands)
     let command_cancel = Arc::new(Mutex::new(None::<CancellationToken>));
@@ -135,28 +219,10 @@ async fn run(working_dir: PathBuf) -> Result<()> {
     // Initialize terminal
     let mut terminal = tui::init()?;
 
-    // Spawn input handler task
-    let input_tx = event_tx.clone();
-    let input_token = cancel_token.clone();
-    tokio::spawn(async move {
-        let mut reader = EventStream::new();
-        loop {
-            tokio::select! {
-                _ = input_token.cancelled() => break,
-                Some(Ok(event)) = reader.next() => {
-                    match event {
-                        CrosstermEvent::Key(key) if key.kind == KeyEventKind::Press => {
-                            let _ = input_tx.send(Event::Input(key)).await;
-                        }
-                        CrosstermEvent::Resize(w, h) => {
-                            let _ = input_tx.send(Event::Resize(w, h)).await;
-                        }
-                        _ => {}
-                    }
-                }
-            }
-        }
-    });
+    // Spawn input handler task; its token is replaced while a terminal
+    // editor owns the terminal
+    let mut input_token = cancel_token.child_token();
+    spawn_input_reader(event_tx.clone(), input_token.clone());
 
     // Spawn tick task
     let tick_tx = event_tx.clone();
@@ -173,76 +239,70 @@ async fn run(working_dir: PathBuf) -> Result<()> {
         }
     });
 
-    // Spawn system stats monitoring task
-    let stats_tx = event_tx.clone();
-    let stats_token = cancel_token.clone();
-    tokio::spawn(async move {
-        let mut sys = System::new_all();
-        let mut interval = tokio::time::interval(Duration::from_secs(2));
-        loop {
-            tokio::select! {
-                _ = stats_token.cancelled() => break,
-                _ = interval.tick() => {
-                    sys.refresh_all();
-
-                    let total_memory = sys.total_memory();
-                    let used_memory = sys.used_memory();
-                    let memory_usage = if total_memory > 0 {
-                        (used_memory as f32 / total_memory as f32) * 100.0
-                    } else {
-                        0.0
-                    };
-
-                    let cpu_usage = sys.global_cpu_usage();
+    // Spawn resource sampler for the managed process trees
+    let tracked_pids: stats::TrackedPids = Arc::default();
+    tokio::spawn(stats::monitor(
+        tracked_pids.clone(),
+        event_tx.clone(),
+        cancel_token.clone(),
+    ));
+
+    // Spawn Sail container monitor
+    if app.is_sail {
+        tokio::spawn(sail::monitor(
+            working_dir.clone(),
+            event_tx.clone(),
+            cancel_token.clone(),
+        ));
+    }
 
-                    let mut process_stats = std::collections::HashMap::new();
-                    for (pid, process) in sys.processes() {
-                        process_stats.insert(
-                            pid.as_u32(),
-                            app::ProcessStats {
-                                cpu_usage: process.cpu_usage(),
-                                memory_bytes: process.memory(),
-                            },
-                        );
-                    }
+    // Spawn supervisor status poller
+    if let Some(client) = supervisor_client {
+        tokio::spawn(supervisor::monitor(
+            client,
+            event_tx.clone(),
+            cancel_token.clone(),
+        ));
+    }
 
-                    let stats = app::SystemStats {
-                        cpu_usage,
-                        memory_usage,
-                        total_memory,
-                        used_memory,
-                        process_stats,
-                    };
+    // The Logs tab asks the watcher for older entries when scrolled to the top
+    let (history_tx, history_rx) = mpsc::unbounded_channel();
 
-                    let _ = stats_tx.send(Event::SystemStatsUpdate(stats)).await;
-                }
-            }
+    // Spawn log watcher for storage/logs and any configured files or globs
+    let mut patterns = Vec::new();
+    if app.is_laravel {
+        patterns.push(DEFAULT_LOG_GLOB.to_string());
+    }
+    let mut excludes = Vec::new();
+    let mut formats = Vec::new();
+    if let Some(ref config) = app.config {
+        patterns.extend_from_slice(config.additional_log_files());
+        excludes.extend_from_slice(config.log_exclude());
+        formats = config.log_source_formats();
+        patterns.extend(formats.iter().map(|(files, _)| files.clone()));
+    }
+    if !patterns.is_empty() {
+        // Patterns were checked when the config was loaded
+        if let Ok(sources) = LogSources::new(&working_dir, &patterns, &excludes)
+            .and_then(|sources| sources.with_formats(&working_dir, formats))
+        {
+            app.has_log_sources = true;
+            let watcher =
+                LogWatcher::new(sources, event_tx.clone(), history_rx, cancel_token.clone());
+            tokio::spawn(async move {
+                let _ = watcher.watch().await;
+            });
         }
-    });
-
-    // Spawn log watcher if log directory exists
-    if let Some(log_dir) = find_log_dir(&working_dir) {
-        // Get additional log files from config
-        let additional_files: Vec<std::path::PathBuf> = app
-            .config
-            .as_ref()
-            .map(|c| {
-                c.additional_log_files()
-                    .iter()
-                    .map(|f| working_dir.join(f))
-                    .collect()
-            })
-            .unwrap_or_default();
-
-        let watcher = LogWatcher::new(log_dir, event_tx.clone(), cancel_token.clone())
-            .with_additional_files(additional_files);
-        tokio::spawn(async move {
-            let _ = watcher.watch().await;
-        });
     }
 
-    // Start all processes
-    let spawn_errors = process_manager.spawn_all().await?;
+    // Start all processes, unless another instance already runs them
+    let spawn_errors = match &attached {
+        Some(state) => {
+            attach_to_instance(&mut app, state);
+            Vec::new()
+        }
+        None => process_manager.spawn_all().await?,
+    };
 
     // Update initial status
     for id in app.process_order.clone() {
@@ -273,10 +333,18 @@ async fn run(working_dir: PathBuf) -> Result<()> {
         ));
     }
 
+    // A terminal editor owns the terminal while this is set; events are
+    // still handled so process output keeps draining
+    let mut editor_open = false;
+
     // Main event loop
     loop {
         // Render UI
-        terminal.draw(|frame| ui::render(frame, &app))?;
+        if !editor_open {
+            let frame = terminal.draw(|frame| ui::render(frame, &app))?;
+            app.logs_tab.view_rows = ui::tabs::logs_view_rows(frame.area);
+            app.logs_tab.detail_max_scroll = ui::tabs::logs_detail_max_scroll(&app, frame.area);
+        }
 
         // Handle events
         if let Some(event) = event_rx.recv().await {
@@ -293,6 +361,18 @@ async fn run(working_dir: PathBuf) -> Result<()> {
                         break;
                     }
 
+                    // The export menu is modal while it is open
+                    if app.export_menu.is_some() {
+                        handle_export_menu_keys(&mut app, &key, &working_dir);
+                        continue;
+                    }
+
+                    // The signal menu is modal while it is open
+                    if app.active_tab == Tab::Processes && app.processes_tab.signal_menu.is_some() {
+                        handle_signal_menu_keys(&mut app, &key, &process_manager);
+                        continue;
+                    }
+
                     // Check for input modes first (search, command args)
                     if handle_input_mode(&mut app, &key) {
                         continue;
@@ -315,6 +395,28 @@ async fn run(working_dir: PathBuf) -> Result<()> {
                         &cancel_token,
                     )
                     .await;
+
+                    if let Some(request) = app.take_log_history_request() {
+                        let _ = history_tx.send(request);
+                    }
+
+                    if let Some(file_ref) = app.take_editor_request() {
+                        editor_open = open_in_editor(
+                            &mut app,
+                            &mut terminal,
+                            &file_ref,
+                            &mut input_token,
+                            &event_tx,
+                            &cancel_token,
+                            &working_dir,
+                        );
+                    }
+                }
+                Event::EditorExited { program, result } => {
+                    editor_open = false;
+                    editor_exited(&mut app, &mut terminal, &program, result);
+                    input_token = cancel_token.child_token();
+                    spawn_input_reader(event_tx.clone(), input_token.clone());
                 }
                 Event::ProcessOutput {
                     id,
@@ -323,51 +425,31 @@ async fn run(working_dir: PathBuf) -> Result<()> {
                 } => {
                     app.add_process_output(&id, line, is_stderr);
                 }
-                Event::ProcessExited { id, exit_code } => {
-                    let status = if process_manager.is_supervised(&id) {
-                        // Supervised: log tail ending is normal, always Stopped
-                        ProcessStatus::Stopped
-                    } else if exit_code == Some(0) {
-                        ProcessStatus::Stopped
+                Event::ProcessStarted { id, pid } => {
+                    app.begin_process_run(&id, pid);
+                    app.set_process_pid(&id, pid);
+                    if process_manager.is_supervised(&id) {
+                        app.set_process_status(&id, ProcessStatus::Supervised);
                     } else {
-                        ProcessStatus::Failed
-                    };
-                    app.set_process_status(&id, status);
-                    app.set_process_pid(&id, None);
-
-                    // Check for auto-restart based on restart policy
-                    if process_manager.should_restart(&id, exit_code) {
-                        // Record failure for backoff calculation
-                        if exit_code != Some(0) {
-                            process_manager.record_failure(&id);
-                        }
-
-                        let backoff = process_manager.get_backoff_delay(&id);
-                        let display_name = app.registry.display_name(&id);
-                        app.set_status(format!(
-                            "Auto-restarting {} in {:.1}s...",
-                            display_name,
-                            backoff.as_secs_f32()
-                        ));
-                        app.set_process_status(&id, ProcessStatus::Restarting);
-
-                        // Spawn delayed restart task
-                        let restart_id = id.clone();
-                        let restart_tx = event_tx.clone();
-                        let restart_token = cancel_token.clone();
-                        tokio::spawn(async move {
-                            tokio::select! {
-                                _ = restart_token.cancelled() => {}
-                                _ = tokio::time::sleep(backoff) => {
-                                    // Signal main loop to restart the process
-                                    let _ = restart_tx.send(Event::ProcessAutoRestart {
-                                        id: restart_id,
-                                    }).await;
-                                }
-                            }
-                        });
+                        app.set_process_status(&id, ProcessStatus::Running);
                     }
                 }
+                Event::ProcessExited {
+                    id,
+                    exit,
+                    requested,
+                } => {
+                    handle_process_exited(
+                        &mut app,
+                        &mut process_manager,
+                        &id,
+                        exit,
+                        requested,
+                        &event_tx,
+                        &cancel_token,
+                        &working_dir,
+                    );
+                }
                 Event::ProcessAutoRestart { id } => {
                     // Handle auto-restart request
                     let display_name = app.registry.display_name(&id);
@@ -388,6 +470,9 @@ async fn run(working_dir: PathBuf) -> Result<()> {
                 Event::LogUpdate(lines) => {
                     app.add_log_lines(lines);
                 }
+                Event::LogHistory(entries) => {
+                    app.prepend_log_history(entries);
+                }
                 Event::CommandOutput { line, is_stderr } => {
                     let output_line = if is_stderr {
                         OutputLine::stderr(line)
@@ -434,6 +519,38 @@ async fn run(working_dir: PathBuf) -> Result<()> {
                     // Terminal will handle resize on next draw
                 }
                 Event::Tick => {
+                    // Mirror restart history for the inspector
+                    for id in app.process_order.clone() {
+                        let state = process_manager.get_restart_state(&id).cloned();
+                        app.set_restart_state(&id, state);
+                    }
+
+                    // Keep the resource sampler pointed at the current PIDs
+                    let pids: Vec<u32> = app
+                        .process_order
+                        .iter()
+                        .filter_map(|id| app.processes.get(id).and_then(|p| p.pid))
+                        .collect();
+                    if let Ok(mut tracked) = tracked_pids.lock() {
+                        if *tracked != pids {
+                            *tracked = pids;
+                        }
+                    }
+
+                    // Pick up processes that exited on their own
+                    for (id, exit) in process_manager.reap_exited() {
+                        handle_process_exited(
+                            &mut app,
+                            &mut process_manager,
+                            &id,
+                            exit,
+                            false,
+                            &event_tx,
+                            &cancel_token,
+                            &working_dir,
+                        );
+                    }
+
                     // Update process status from manager
                     for id in app.process_order.clone() {
                         let is_running = process_manager.is_running(&id);
@@ -443,7 +560,11 @@ async fn run(working_dir: PathBuf) -> Result<()> {
                             .map(|p| p.status)
                             .unwrap_or(ProcessStatus::Stopped);
 
-                        if !matches!(current_status, ProcessStatus::Restarting) && is_running {
+                        if !matches!(
+                            current_status,
+                            ProcessStatus::Restarting | ProcessStatus::Paused
+                        ) && is_running
+                        {
                             let expected = if process_manager.is_supervised(&id) {
                                 ProcessStatus::Supervised
                             } else {
@@ -457,7 +578,76 @@ async fn run(working_dir: PathBuf) -> Result<()> {
                 }
                 Event::SystemStatsUpdate(stats) => {
                     app.system_stats = stats;
+                    if app.attached_to.is_some() {
+                        refresh_attached_processes(&mut app);
+                    }
                 }
+                Event::SailStateChanged(state) => {
+                    let was_up = app.sail_state.is_some_and(|s| s.is_up());
+                    app.sail_state = Some(state);
+
+                    if state.is_up() && !was_up {
+                        // Container is back: reattach tails and Sail-routed processes
+                        let reconnected = process_manager.reconnect_sail().await;
+                        for id in &reconnected {
+                            let status = if process_manager.is_supervised(id) {
+                                ProcessStatus::Supervised
+                            } else {
+                                ProcessStatus::Running
+                            };
+                            app.set_process_status(id, status);
+                            app.set_process_pid(id, process_manager.get_pid(id));
+                        }
+                        if reconnected.is_empty() {
+                            app.set_status("Sail container is up");
+                        } else {
+                            app.set_status(format!(
+                                "Sail container is up — reconnected {} process(es)",
+                                reconnected.len()
+                            ));
+                        }
+                    } else if !state.is_up() && was_up {
+                        app.set_status(format!(
+                            "Sail container {} — processes will reconnect when it returns",
+                            state.label()
+                        ));
+                    }
+                }
+                Event::SupervisorStatus(infos) => {
+                    app.apply_supervisor_status(&infos);
+                }
+                Event::SupervisorActionFinished { id, action, result } => {
+                    let display_name = app.registry.display_name(&id);
+                    match result {
+                        Ok(()) => {
+                            // Make sure the log tail is attached after (re)starting
+                            if action != SupervisorAction::Stop
+                                && !process_manager.is_running(&id)
+                                && process_manager.spawn(&id).await.is_ok()
+                            {
+                                app.set_process_status(&id, ProcessStatus::Supervised);
+                                app.set_process_pid(&id, process_manager.get_pid(&id));
+                            }
+                            app.set_status(format!(
+                                "Supervisor: {} {}ed",
+                                display_name,
+                                action.verb()
+                            ));
+                        }
+                        Err(e) => {
+                            app.set_status(format!(
+                                "Supervisor failed to {} {}: {}",
+                                action.verb(),
+                                display_name,
+                                e
+                            ));
+                        }
+                    }
+                }
+                Event::SailUpFinished(result) => match result {
+                    Ok(()) => app.set_status("sail up -d finished — waiting for container"),
+                    Err(e) => app.set_status(format!("sail up -d failed: {}", e)),
+                },
             }
         }
 
@@ -469,34 +659,342 @@ async fn run(working_dir: PathBuf) -> Result<()> {
     // Cleanup
     cancel_token.cancel();
     process_manager.kill_all().await?;
+    instance::remove_state(&working_dir);
     tui::restore()?;
 
     Ok(())
 }
 
+/// Forward key presses and resizes to the event loop until cancelled
+fn spawn_input_reader(tx: mpsc::Sender<Event>, token: CancellationToken) {
+    tokio::spawn(async move {
+        let mut reader = EventStream::new();
+        loop {
+            tokio::select! {
+                _ = token.cancelled() => break,
+                Some(Ok(event)) = reader.next() => {
+                    match event {
+                        CrosstermEvent::Key(key) if key.kind == KeyEventKind::Press => {
+                            let _ = tx.send(Event::Input(key)).await;
+                        }
+                        CrosstermEvent::Resize(w, h) => {
+                            let _ = tx.send(Event::Resize(w, h)).await;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+    });
+}
+
+/// Open a file reference in the configured editor. GUI editors are spawned
+/// in the background; terminal editors take over the terminal until they
+/// exit, with the input reader stopped so it does not steal their keys.
+/// Returns whether a terminal editor now owns the terminal; its exit is
+/// reported as `Event::EditorExited` so the event loop keeps running.
+fn open_in_editor(
+    app: &mut App,
+    terminal: &mut tui::Tui,
+    file_ref: &FileRef,
+    input_token: &mut CancellationToken,
+    event_tx: &mpsc::Sender<Event>,
+    cancel_token: &CancellationToken,
+    working_dir: &Path,
+) -> bool {
+    let shown = file_ref.display(working_dir);
+    if !file_ref.path.exists() {
+        app.set_status(format!("File not found: {}", shown));
+        return false;
+    }
+    let template = app.config.as_ref().and_then(|c| c.editor());
+    let Some(command) = editor::editor_command(template, file_ref) else {
+        app.set_status("Editor command is empty");
+        return false;
+    };
+
+    if !command.terminal {
+        let child = Command::new(&command.program)
+            .args(&command.args)
+            .current_dir(working_dir)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn();
+        match child {
+            Ok(mut child) => {
+                // Reap the editor (or its launcher) when it exits
+                tokio::spawn(async move {
+                    let _ = child.wait().await;
+                });
+                app.set_status(format!("Opened {} in {}", shown, command.program));
+            }
+            Err(e) => {
+                app.set_status(format!("Failed to start {}: {}", command.program, e));
+            }
+        }
+        return false;
+    }
+
+    input_token.cancel();
+    let child = tui::restore().map_err(|e| e.to_string()).and_then(|_| {
+        Command::new(&command.program)
+            .args(&command.args)
+            .current_dir(working_dir)
+            .spawn()
+            .map_err(|e| e.to_string())
+    });
+    match child {
+        Ok(mut child) => {
+            let tx = event_tx.clone();
+            let program = command.program;
+            tokio::spawn(async move {
+                let result = child.wait().await.map_err(|e| e.to_string());
+                let _ = tx.send(Event::EditorExited { program, result }).await;
+            });
+            true
+        }
+        Err(e) => {
+            editor_exited(app, terminal, &command.program, Err(e));
+            *input_token = cancel_token.child_token();
+            spawn_input_reader(event_tx.clone(), input_token.clone());
+            false
+        }
+    }
+}
+
+/// Take the terminal back from a terminal editor that exited
+fn editor_exited(
+    app: &mut App,
+    terminal: &mut tui::Tui,
+    program: &str,
+    result: std::result::Result<std::process::ExitStatus, String>,
+) {
+    if let Err(e) = tui::resume(terminal) {
+        app.set_status(format!("Failed to restore the terminal: {}", e));
+        return;
+    }
+    match result {
+        Ok(status) if status.success() => app.clear_status(),
+        Ok(status) => app.set_status(format!("{} exited with {}", program, status)),
+        Err(e) => app.set_status(format!("Failed to start {}: {}", program, e)),
+    }
+}
+
+/// Show the processes of the instance we attached to as running
+fn attach_to_instance(app: &mut App, state: &instance::InstanceState) {
+    app.attached_to = Some(state.pid);
+    for id in app.process_order.clone() {
+        if let Some(pgid) = state.groups.get(&id.to_string()).map(|g| g.pgid) {
+            if instance::group_alive(pgid) {
+                app.set_process_status(&id, ProcessStatus::Running);
+                app.set_process_pid(&id, Some(pgid as u32));
+            }
+        }
+    }
+    app.set_status(format!(
+        "Attached to laramux (PID {}) — processes are controlled there",
+        state.pid
+    ));
+}
+
+/// Mark processes of the instance we attached to as stopped once their group is gone
+fn refresh_attached_processes(app: &mut App) {
+    for id in app.process_order.clone() {
+        let Some(pid) = app.processes.get(&id).and_then(|p| p.pid) else {
+            continue;
+        };
+        if !instance::group_alive(pid as i32) {
+            app.set_process_status(&id, ProcessStatus::Stopped);
+            app.set_process_pid(&id, None);
+        }
+    }
+}
+
+/// Update state after a managed process exits and schedule an auto-restart if needed
+#[allow(clippy::too_many_arguments)]
+fn handle_process_exited(
+    app: &mut App,
+    process_manager: &mut ProcessManager,
+    id: &ProcessId,
+    exit: ExitReason,
+    requested: bool,
+    event_tx: &mpsc::Sender<Event>,
+    cancel_token: &CancellationToken,
+    working_dir: &Path,
+) {
+    let run = app.end_process_run(id, exit, requested);
+    app.set_process_pid(id, None);
+
+    // Stops and restarts set the status themselves
+    if requested {
+        return;
+    }
+
+    let status = if process_manager.is_supervised(id) {
+        // Supervised: log tail ending is normal, always Stopped
+        ProcessStatus::Stopped
+    } else if exit.is_success() {
+        ProcessStatus::Stopped
+    } else {
+        ProcessStatus::Failed
+    };
+    app.set_process_status(id, status);
+
+    if let Some(run) = run.filter(|_| status == ProcessStatus::Failed) {
+        save_crash_report(app, id, &run, working_dir);
+    }
+
+    // Sail-routed processes die with the container; they reconnect when it returns
+    let container_down = app.sail_state.is_some_and(|s| !s.is_up());
+    if container_down && process_manager.is_sail_routed(id) {
+        return;
+    }
+
+    // Record failure for backoff calculation and restart history
+    if status == ProcessStatus::Failed {
+        process_manager.record_failure(id);
+    }
+
+    // Check for auto-restart based on restart policy
+    if process_manager.should_restart(id, exit.code()) {
+        let backoff = process_manager.get_backoff_delay(id);
+        let display_name = app.registry.display_name(id);
+        app.set_status(format!(
+            "Auto-restarting {} in {:.1}s...",
+            display_name,
+            backoff.as_secs_f32()
+        ));
+        app.set_process_status(id, ProcessStatus::Restarting);
+
+        // Spawn delayed restart task
+        let restart_id = id.clone();
+        let restart_tx = event_tx.clone();
+        let restart_token = cancel_token.clone();
+        tokio::spawn(async move {
+            tokio::select! {
+                _ = restart_token.cancelled() => {}
+                _ = tokio::time::sleep(backoff) => {
+                    // Signal main loop to restart the process
+                    let _ = restart_tx.send(Event::ProcessAutoRestart {
+                        id: restart_id,
+                    }).await;
+                }
+            }
+        });
+    }
+}
+
+/// Write a crash report for a failed run and point to it in the process output
+fn save_crash_report(app: &mut App, id: &ProcessId, run: &RunRecord, working_dir: &Path) {
+    let display_name = app.registry.display_name(id);
+    let Some(process) = app.processes.get(id) else {
+        return;
+    };
+    let exit = run.exit.map(|e| e.label()).unwrap_or_default();
+
+    match crash::write_report(working_dir, &display_name, process, run) {
+        Ok(path) => {
+            let shown = path.strip_prefix(working_dir).unwrap_or(&path);
+            let line = format!(
+                "{} exited with {} after {}s. Crash report: {}",
+                display_name,
+                exit,
+                run.duration().as_secs(),
+                shown.display()
+            );
+            app.set_run_crash_report(id, run.number, path);
+            app.add_process_output(id, line, true);
+        }
+        Err(e) => {
+            app.add_process_output(id, format!("Failed to write crash report: {}", e), true);
+        }
+    }
+}
+
+/// Load the highlighted crash report into the preview pane
+fn load_crash_preview(app: &mut App) {
+    app.processes_tab.crash_preview = app
+        .processes_tab
+        .selected_crash()
+        .map(|report| {
+            std::fs::read_to_string(&report.path)
+                .unwrap_or_else(|e| format!("Failed to read {}: {}", report.path.display(), e))
+        })
+        .unwrap_or_default();
+}
+
 /// Handle input mode keys (search input, command args input)
 /// Returns true if the key was handled
 fn handle_input_mode(app: &mut App, key: &crossterm::event::KeyEvent) -> bool {
     // Logs tab search input mode
     if app.active_tab == Tab::Logs && app.logs_tab.input_mode {
-        match key.code {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let edited = match key.code {
             KeyCode::Esc => {
                 app.logs_tab.input_mode = false;
+                false
             }
             KeyCode::Enter => {
                 app.logs_tab.input_mode = false;
+                // Find mode starts at the most recent hit unless one is selected
+                if app.logs_tab.find_mode && app.logs_tab.selected_entry.is_none() {
+                    app.jump_to_log_match(false);
+                }
+                false
+            }
+            KeyCode::Tab => {
+                app.logs_tab.find_mode = !app.logs_tab.find_mode;
+                true
+            }
+            KeyCode::Char('r') if ctrl => {
+                app.logs_tab.regex = !app.logs_tab.regex;
+                true
+            }
+            KeyCode::Char('s') if ctrl => {
+                app.logs_tab.case_sensitive = !app.logs_tab.case_sensitive;
+                true
             }
             KeyCode::Backspace => {
                 app.logs_tab.search_query.pop();
+                true
             }
-            KeyCode::Char(c) => {
+            KeyCode::Char(c) if !ctrl => {
                 app.logs_tab.search_query.push(c);
+                true
             }
-            _ => {}
+            _ => false,
+        };
+        // Editing the query or its options restarts n/N from the newest hit
+        if edited {
+            app.logs_tab.selected_entry = None;
         }
+        app.logs_tab.update_search();
         return true;
     }
 
+    // Logs tab custom time range input
+    if app.active_tab == Tab::Logs {
+        if let Some(input) = app.logs_tab.range_input.as_mut() {
+            match key.code {
+                KeyCode::Esc => {
+                    app.logs_tab.range_input = None;
+                }
+                KeyCode::Enter => {
+                    app.apply_time_range();
+                }
+                KeyCode::Backspace => {
+                    input.pop();
+                }
+                KeyCode::Char(c) => {
+                    input.push(c);
+                }
+                _ => {}
+            }
+            return true;
+        }
+    }
+
     // Artisan tab input mode
     if app.active_tab == Tab::Artisan && app.artisan_tab.input_mode {
         match key.code {
@@ -666,7 +1164,7 @@ async fn handle_tab_keys(
 ) {
     match app.active_tab {
         Tab::Processes => {
-            handle_processes_keys(app, key, process_manager).await;
+            handle_processes_keys(app, key, process_manager, event_tx, working_dir).await;
         }
         Tab::Logs => {
             handle_logs_keys(app, key);
@@ -720,7 +1218,34 @@ async fn handle_processes_keys(
     app: &mut App,
     key: &crossterm::event::KeyEvent,
     process_manager: &mut ProcessManager,
+    event_tx: &mpsc::Sender<Event>,
+    working_dir: &Path,
 ) {
+    // An attached session only watches; the owning instance controls the processes
+    if let Some(owner) = app.attached_to {
+        let controls_process = matches!(
+            app.processes_tab.view,
+            ProcessesView::List | ProcessesView::Output
+        ) && match key.code {
+            KeyCode::Char('s' | 'x' | 'r' | 'R' | 'P') => true,
+            KeyCode::Char(ch) => {
+                app.processes_tab.view == ProcessesView::List
+                    && app
+                        .registry
+                        .find_by_hotkey(ch, &app.process_order)
+                        .is_some()
+            }
+            _ => false,
+        };
+        if controls_process {
+            app.set_status(format!(
+                "Attached read-only — control processes from laramux PID {}",
+                owner
+            ));
+            return;
+        }
+    }
+
     match app.processes_tab.view {
         ProcessesView::List => {
             match key.code {
@@ -732,10 +1257,43 @@ async fn handle_processes_keys(
                 }
                 KeyCode::Enter => {
                     app.processes_tab.toggle_view();
+                    app.mark_selected_read();
+                }
+                KeyCode::Char('i') => {
+                    app.processes_tab.toggle_inspector();
+                }
+                KeyCode::Char('E') => {
+                    open_export_menu(app);
+                }
+                KeyCode::Char('C') => {
+                    app.processes_tab
+                        .toggle_crashes(crash::list_reports(working_dir));
+                    load_crash_preview(app);
+                }
+                KeyCode::Char('P') => {
+                    // Open the signal menu for the selected process
+                    if let Some(id) = app.selected_id().cloned() {
+                        if process_manager.is_supervised(&id) {
+                            app.set_status(format!(
+                                "{} is managed by supervisor — signal it there",
+                                app.registry.display_name(&id)
+                            ));
+                        } else if process_manager.is_running(&id) {
+                            app.processes_tab.signal_menu = Some(0);
+                        } else {
+                            app.set_status(format!(
+                                "{} is not running",
+                                app.registry.display_name(&id)
+                            ));
+                        }
+                    }
                 }
                 KeyCode::Char('s') => {
                     // Start selected process (or tail logs for supervised)
                     if let Some(id) = app.selected_id().cloned() {
+                        if supervisor_key(app, process_manager, &id, SupervisorAction::Start) {
+                            return;
+                        }
                         let display_name = app.registry.display_name(&id);
                         let is_sup = process_manager.is_supervised(&id);
                         if is_sup {
@@ -756,13 +1314,16 @@ async fn handle_processes_keys(
                 KeyCode::Char('x') => {
                     // Stop selected process (or detach for supervised)
                     if let Some(id) = app.selected_id().cloned() {
+                        if supervisor_key(app, process_manager, &id, SupervisorAction::Stop) {
+                            return;
+                        }
                         let display_name = app.registry.display_name(&id);
                         if process_manager.is_supervised(&id) {
                             app.set_status(format!("Detaching from {}...", display_name));
                         } else {
                             app.set_status(format!("Stopping {}...", display_name));
                         }
-                        let _ = process_manager.kill(&id).await;
+                        let _ = process_manager.stop(&id).await;
                         app.set_process_status(&id, ProcessStatus::Stopped);
                         app.clear_status();
                     }
@@ -770,58 +1331,129 @@ async fn handle_processes_keys(
                 KeyCode::Char('r') => {
                     // Restart selected process (or reconnect for supervised)
                     if let Some(id) = app.selected_id().cloned() {
+                        if supervisor_key(app, process_manager, &id, SupervisorAction::Restart) {
+                            return;
+                        }
                         let display_name = app.registry.display_name(&id);
                         if process_manager.is_supervised(&id) {
                             app.set_status(format!("Reconnecting to {}...", display_name));
                         } else {
                             app.set_status(format!("Restarting {}...", display_name));
                         }
-                        app.set_process_status(&id, ProcessStatus::Restarting);
-                        let _ = process_manager.restart(&id).await;
-                        if process_manager.is_supervised(&id) {
-                            app.set_process_status(&id, ProcessStatus::Supervised);
-                        }
+                        restart_process(app, process_manager, &id).await;
                         app.clear_status();
                     }
                 }
+                KeyCode::Char('U') if app.is_sail => {
+                    // Bring the Sail container up; the monitor picks up the state change
+                    app.set_status("Running sail up -d...");
+                    tokio::spawn(sail::sail_up_background(
+                        working_dir.to_path_buf(),
+                        event_tx.clone(),
+                    ));
+                }
                 KeyCode::Char('R') => {
                     // Restart all
                     app.set_status("Restarting all processes...");
                     for id in app.process_order.clone() {
                         app.set_process_status(&id, ProcessStatus::Restarting);
                     }
-                    let _ = process_manager.restart_all().await;
-                    // Status will be updated by tick event when processes are running
+                    // Status is updated as each process reports it started
+                    for (id, error) in process_manager.restart_all().await {
+                        app.set_process_status(&id, ProcessStatus::Failed);
+                        app.add_process_output(
+                            &id,
+                            format!("ERROR: Failed to restart process: {}", error),
+                            true,
+                        );
+                    }
                     app.clear_status();
                 }
                 KeyCode::Char(ch) => {
                     // Dynamic hotkey handling via registry (s/v/q/h/b)
                     if let Some(id) = app.registry.find_by_hotkey(ch, &app.process_order) {
+                        if supervisor_key(app, process_manager, &id, SupervisorAction::Restart) {
+                            return;
+                        }
                         let display_name = app.registry.display_name(&id);
                         app.set_status(format!("Restarting {}...", display_name));
-                        app.set_process_status(&id, ProcessStatus::Restarting);
-                        let _ = process_manager.restart(&id).await;
-                        // Status will be updated by tick event when process is running
+                        // Status is updated when the process reports it started
+                        restart_process(app, process_manager, &id).await;
                         app.clear_status();
                     }
                 }
                 _ => {}
             }
         }
+        ProcessesView::Inspector => match key.code {
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('i') => {
+                app.processes_tab.toggle_inspector();
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                app.processes_tab.inspector_scroll =
+                    app.processes_tab.inspector_scroll.saturating_sub(1);
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                app.processes_tab.inspector_scroll =
+                    app.processes_tab.inspector_scroll.saturating_add(1);
+            }
+            _ => {}
+        },
+        ProcessesView::Crashes => match key.code {
+            KeyCode::Esc | KeyCode::Char('C') => {
+                app.processes_tab.toggle_crashes(Vec::new());
+            }
+            KeyCode::Up | KeyCode::Char('k') if app.processes_tab.select_crash(-1) => {
+                load_crash_preview(app);
+            }
+            KeyCode::Down | KeyCode::Char('j') if app.processes_tab.select_crash(1) => {
+                load_crash_preview(app);
+            }
+            KeyCode::PageUp => {
+                app.processes_tab.crash_scroll = app.processes_tab.crash_scroll.saturating_sub(10);
+            }
+            KeyCode::PageDown => {
+                app.processes_tab.crash_scroll = app.processes_tab.crash_scroll.saturating_add(10);
+            }
+            _ => {}
+        },
         ProcessesView::Output => match key.code {
             KeyCode::Enter | KeyCode::Esc => {
                 app.processes_tab.toggle_view();
             }
+            KeyCode::Char('E') => {
+                open_export_menu(app);
+            }
+            KeyCode::Char('o') => {
+                app.open_output_file_ref();
+            }
+            KeyCode::Char('[') => {
+                app.step_viewed_run(-1);
+            }
+            KeyCode::Char(']') => {
+                app.step_viewed_run(1);
+            }
             KeyCode::Char('c') => {
                 app.clear_selected_output();
             }
+            KeyCode::Char('f') => {
+                app.toggle_raw_output();
+            }
+            KeyCode::Char('n') => {
+                app.jump_to_error(true);
+            }
+            KeyCode::Char('N') => {
+                app.jump_to_error(false);
+            }
             KeyCode::Char('r') => {
                 if let Some(id) = app.selected_id().cloned() {
+                    if supervisor_key(app, process_manager, &id, SupervisorAction::Restart) {
+                        return;
+                    }
                     let display_name = app.registry.display_name(&id);
                     app.set_status(format!("Restarting {}...", display_name));
-                    app.set_process_status(&id, ProcessStatus::Restarting);
-                    let _ = process_manager.restart(&id).await;
-                    // Status will be updated by tick event when process is running
+                    // Status is updated when the process reports it started
+                    restart_process(app, process_manager, &id).await;
                     app.clear_status();
                 }
             }
@@ -842,7 +1474,155 @@ async fn handle_processes_keys(
     }
 }
 
+/// Open the export menu for the Logs tab or the selected process
+fn open_export_menu(app: &mut App) {
+    match app.active_tab {
+        Tab::Logs if app.filtered_logs().is_empty() => {
+            app.set_status("No log entries to export");
+        }
+        Tab::Processes if app.selected_process().is_none() => {}
+        Tab::Logs | Tab::Processes => app.export_menu = Some(0),
+        _ => {}
+    }
+}
+
+fn handle_export_menu_keys(app: &mut App, key: &crossterm::event::KeyEvent, working_dir: &Path) {
+    let Some(selected) = app.export_menu else {
+        return;
+    };
+    let formats = ExportFormat::all();
+
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('E') | KeyCode::Char('q') => {
+            app.export_menu = None;
+        }
+        KeyCode::Up | KeyCode::Char('k') => {
+            app.export_menu = Some(selected.saturating_sub(1));
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            app.export_menu = Some((selected + 1).min(formats.len() - 1));
+        }
+        KeyCode::Enter => {
+            app.export_menu = None;
+            let format = formats[selected];
+            let (name, content, what) = if app.active_tab == Tab::Logs {
+                let logs = app.filtered_logs();
+                (
+                    "logs".to_string(),
+                    export::render_logs(&logs, format),
+                    format!("{} log entries", logs.len()),
+                )
+            } else {
+                let Some(process) = app.selected_process() else {
+                    return;
+                };
+                (
+                    process.id.to_string(),
+                    export::render_output(&process.output, format),
+                    format!("{} lines", process.output.len()),
+                )
+            };
+
+            match export::write_export(working_dir, &name, format, &content) {
+                Ok(path) => {
+                    let path = path.strip_prefix(working_dir).unwrap_or(&path);
+                    app.set_status(format!("Exported {} to {}", what, path.display()));
+                }
+                Err(e) => app.set_status(format!("Export failed: {}", e)),
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Navigate the signal menu and send the chosen signal to the selected process
+fn handle_signal_menu_keys(
+    app: &mut App,
+    key: &crossterm::event::KeyEvent,
+    process_manager: &ProcessManager,
+) {
+    let Some(selected) = app.processes_tab.signal_menu else {
+        return;
+    };
+    let signals = ProcessSignal::all();
+
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('P') | KeyCode::Char('q') => {
+            app.processes_tab.signal_menu = None;
+        }
+        KeyCode::Up | KeyCode::Char('k') => {
+            app.processes_tab.signal_menu = Some(selected.saturating_sub(1));
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            app.processes_tab.signal_menu = Some((selected + 1).min(signals.len() - 1));
+        }
+        KeyCode::Enter => {
+            app.processes_tab.signal_menu = None;
+            let Some(id) = app.selected_id().cloned() else {
+                return;
+            };
+            let signal = signals[selected];
+            let display_name = app.registry.display_name(&id);
+
+            match process_manager.send_signal(&id, signal) {
+                Ok(()) => {
+                    if let Some(process) = app.processes.get(&id) {
+                        let status = signal.status_after(process.status);
+                        app.set_process_status(&id, status);
+                    }
+                    app.set_status(format!("Sent {} to {}", signal.name(), display_name));
+                }
+                Err(e) => app.set_status(format!("{}: {}", display_name, e)),
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Restart a process, marking it failed if it cannot be spawned again
+async fn restart_process(app: &mut App, process_manager: &mut ProcessManager, id: &ProcessId) {
+    app.set_process_status(id, ProcessStatus::Restarting);
+    if let Err(e) = process_manager.restart(id).await {
+        app.set_process_status(id, ProcessStatus::Failed);
+        app.add_process_output(id, format!("ERROR: Failed to restart process: {}", e), true);
+    }
+}
+
+/// Route a start/stop/restart of a supervised process through supervisord.
+/// Returns false when the process is not under supervisor control.
+fn supervisor_key(
+    app: &mut App,
+    process_manager: &ProcessManager,
+    id: &ProcessId,
+    action: SupervisorAction,
+) -> bool {
+    if !process_manager.supervisor_action(id, action) {
+        return false;
+    }
+    let verb = match action {
+        SupervisorAction::Start => "Starting",
+        SupervisorAction::Stop => "Stopping",
+        SupervisorAction::Restart => "Restarting",
+    };
+    app.set_status(format!(
+        "{} {} via supervisor...",
+        verb,
+        app.registry.display_name(id)
+    ));
+    true
+}
+
 fn handle_logs_keys(app: &mut App, key: &crossterm::event::KeyEvent) {
+    if app.logs_tab.detail_open {
+        handle_log_detail_keys(app, key);
+        return;
+    }
+    match app.logs_tab.view {
+        LogsView::Exceptions => return handle_exception_keys(app, key),
+        LogsView::Occurrences => return handle_occurrence_keys(app, key),
+        LogsView::Entries => {}
+    }
+
     match key.code {
         KeyCode::Char('/') => {
             app.logs_tab.input_mode = true;
@@ -852,24 +1632,56 @@ fn handle_logs_keys(app: &mut App, key: &crossterm::event::KeyEvent) {
         }
         KeyCode::Char('F') => {
             app.logs_tab.cycle_file();
+            app.log_history.exhausted = false;
         }
         KeyCode::Char('c') => {
             app.clear_logs();
         }
+        KeyCode::Char('e') => {
+            app.toggle_selected_expanded();
+        }
+        KeyCode::Char('z') => {
+            app.logs_tab.toggle_expand_all();
+        }
+        KeyCode::Char('t') => {
+            app.cycle_time_filter();
+        }
+        KeyCode::Char('T') => {
+            app.logs_tab.range_input = Some(String::new());
+        }
+        KeyCode::Char('a') => {
+            app.logs_tab.relative_times = !app.logs_tab.relative_times;
+        }
+        KeyCode::Char('n') if app.logs_tab.find_mode => {
+            app.jump_to_log_match(true);
+        }
+        KeyCode::Char('N') if app.logs_tab.find_mode => {
+            app.jump_to_log_match(false);
+        }
         KeyCode::Char('g') => {
-            // Go to top
-            let filtered = app.filtered_logs();
-            app.logs_tab.scroll_offset = filtered.len();
+            app.scroll_log_to_top();
         }
         KeyCode::Char('G') => {
-            // Go to bottom
-            app.logs_tab.scroll_offset = 0;
+            // Go to bottom and follow new entries again
+            app.scroll_log_to_bottom();
         }
         KeyCode::Up | KeyCode::Char('k') => {
-            app.scroll_log_up(1);
+            app.select_log_entry(-1);
         }
         KeyCode::Down | KeyCode::Char('j') => {
-            app.scroll_log_down(1);
+            app.select_log_entry(1);
+        }
+        KeyCode::Enter => {
+            app.open_log_detail();
+        }
+        KeyCode::Esc => {
+            app.logs_tab.selected_entry = None;
+        }
+        KeyCode::Char('x') => {
+            app.logs_tab.toggle_exceptions();
+        }
+        KeyCode::Char('E') => {
+            open_export_menu(app);
         }
         KeyCode::PageUp => {
             app.scroll_log_up(10);
@@ -881,6 +1693,98 @@ fn handle_logs_keys(app: &mut App, key: &crossterm::event::KeyEvent) {
     }
 }
 
+/// Keys in the Logs tab's exception groups view
+fn handle_exception_keys(app: &mut App, key: &crossterm::event::KeyEvent) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('x') => {
+            app.logs_tab.toggle_exceptions();
+        }
+        KeyCode::Up | KeyCode::Char('k') => {
+            app.select_exception(-1);
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            app.select_exception(1);
+        }
+        KeyCode::Char('s') => {
+            app.logs_tab.exception_sort = app.logs_tab.exception_sort.toggle();
+            app.logs_tab.exception_index = 0;
+        }
+        KeyCode::Char('c') => {
+            app.clear_logs();
+        }
+        KeyCode::Enter => {
+            app.open_exception_group();
+        }
+        _ => {}
+    }
+}
+
+/// Keys in the occurrences of one exception group
+fn handle_occurrence_keys(app: &mut App, key: &crossterm::event::KeyEvent) {
+    match key.code {
+        KeyCode::Esc => {
+            app.logs_tab.view = LogsView::Exceptions;
+        }
+        KeyCode::Char('x') => {
+            app.logs_tab.toggle_exceptions();
+        }
+        KeyCode::Up | KeyCode::Char('k') => {
+            app.select_occurrence(-1);
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            app.select_occurrence(1);
+        }
+        KeyCode::Enter => {
+            app.open_occurrence_detail();
+        }
+        _ => {}
+    }
+}
+
+/// Keys while the log entry detail pane is open
+fn handle_log_detail_keys(app: &mut App, key: &crossterm::event::KeyEvent) {
+    let tab = &mut app.logs_tab;
+    match key.code {
+        KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
+            tab.detail_open = false;
+        }
+        KeyCode::Up | KeyCode::Char('k') => {
+            tab.detail_scroll = tab.detail_scroll.saturating_sub(1);
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            tab.detail_scroll = tab
+                .detail_scroll
+                .saturating_add(1)
+                .min(tab.detail_max_scroll);
+        }
+        KeyCode::PageUp => {
+            tab.detail_scroll = tab.detail_scroll.saturating_sub(10);
+        }
+        KeyCode::PageDown => {
+            tab.detail_scroll = tab
+                .detail_scroll
+                .saturating_add(10)
+                .min(tab.detail_max_scroll);
+        }
+        KeyCode::Char('g') => {
+            tab.detail_scroll = 0;
+        }
+        KeyCode::Char('G') => {
+            tab.detail_scroll = tab.detail_max_scroll;
+        }
+        KeyCode::Char('n') => {
+            app.select_detail_ref(1);
+        }
+        KeyCode::Char('N') => {
+            app.select_detail_ref(-1);
+        }
+        KeyCode::Char('o') => {
+            app.open_detail_ref();
+        }
+        _ => {}
+    }
+}
+
 async fn handle_artisan_keys(
     app: &mut App,
     key: &crossterm::event::KeyEvent,
@@ -984,10 +1888,11 @@ async fn handle_artisan_keys(
             }
 
             let user_args = app.artisan_tab.input_buffer.clone();
-            if let Some(resolved) =
+            let resolved = app.console.as_ref().and_then(|console| {
                 app.artisan_tab
-                    .selected_command_resolved(&user_args, &favorites, app.is_sail)
-            {
+                    .selected_command_resolved(&user_args, &favorites, console)
+            });
+            if let Some(resolved) = resolved {
                 spawn_command(
                     app,
                     resolved,
@@ -1120,10 +2025,11 @@ async fn handle_make_keys(
             }
 
             let user_args = app.make_tab.input_buffer.clone();
-            if let Some(resolved) =
+            let resolved = app.console.as_ref().and_then(|console| {
                 app.make_tab
-                    .selected_command_resolved(&user_args, &favorites, app.is_sail)
-            {
+                    .selected_command_resolved(&user_args, &favorites, console)
+            });
+            if let Some(resolved) = resolved {
                 spawn_command(
                     app,
                     resolved,
@@ -1485,15 +2391,11 @@ fn handle_config_keys(app: &mut App, key: &crossterm::event::KeyEvent, working_d
                 app.config_tab.edit_mode = ConfigEditMode::Browse;
                 app.config_tab.enum_selection = 0;
             }
-            KeyCode::Up | KeyCode::Char('k') => {
-                if app.config_tab.enum_selection > 0 {
-                    app.config_tab.enum_selection -= 1;
-                }
+            KeyCode::Up | KeyCode::Char('k') if app.config_tab.enum_selection > 0 => {
+                app.config_tab.enum_selection -= 1;
             }
-            KeyCode::Down | KeyCode::Char('j') => {
-                if app.config_tab.enum_selection < max_enum {
-                    app.config_tab.enum_selection += 1;
-                }
+            KeyCode::Down | KeyCode::Char('j') if app.config_tab.enum_selection < max_enum => {
+                app.config_tab.enum_selection += 1;
             }
             KeyCode::Enter => {
                 // Apply enum selection
@@ -1635,14 +2537,13 @@ fn handle_config_keys(app: &mut App, key: &crossterm::event::KeyEvent, working_d
         }
 
         // Toggle (for Disabled section)
-        KeyCode::Char(' ') => {
+        KeyCode::Char(' ')
             if app.config_tab.focus == ConfigFocus::Details
-                && app.config_tab.section == ConfigSection::Disabled
-            {
-                if let Some(ref mut draft) = app.config_tab.config_draft {
-                    draft.toggle_item(app.config_tab.selected_item);
-                    app.config_tab.has_changes = true;
-                }
+                && app.config_tab.section == ConfigSection::Disabled =>
+        {
+            if let Some(ref mut draft) = app.config_tab.config_draft {
+                draft.toggle_item(app.config_tab.selected_item);
+                app.config_tab.has_changes = true;
             }
         }
 
@@ -1663,64 +2564,52 @@ fn handle_config_keys(app: &mut App, key: &crossterm::event::KeyEvent, working_d
         }
 
         // Add new item
-        KeyCode::Char('a') => {
-            if app.config_tab.focus == ConfigFocus::Details && !in_field_view {
-                handle_config_add(app);
-            }
+        KeyCode::Char('a') if app.config_tab.focus == ConfigFocus::Details && !in_field_view => {
+            handle_config_add(app);
         }
 
         // Delete item
-        KeyCode::Char('d') => {
-            if app.config_tab.focus == ConfigFocus::Details && !in_field_view {
-                // Guard: sections with fixed items can't delete
-                let can_delete = match app.config_tab.section {
-                    ConfigSection::Disabled | ConfigSection::Overrides | ConfigSection::Sail => {
-                        false
-                    }
-                    ConfigSection::Logs => app.config_tab.selected_item >= 2,
-                    _ => true,
-                };
-                if can_delete {
-                    let count = get_config_item_count(app);
-                    if count > 0 && app.config_tab.selected_item < count {
-                        app.config_tab.confirm_delete = Some(app.config_tab.selected_item);
-                    }
+        KeyCode::Char('d') if app.config_tab.focus == ConfigFocus::Details && !in_field_view => {
+            // Guard: sections with fixed items can't delete
+            let can_delete = match app.config_tab.section {
+                ConfigSection::Disabled | ConfigSection::Overrides | ConfigSection::Sail => false,
+                ConfigSection::Logs => app.config_tab.selected_item >= 2,
+                _ => true,
+            };
+            if can_delete {
+                let count = get_config_item_count(app);
+                if count > 0 && app.config_tab.selected_item < count {
+                    app.config_tab.confirm_delete = Some(app.config_tab.selected_item);
                 }
             }
         }
 
         // Toggle enabled (for Custom processes)
-        KeyCode::Char('e') => {
+        KeyCode::Char('e')
             if app.config_tab.focus == ConfigFocus::Details
-                && app.config_tab.section == ConfigSection::Custom
-            {
-                if let Some(ref mut draft) = app.config_tab.config_draft {
-                    if let Some(cp) = draft.custom.get_mut(app.config_tab.selected_item) {
-                        cp.enabled = !cp.enabled;
-                        app.config_tab.has_changes = true;
-                    }
+                && app.config_tab.section == ConfigSection::Custom =>
+        {
+            if let Some(ref mut draft) = app.config_tab.config_draft {
+                if let Some(cp) = draft.custom.get_mut(app.config_tab.selected_item) {
+                    cp.enabled = !cp.enabled;
+                    app.config_tab.has_changes = true;
                 }
             }
         }
 
         // Save configuration
-        KeyCode::Char('s') => {
-            if app.config_tab.has_changes && !in_field_view {
-                save_config(app, working_dir);
-            }
+        KeyCode::Char('s') if app.config_tab.has_changes && !in_field_view => {
+            save_config(app, working_dir);
         }
 
         // Reset changes
-        KeyCode::Char('r') => {
-            if !in_field_view {
-                app.config_tab.config_draft =
-                    Some(app::ConfigDraft::from_config(app.config.as_ref()));
-                app.config_tab.has_changes = false;
-                app.config_tab.selected_item = 0;
-                app.config_tab.scroll_offset = 0;
-                app.config_tab.detail_view = ConfigDetailView::ItemList;
-                app.set_status("Changes reset");
-            }
+        KeyCode::Char('r') if !in_field_view => {
+            app.config_tab.config_draft = Some(app::ConfigDraft::from_config(app.config.as_ref()));
+            app.config_tab.has_changes = false;
+            app.config_tab.selected_item = 0;
+            app.config_tab.scroll_offset = 0;
+            app.config_tab.detail_view = ConfigDetailView::ItemList;
+            app.set_status("Changes reset");
         }
 
         // Escape - go back through view hierarchy
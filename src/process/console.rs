@@ -0,0 +1,108 @@
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use crate::process::sail::SAIL_BIN;
+
+/// Laravel's console script
+pub const ARTISAN: &str = "artisan";
+
+/// Symfony's console script
+pub const SYMFONY_CONSOLE: &str = "bin/console";
+
+/// A Symfony Console application (Artisan, Symfony's `bin/console`, a Laravel
+/// Zero app) whose commands fill the Artisan and Make tabs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsoleBinary {
+    /// Script path relative to the project root, e.g. `artisan` or `bin/console`
+    pub script: String,
+    /// Run through Sail instead of the local PHP
+    pub is_sail: bool,
+}
+
+impl ConsoleBinary {
+    pub fn new(script: impl Into<String>, is_sail: bool) -> Self {
+        Self {
+            script: script.into(),
+            is_sail,
+        }
+    }
+
+    pub fn is_artisan(&self) -> bool {
+        self.script == ARTISAN
+    }
+
+    /// Short name shown in the UI: `artisan`, `console`, or the app's binary name
+    pub fn name(&self) -> &str {
+        Path::new(&self.script)
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or(&self.script)
+    }
+
+    /// Program and arguments that run a console command
+    pub fn invocation(&self, args: &[String]) -> (String, Vec<String>) {
+        let (program, mut full_args) = match (self.is_sail, self.is_artisan()) {
+            (true, true) => (SAIL_BIN, vec![ARTISAN.to_string()]),
+            (true, false) => (SAIL_BIN, vec!["php".to_string(), self.script.clone()]),
+            (false, _) => ("php", vec![self.script.clone()]),
+        };
+        full_args.extend(args.iter().cloned());
+        (program.to_string(), full_args)
+    }
+
+    /// Command line shown for a console command, e.g. `php bin/console cache:clear`
+    /// or `sail artisan migrate`
+    pub fn display(&self, command: &str) -> String {
+        let (program, args) = self.invocation(&[command.to_string()]);
+        let program = Path::new(&program)
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or(&program);
+        format!("{} {}", program, args.join(" "))
+    }
+
+    /// A `Command` that runs the console in the project root
+    pub fn command(&self, working_dir: &Path, args: &[&str]) -> Command {
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        let (program, args) = self.invocation(&args);
+        let mut command = Command::new(program);
+        command
+            .args(args)
+            .current_dir(working_dir)
+            .stdin(Stdio::null());
+        command
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_console_invocation() {
+        let artisan = ConsoleBinary::new(ARTISAN, false);
+        assert_eq!(artisan.display("migrate"), "php artisan migrate");
+        assert_eq!(
+            ConsoleBinary::new(ARTISAN, true).display("migrate"),
+            "sail artisan migrate"
+        );
+
+        let symfony = ConsoleBinary::new(SYMFONY_CONSOLE, true);
+        assert_eq!(symfony.name(), "console");
+        assert_eq!(
+            symfony.invocation(&["list".to_string()]),
+            (
+                SAIL_BIN.to_string(),
+                vec![
+                    "php".to_string(),
+                    "bin/console".to_string(),
+                    "list".to_string()
+                ]
+            )
+        );
+        assert_eq!(
+            symfony.display("cache:clear"),
+            "sail php bin/console cache:clear"
+        );
+    }
+}
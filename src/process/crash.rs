@@ -0,0 +1,224 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use jiff::tz::TimeZone;
+
+use crate::error::Result;
+use crate::log::time::format_datetime;
+use crate::process::output::plain_text;
+use crate::process::types::{OutputLine, Process, RunRecord};
+
+/// Directory crash reports are written to, relative to the project root
+pub const CRASH_DIR: &str = ".laramux/crashes";
+
+/// Output lines included at the end of a crash report
+const CRASH_REPORT_LINES: usize = 200;
+
+/// Oldest reports are deleted once the directory holds more than this
+const MAX_CRASH_REPORTS: usize = 50;
+
+/// A crash report found on disk
+#[derive(Debug, Clone)]
+pub struct CrashReport {
+    pub path: PathBuf,
+    /// Value of the report's `Process:` header
+    pub process: String,
+    /// Value of the report's `Exit:` header
+    pub exit: String,
+    pub written_at: SystemTime,
+}
+
+/// Write a crash report for a failed run and prune old reports.
+/// Returns the path of the new report.
+pub fn write_report(
+    working_dir: &Path,
+    display_name: &str,
+    process: &Process,
+    run: &RunRecord,
+) -> Result<PathBuf> {
+    let dir = working_dir.join(CRASH_DIR);
+    fs::create_dir_all(&dir)?;
+
+    let ended_at = run.ended_at.unwrap_or_else(SystemTime::now);
+    let file_name = format!(
+        "{}-{}-run{}.log",
+        unix_secs(ended_at),
+        sanitize_file_name(&process.id.to_string()),
+        run.number
+    );
+    let path = dir.join(file_name);
+
+    let index = process
+        .runs
+        .iter()
+        .position(|r| r.number == run.number)
+        .unwrap_or(usize::MAX);
+    let output: Vec<&OutputLine> = process.run_output(index).collect();
+    fs::write(&path, render_report(display_name, process, run, &output))?;
+
+    prune_reports(&dir);
+    Ok(path)
+}
+
+/// Build the text of a crash report
+pub fn render_report(
+    display_name: &str,
+    process: &Process,
+    run: &RunRecord,
+    output: &[&OutputLine],
+) -> String {
+    let config = &process.config;
+    let mut report = String::new();
+
+    report.push_str(&format!("Process: {}\n", display_name));
+    report.push_str(&format!(
+        "Exit: {}\n",
+        run.exit.map(|e| e.label()).unwrap_or_default()
+    ));
+    report.push_str(&format!("Run: {}\n", run.number));
+    report.push_str(&format!("Started: {}\n", format_utc(run.started_at)));
+    if let Some(ended_at) = run.ended_at {
+        report.push_str(&format!("Ended: {}\n", format_utc(ended_at)));
+    }
+    report.push_str(&format!("Duration: {}s\n", run.duration().as_secs()));
+    if let Some(pid) = run.pid {
+        report.push_str(&format!("PID: {}\n", pid));
+    }
+
+    report.push_str("\n[config]\n");
+    report.push_str(&format!(
+        "command: {} {}\n",
+        config.command,
+        config.args.join(" ")
+    ));
+    report.push_str(&format!("working_dir: {}\n", config.working_dir.display()));
+    report.push_str(&format!("restart_policy: {:?}\n", config.restart_policy));
+
+    report.push_str("\n[env]\n");
+    let mut env: Vec<(&String, &String)> = config.env.iter().collect();
+    env.sort();
+    for (key, value) in env {
+        report.push_str(&format!("{}={}\n", key, value));
+    }
+
+    let skip = output.len().saturating_sub(CRASH_REPORT_LINES);
+    report.push_str(&format!("\n[output] last {} lines\n", output.len() - skip));
+    for line in &output[skip..] {
+        report.push_str(&plain_text(&line.content));
+        report.push('\n');
+    }
+
+    report
+}
+
+/// Crash reports on disk, newest first
+pub fn list_reports(working_dir: &Path) -> Vec<CrashReport> {
+    let Ok(entries) = fs::read_dir(working_dir.join(CRASH_DIR)) else {
+        return Vec::new();
+    };
+
+    let mut reports: Vec<CrashReport> = entries
+        .flatten()
+        .filter(|e| e.path().extension().is_some_and(|ext| ext == "log"))
+        .map(|e| {
+            let path = e.path();
+            let content = fs::read_to_string(&path).unwrap_or_default();
+            let header = |key: &str| {
+                content
+                    .lines()
+                    .take_while(|l| !l.is_empty())
+                    .find_map(|l| l.strip_prefix(key))
+                    .unwrap_or_default()
+                    .trim()
+                    .to_string()
+            };
+            CrashReport {
+                process: header("Process:"),
+                exit: header("Exit:"),
+                written_at: e
+                    .metadata()
+                    .and_then(|m| m.modified())
+                    .unwrap_or(UNIX_EPOCH),
+                path,
+            }
+        })
+        .collect();
+
+    reports.sort_by_key(|r| std::cmp::Reverse(r.written_at));
+    reports
+}
+
+/// Delete the oldest reports beyond the retention limit
+fn prune_reports(dir: &Path) {
+    let Some(working_dir) = dir.parent().and_then(Path::parent) else {
+        return;
+    };
+    for report in list_reports(working_dir).iter().skip(MAX_CRASH_REPORTS) {
+        let _ = fs::remove_file(&report.path);
+    }
+}
+
+fn sanitize_file_name(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect()
+}
+
+fn unix_secs(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// Format a timestamp as `YYYY-MM-DD HH:MM:SS UTC`
+pub fn format_utc(time: SystemTime) -> String {
+    format!(
+        "{} UTC",
+        format_datetime(unix_secs(time) as i64, &TimeZone::UTC)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::process::types::{ExitReason, ProcessConfig, ProcessKind};
+    use std::time::Duration;
+
+    #[test]
+    fn test_format_utc() {
+        assert_eq!(format_utc(UNIX_EPOCH), "1970-01-01 00:00:00 UTC");
+        assert_eq!(
+            format_utc(UNIX_EPOCH + Duration::from_secs(1_709_251_199)),
+            "2024-02-29 23:59:59 UTC"
+        );
+    }
+
+    #[test]
+    fn test_write_and_list_reports() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = ProcessConfig::new(ProcessKind::Queue, "php", dir.path().to_path_buf());
+        config
+            .env
+            .insert("QUEUE".to_string(), "default".to_string());
+        let mut process = Process::new(config);
+
+        process.begin_run(Some(42));
+        process.add_output(OutputLine::stderr(
+            "\u{1b}[31mPDOException\u{1b}[0m".to_string(),
+        ));
+        process.end_run(ExitReason::Code(255), false);
+        let run = process.runs.back().unwrap().clone();
+
+        let path = write_report(dir.path(), "Queue", &process, &run).unwrap();
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.contains("Exit: exit 255\n"));
+        assert!(content.contains("QUEUE=default\n"));
+        assert!(content.ends_with("PDOException\n"));
+
+        let reports = list_reports(dir.path());
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].process, "Queue");
+        assert_eq!(reports[0].exit, "exit 255");
+    }
+}
@@ -1,11 +1,16 @@
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 
 use serde::Deserialize;
 
-use crate::config::LaramuxConfig;
+use crate::config::{LaramuxConfig, OutputConfig, CONFIG_FILE};
 use crate::error::{LaraMuxError, Result};
+use crate::process::console::{ConsoleBinary, ARTISAN, SYMFONY_CONSOLE};
+use crate::process::output::OutputRules;
+use crate::process::sail;
+use crate::process::supervisor::{SupervisorClient, SUPERVISOR_CONF};
+use crate::process::supervisor_patch;
 use crate::process::types::{ProcessConfig, ProcessId, ProcessKind, ProcessRegistry};
 
 /// A discovered quality tool (PHPStan, Pint, Rector, Pest, etc.)
@@ -21,14 +26,20 @@ pub struct QualityTool {
 
 /// Result of service discovery
 pub struct DiscoveryResult {
+    /// False in generic mode, where only custom and Procfile processes run
+    pub is_laravel: bool,
     pub is_sail: bool,
     pub configs: Vec<ProcessConfig>,
     pub registry: ProcessRegistry,
+    /// Console application behind the Artisan and Make tabs, if any
+    pub console: Option<ConsoleBinary>,
     pub artisan_commands: Vec<FullArtisanCommand>,
     pub artisan_make_commands: Vec<FullArtisanCommand>,
     pub quality_tools: Vec<QualityTool>,
     pub testing_tools: Vec<QualityTool>,
-    pub supervised_kinds: Vec<ProcessKind>,
+    pub supervised_ids: Vec<ProcessId>,
+    /// Client for controlling supervisord (Sail projects with supervised programs)
+    pub supervisor: Option<SupervisorClient>,
 }
 
 /// Check if Laravel Herd is installed (macOS or Windows)
@@ -86,25 +97,33 @@ fn is_herd_installed() -> bool {
     }
 }
 
-/// Check if Laravel Sail is present and its Docker container is running
-fn is_sail_project(working_dir: &Path) -> bool {
-    if !working_dir.join("vendor/bin/sail").exists() {
-        return false;
-    }
-    // Verify the Sail container is actually running
-    let output = Command::new("docker")
-        .args(["compose", "ps", "-q", "laravel.test"])
-        .current_dir(working_dir)
-        .stdin(Stdio::null())
-        .stdout(Stdio::piped())
-        .stderr(Stdio::null())
-        .output();
-    match output {
-        Ok(o) if o.status.success() => {
-            !o.stdout.is_empty() && !o.stdout.iter().all(|b| b.is_ascii_whitespace())
-        }
-        _ => false,
-    }
+/// Check if the project runs under Laravel Sail.
+///
+/// The `sail` config setting wins; otherwise the Sail binary decides. The
+/// container does not need to be running — it is brought up separately.
+pub fn is_sail_project(working_dir: &Path, config: Option<&LaramuxConfig>) -> bool {
+    config
+        .and_then(|c| c.sail_override())
+        .unwrap_or_else(|| sail::has_sail(working_dir))
+}
+
+/// Find the project root for `start`: the nearest directory, walking up,
+/// with a `.laramux.json` or a `composer.json` requiring `laravel/framework`.
+/// Falls back to `start` so generic projects still run from where they are.
+pub fn find_project_root(start: &Path) -> PathBuf {
+    start
+        .ancestors()
+        .find(|dir| dir.join(CONFIG_FILE).is_file() || is_laravel_root(dir))
+        .unwrap_or(start)
+        .to_path_buf()
+}
+
+/// Whether `dir` has a `composer.json` requiring `laravel/framework`
+pub fn is_laravel_root(dir: &Path) -> bool {
+    std::fs::read_to_string(dir.join("composer.json"))
+        .ok()
+        .and_then(|content| serde_json::from_str::<ComposerJson>(&content).ok())
+        .is_some_and(|composer| composer.requires("laravel/framework"))
 }
 
 /// Info about a supervised program parsed from the supervisor config
@@ -115,206 +134,254 @@ struct SupervisedProgram {
     log_file: Option<String>,
 }
 
-/// Detect services managed by supervisord inside the Sail container.
-///
-/// Sail's supervisord config typically lacks a [unix_http_server] section, so
-/// `supervisorctl status` won't work. Instead we:
-///   1. Read the supervisor config to find [program:X] sections
-///   2. Map program names to ProcessKind via fuzzy match
-///   3. Extract log file paths for per-program tailing
-///
-/// Returns a map of ProcessKind → SupervisedProgram for detected programs.
-/// Programs are included if they are defined in the config, regardless of
-/// whether they are currently running (supervisor manages their lifecycle).
-fn detect_supervised_services(working_dir: &Path) -> HashMap<ProcessKind, SupervisedProgram> {
-    let mut supervised = HashMap::new();
-
-    // Read the supervisor config inside the container
-    let config_output = Command::new("docker")
-        .args([
-            "compose",
-            "exec",
-            "-T",
-            "laravel.test",
-            "cat",
-            "/etc/supervisor/conf.d/supervisord.conf",
-        ])
-        .current_dir(working_dir)
-        .stdin(Stdio::null())
-        .stdout(Stdio::piped())
-        .stderr(Stdio::null())
-        .output();
-
-    let config_text = match config_output {
-        Ok(output) if output.status.success() => String::from_utf8(output.stdout).ok(),
-        _ => None,
-    };
-
-    let Some(config_text) = config_text else {
-        return supervised;
-    };
+/// The parts of a supervisord config file laramux cares about
+#[derive(Default)]
+struct ParsedSupervisorConfig {
+    /// [program:X] sections, in file order
+    programs: Vec<SupervisedProgram>,
+    /// Glob patterns from `[include] files=`
+    includes: Vec<String>,
+    /// The `[unix_http_server] file=` path, if configured
+    socket_file: Option<String>,
+}
 
-    // Parse config: extract [program:NAME] sections with their stdout_logfile
-    let mut current_kind: Option<(ProcessKind, String)> = None;
-    let mut current_log: Option<String> = None;
-
-    let map_name_to_kind = |name: &str| -> Option<ProcessKind> {
-        let lower = name.to_lowercase();
-        if lower.contains("horizon") {
-            Some(ProcessKind::Horizon)
-        } else if lower.contains("reverb") {
-            Some(ProcessKind::Reverb)
-        } else if lower.contains("worker") || lower.contains("queue") {
-            Some(ProcessKind::Queue)
-        } else if lower.contains("vite") {
-            Some(ProcessKind::Vite)
-        } else {
-            None
-        }
-    };
+/// Supervisor programs and control socket found in the container's config
+struct DetectedSupervisor {
+    programs: Vec<SupervisedProgram>,
+    /// The `[unix_http_server] file=` path, if configured
+    socket_file: Option<String>,
+}
 
-    // Helper to flush the current section
-    let flush = |kind: &mut Option<(ProcessKind, String)>,
-                 log: &mut Option<String>,
-                 map: &mut HashMap<ProcessKind, SupervisedProgram>| {
-        if let Some((k, name)) = kind.take() {
-            map.insert(
-                k,
-                SupervisedProgram {
-                    name,
-                    log_file: log.take(),
-                },
-            );
-        }
-        *log = None;
-    };
+/// Parse a supervisord config file
+fn parse_supervisor_config(config_text: &str) -> ParsedSupervisorConfig {
+    let mut parsed = ParsedSupervisorConfig::default();
+    let mut section = String::new();
+    let mut current: Option<SupervisedProgram> = None;
 
     for line in config_text.lines() {
         let trimmed = line.trim();
 
         // New section header
         if trimmed.starts_with('[') && trimmed.ends_with(']') {
-            // Flush previous section
-            flush(&mut current_kind, &mut current_log, &mut supervised);
-
-            // Check if this is a [program:NAME] we care about
-            if trimmed.starts_with("[program:") {
-                let name = &trimmed[9..trimmed.len() - 1];
-                if let Some(kind) = map_name_to_kind(name) {
-                    current_kind = Some((kind, name.to_string()));
-                }
+            // Flush previous program section
+            parsed.programs.extend(current.take());
+
+            section = trimmed[1..trimmed.len() - 1].trim().to_string();
+            if let Some(name) = section.strip_prefix("program:") {
+                current = Some(SupervisedProgram {
+                    name: name.trim().to_string(),
+                    log_file: None,
+                });
             }
             continue;
         }
 
-        // Parse stdout_logfile within a matched section
-        if current_kind.is_some() && trimmed.starts_with("stdout_logfile=") {
-            let path = trimmed.trim_start_matches("stdout_logfile=").trim();
-            // Only use real file paths, not /dev/stdout
-            if !path.starts_with("/dev/") && path.starts_with('/') {
-                current_log = Some(path.to_string());
+        let Some((key, value)) = trimmed.split_once('=') else {
+            continue;
+        };
+        let (key, value) = (key.trim(), value.trim());
+
+        match section.as_str() {
+            "unix_http_server" if key == "file" => {
+                parsed.socket_file = Some(value.to_string());
+            }
+            "include" if key == "files" => {
+                parsed
+                    .includes
+                    .extend(value.split_whitespace().map(|p| p.to_string()));
+            }
+            _ => {
+                // Only use real file paths, not /dev/stdout
+                if let Some(program) = current.as_mut() {
+                    if key == "stdout_logfile"
+                        && !value.starts_with("/dev/")
+                        && value.starts_with('/')
+                    {
+                        program.log_file = Some(value.to_string());
+                    }
+                }
             }
         }
     }
     // Flush last section
-    flush(&mut current_kind, &mut current_log, &mut supervised);
+    parsed.programs.extend(current.take());
 
-    // If any programs log to /dev/stdout (no per-program log file), automatically
-    // set up per-program log files inside the container
-    let needs_setup: Vec<String> = supervised
-        .iter()
-        .filter(|(_, info)| info.log_file.is_none())
-        .map(|(_, info)| info.name.clone())
-        .collect();
+    parsed
+}
 
-    if !needs_setup.is_empty() && setup_supervised_log_files(working_dir, &needs_setup) {
-        for info in supervised.values_mut() {
-            if info.log_file.is_none() {
-                info.log_file = Some(format!("/var/log/supervisor/{}.log", info.name));
-            }
-        }
+/// Read files inside the Sail container, returning their concatenated contents.
+///
+/// Patterns are passed as positional arguments and glob-expanded by the shell,
+/// never interpolated into the script itself.
+fn read_container_files(working_dir: &Path, patterns: &[String]) -> Option<String> {
+    let output = Command::new("docker")
+        .args(["compose", "exec", "-T", sail::SAIL_SERVICE, "sh", "-c"])
+        .arg(r#"for p in "$@"; do for f in $p; do [ -f "$f" ] && cat "$f" && echo; done; done"#)
+        .arg("sh")
+        .args(patterns)
+        .current_dir(working_dir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .output();
+
+    match output {
+        Ok(output) if output.status.success() => String::from_utf8(output.stdout).ok(),
+        _ => None,
     }
+}
 
-    supervised
+/// Map a supervisor program name to a built-in kind via fuzzy match
+fn kind_for_program_name(name: &str) -> Option<ProcessKind> {
+    let lower = name.to_lowercase();
+    if lower.contains("horizon") {
+        Some(ProcessKind::Horizon)
+    } else if lower.contains("reverb") {
+        Some(ProcessKind::Reverb)
+    } else if lower.contains("worker") || lower.contains("queue") {
+        Some(ProcessKind::Queue)
+    } else if lower.contains("vite") {
+        Some(ProcessKind::Vite)
+    } else {
+        None
+    }
 }
 
-/// Set up per-program log files for supervised services that log to /dev/stdout.
+/// Detect services managed by supervisord inside the Sail container.
 ///
-/// Modifies the supervisor config inside the running container to redirect each
-/// program's output to individual log files under /var/log/supervisor/, then
-/// reloads supervisor via SIGHUP. Targeted programs will briefly restart.
+/// Sail's supervisord config often lacks a [unix_http_server] section, so
+/// `supervisorctl status` can't be relied on for discovery. Instead we:
+///   1. Read the supervisor config, plus any files pulled in via [include]
+///   2. Collect every [program:X] section
+///   3. Extract log file paths for per-program tailing, falling back to the
+///      combined container log for programs that write to /dev/stdout
 ///
-/// Uses a marker file to avoid re-running on subsequent laramux starts within
-/// the same container lifetime.
-fn setup_supervised_log_files(working_dir: &Path, program_names: &[String]) -> bool {
-    if program_names.is_empty() {
-        return true;
+/// Programs are included if they are defined in the config, regardless of
+/// whether they are currently running (supervisor manages their lifecycle).
+fn detect_supervised_services(
+    working_dir: &Path,
+    config: Option<&LaramuxConfig>,
+) -> DetectedSupervisor {
+    let mut detected = DetectedSupervisor {
+        programs: Vec::new(),
+        socket_file: None,
+    };
+
+    // Read the supervisor config inside the container
+    let Some(config_text) = read_container_files(working_dir, &[SUPERVISOR_CONF.to_string()])
+    else {
+        return detected;
+    };
+
+    let parsed = parse_supervisor_config(&config_text);
+    detected.socket_file = parsed.socket_file;
+    detected.programs = parsed.programs;
+
+    // Pull in [include] files; relative patterns resolve against the config's directory
+    if !parsed.includes.is_empty() {
+        let config_dir = Path::new(SUPERVISOR_CONF)
+            .parent()
+            .unwrap_or(Path::new("/"));
+        let patterns: Vec<String> = parsed
+            .includes
+            .iter()
+            .map(|p| config_dir.join(p).to_string_lossy().to_string())
+            .collect();
+
+        if let Some(included) = read_container_files(working_dir, &patterns) {
+            // Supervisor only honours [include] in the main config, so no recursion
+            for program in parse_supervisor_config(&included).programs {
+                // The main config may match its own include glob
+                if !detected.programs.iter().any(|p| p.name == program.name) {
+                    detected.programs.push(program);
+                }
+            }
+        }
     }
 
-    let targets = program_names.join(",");
-    let touch_files: String = program_names
+    // Programs logging to /dev/stdout have no per-program log file. When
+    // `sail.patch_supervisor_logs` is on, offer to redirect them to files.
+    let patch_enabled = config
+        .map(|c| c.sail_patch_supervisor_logs())
+        .unwrap_or(false);
+    let needs_setup: Vec<String> = detected
+        .programs
         .iter()
-        .map(|n| format!("/var/log/supervisor/{}.log", n))
-        .collect::<Vec<_>>()
-        .join(" ");
-
-    let script = format!(
-        r#"MARKER="/tmp/.laramux-logging-setup"
-[ -f "$MARKER" ] && exit 0
-mkdir -p /var/log/supervisor
-touch {touch_files}
-CONFIG="/etc/supervisor/conf.d/supervisord.conf"
-grep -q "stdout_logfile=/dev/stdout" "$CONFIG" 2>/dev/null || {{ touch "$MARKER"; exit 0; }}
-awk -v targets="{targets}" '
-BEGIN {{ split(targets, t, ","); for (i in t) target[t[i]] = 1 }}
-/^\[program:/ {{
-    prog = $0; sub(/^\[program:/, "", prog); sub(/\].*/, "", prog)
-    is_target = (prog in target)
-}}
-/^\[/ && !/^\[program:/ {{ is_target = 0 }}
-is_target && /^stdout_logfile=\/dev\/stdout/ {{
-    print "stdout_logfile=/var/log/supervisor/" prog ".log"
-    print "stdout_logfile_maxbytes=10MB"
-    next
-}}
-is_target && /^stderr_logfile=\/dev\/stderr/ {{
-    print "stderr_logfile=/var/log/supervisor/" prog ".log"
-    print "stderr_logfile_maxbytes=0"
-    next
-}}
-is_target && /^stdout_logfile_maxbytes=/ {{ next }}
-is_target && /^stderr_logfile_maxbytes=/ {{ next }}
-{{ print }}
-' "$CONFIG" > "${{CONFIG}}.tmp" && mv "${{CONFIG}}.tmp" "$CONFIG"
-kill -HUP 1
-sleep 1
-touch "$MARKER""#
-    );
+        .filter(|info| info.log_file.is_none())
+        .map(|info| info.name.clone())
+        .collect();
 
-    let output = Command::new("docker")
-        .args([
-            "compose",
-            "exec",
-            "-T",
-            "laravel.test",
-            "bash",
-            "-c",
-            &script,
-        ])
-        .current_dir(working_dir)
-        .stdin(Stdio::null())
-        .stdout(Stdio::piped())
-        .stderr(Stdio::null())
-        .output();
+    if patch_enabled && !needs_setup.is_empty() {
+        let patched = supervisor_patch::patch_log_files(working_dir, &needs_setup);
+        for info in detected.programs.iter_mut() {
+            if patched.contains(&info.name) {
+                info.log_file = Some(supervisor_patch::log_file_for(&info.name));
+            }
+        }
+    }
 
-    matches!(output, Ok(ref o) if o.status.success())
+    detected
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Default, Deserialize)]
 struct ComposerJson {
     require: Option<std::collections::HashMap<String, String>>,
     #[serde(rename = "require-dev")]
     require_dev: Option<std::collections::HashMap<String, String>>,
+    bin: Option<ComposerBin>,
+}
+
+/// Composer's `bin` field, a single path or a list of paths
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ComposerBin {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl ComposerJson {
+    fn requires(&self, package: &str) -> bool {
+        self.require
+            .as_ref()
+            .is_some_and(|r| r.contains_key(package))
+    }
+
+    fn bins(&self) -> Vec<&str> {
+        match &self.bin {
+            Some(ComposerBin::One(bin)) => vec![bin.as_str()],
+            Some(ComposerBin::Many(bins)) => bins.iter().map(String::as_str).collect(),
+            None => Vec::new(),
+        }
+    }
+}
+
+/// Find the Symfony Console application for the Artisan and Make tabs: the
+/// configured `console_binary`, Laravel's artisan, Symfony's bin/console, or
+/// a Laravel Zero app's binary
+fn detect_console(
+    working_dir: &Path,
+    config: Option<&LaramuxConfig>,
+    composer: &ComposerJson,
+    is_laravel: bool,
+    is_sail: bool,
+) -> Option<ConsoleBinary> {
+    let script = if let Some(configured) = config.and_then(|c| c.console_binary()) {
+        configured.to_string()
+    } else if is_laravel {
+        ARTISAN.to_string()
+    } else if working_dir.join(SYMFONY_CONSOLE).is_file() {
+        SYMFONY_CONSOLE.to_string()
+    } else if composer.requires("laravel-zero/framework") {
+        composer
+            .bins()
+            .into_iter()
+            .chain(["application"])
+            .find(|bin| working_dir.join(bin).is_file())?
+            .to_string()
+    } else {
+        return None;
+    };
+    Some(ConsoleBinary::new(script, is_sail))
 }
 
 #[derive(Debug, Deserialize)]
@@ -334,26 +401,49 @@ fn apply_overrides(
 ) -> ProcessConfig {
     if let Some(cfg) = laramux_config {
         if let Some(override_cfg) = cfg.get_override(kind.config_name()) {
+            let key = |field: &str| format!("overrides.{}.{}", kind.config_name(), field);
             if let Some(ref cmd) = override_cfg.command {
                 config.command = cmd.clone();
+                config = config.with_source("command", key("command"));
             }
             if let Some(ref args) = override_cfg.args {
                 config.args = args.clone();
+                config = config.with_source("args", key("args"));
             }
             if let Some(ref wd) = override_cfg.working_dir {
                 config.working_dir = working_dir.join(wd);
+                config = config.with_source("working_dir", key("working_dir"));
             }
             if let Some(ref env) = override_cfg.env {
                 config.env = env.clone();
+                config = config.with_source("env", key("env"));
             }
             if let Some(restart_policy) = override_cfg.restart_policy {
                 config.restart_policy = restart_policy;
+                config = config.with_source("restart_policy", key("restart_policy"));
+            }
+            if let Some(rules) = compile_output_rules(override_cfg.output.as_ref()) {
+                config = config
+                    .with_output_rules(rules)
+                    .with_source("output", key("output"));
             }
         }
     }
     config
 }
 
+/// How to run laramux outside a Laravel project, appended to the detection errors
+const GENERIC_MODE_HINT: &str =
+    "To use laramux without Laravel, add custom processes to .laramux.json or a Procfile.";
+
+/// Procfiles read in generic mode, in order of preference
+const PROCFILES: &[&str] = &["Procfile.dev", "Procfile"];
+
+/// Compile a process's `output` rules (patterns were checked when the config loaded)
+fn compile_output_rules(output: Option<&OutputConfig>) -> Option<OutputRules> {
+    output.and_then(|o| OutputRules::compile(o).ok())
+}
+
 /// Discover available Laravel services in the project
 pub fn discover_services(
     working_dir: &Path,
@@ -362,50 +452,83 @@ pub fn discover_services(
     let mut configs = Vec::new();
     let mut registry = ProcessRegistry::new();
 
-    // Detect Laravel Sail (project-level, takes precedence over Herd)
-    let is_sail = match config.and_then(|c| c.sail) {
-        Some(forced) => forced,
-        None => is_sail_project(working_dir),
-    };
-
     // Check for Laravel (composer.json)
     let composer_path = working_dir.join("composer.json");
-    if !composer_path.exists() {
-        return Err(LaraMuxError::FileNotFound(
-            "composer.json not found. Is this a Laravel project?".to_string(),
-        ));
-    }
-
-    let composer_content = std::fs::read_to_string(&composer_path)?;
-    let composer: ComposerJson = serde_json::from_str(&composer_content)?;
+    let composer: Option<ComposerJson> = if composer_path.exists() {
+        let composer_content = std::fs::read_to_string(&composer_path)?;
+        Some(serde_json::from_str(&composer_content)?)
+    } else {
+        None
+    };
 
     // Check for Laravel framework
     let has_laravel = composer
-        .require
         .as_ref()
-        .map(|r| r.contains_key("laravel/framework"))
-        .unwrap_or(false);
+        .is_some_and(|c| c.requires("laravel/framework"));
 
-    if !has_laravel {
-        return Err(LaraMuxError::Config(
-            "Not a Laravel project (laravel/framework not found in composer.json)".to_string(),
-        ));
-    }
+    let composer = match composer {
+        Some(composer) if has_laravel => composer,
+        Some(composer) => {
+            return discover_generic(
+                working_dir,
+                config,
+                &composer,
+                LaraMuxError::Config(format!(
+                    "Not a Laravel project (laravel/framework not found in composer.json). {}",
+                    GENERIC_MODE_HINT
+                )),
+            )
+        }
+        None => {
+            return discover_generic(
+                working_dir,
+                config,
+                &ComposerJson::default(),
+                LaraMuxError::FileNotFound(format!(
+                    "composer.json not found. Is this a Laravel project? {}",
+                    GENERIC_MODE_HINT
+                )),
+            )
+        }
+    };
+
+    // Detect Laravel Sail (project-level, takes precedence over Herd)
+    let is_sail = is_sail_project(working_dir, config);
 
     // Helper to check if a process is disabled
     let is_disabled = |name: &str| config.map(|c| c.is_disabled(name)).unwrap_or(false);
 
     // Detect supervised services running inside the Sail container
-    let supervised_map = if is_sail {
-        detect_supervised_services(working_dir)
+    let detected = if is_sail {
+        detect_supervised_services(working_dir, config)
     } else {
-        HashMap::new()
+        DetectedSupervisor {
+            programs: Vec::new(),
+            socket_file: None,
+        }
     };
-    let mut supervised_kinds: Vec<ProcessKind> = Vec::new();
+
+    // Map programs to built-in kinds: explicit config mappings first, then
+    // fuzzy name matches. Anything left over becomes a custom process.
+    let mut supervised_map: HashMap<ProcessKind, &SupervisedProgram> = HashMap::new();
+    for program in &detected.programs {
+        if let Some(kind) = config.and_then(|c| c.sail_program_kind(&program.name)) {
+            supervised_map.entry(kind).or_insert(program);
+        }
+    }
+    for program in &detected.programs {
+        if supervised_map.values().any(|p| p.name == program.name) {
+            continue;
+        }
+        if let Some(kind) = kind_for_program_name(&program.name) {
+            supervised_map.entry(kind).or_insert(program);
+        }
+    }
+    let mut supervised_ids: Vec<ProcessId> = Vec::new();
 
     // Helper to create a supervised ProcessConfig that tails the program's log file
     let supervised_config =
-        |kind: ProcessKind, info: &SupervisedProgram, working_dir: &Path| -> ProcessConfig {
+        |id: ProcessId, info: &SupervisedProgram, working_dir: &Path| -> ProcessConfig {
             let (cmd, args) = if let Some(ref log_file) = info.log_file {
                 // Tail the per-program log file inside the container
                 (
@@ -437,9 +560,25 @@ pub fn discover_services(
                     ],
                 )
             };
-            ProcessConfig::new(kind, cmd, working_dir.to_path_buf())
+            let mut process_config = ProcessConfig::new(id.clone(), cmd, working_dir.to_path_buf())
                 .with_args(args)
-                .with_supervised(info.name.clone())
+                .with_supervised(info.name.clone());
+            // Output rules apply to the tailed log of a supervised built-in too
+            if let ProcessId::Builtin(kind) = id {
+                let output = config
+                    .and_then(|c| c.get_override(kind.config_name()))
+                    .and_then(|o| o.output.as_ref());
+                if let Some(rules) = compile_output_rules(output) {
+                    process_config = process_config
+                        .with_output_rules(rules)
+                        .with_source("output", format!("overrides.{}.output", kind.config_name()));
+                }
+            }
+            match config.and_then(|c| c.sail.programs.get(&info.name)) {
+                Some(_) => process_config
+                    .with_source("supervisor_program", format!("sail.programs.{}", info.name)),
+                None => process_config,
+            }
         };
 
     // Add artisan serve (unless Sail handles it, Herd is installed, or disabled)
@@ -470,8 +609,12 @@ pub fn discover_services(
     // Use Horizon if installed, otherwise fall back to basic queue:work
     if has_horizon && !is_disabled("horizon") {
         if let Some(info) = supervised_map.get(&ProcessKind::Horizon) {
-            configs.push(supervised_config(ProcessKind::Horizon, info, working_dir));
-            supervised_kinds.push(ProcessKind::Horizon);
+            configs.push(supervised_config(
+                ProcessKind::Horizon.into(),
+                info,
+                working_dir,
+            ));
+            supervised_ids.push(ProcessKind::Horizon.into());
         } else {
             let (cmd, args) = if is_sail {
                 (
@@ -493,8 +636,12 @@ pub fn discover_services(
         }
     } else if !is_disabled("queue") {
         if let Some(info) = supervised_map.get(&ProcessKind::Queue) {
-            configs.push(supervised_config(ProcessKind::Queue, info, working_dir));
-            supervised_kinds.push(ProcessKind::Queue);
+            configs.push(supervised_config(
+                ProcessKind::Queue.into(),
+                info,
+                working_dir,
+            ));
+            supervised_ids.push(ProcessKind::Queue.into());
         } else {
             let (cmd, args) = if is_sail {
                 (
@@ -541,8 +688,12 @@ pub fn discover_services(
 
     if has_reverb && !is_disabled("reverb") {
         if let Some(info) = supervised_map.get(&ProcessKind::Reverb) {
-            configs.push(supervised_config(ProcessKind::Reverb, info, working_dir));
-            supervised_kinds.push(ProcessKind::Reverb);
+            configs.push(supervised_config(
+                ProcessKind::Reverb.into(),
+                info,
+                working_dir,
+            ));
+            supervised_ids.push(ProcessKind::Reverb.into());
         } else {
             let (cmd, args) = if is_sail {
                 (
@@ -592,8 +743,12 @@ pub fn discover_services(
 
         if has_vite && has_dev_script {
             if let Some(program) = supervised_map.get(&ProcessKind::Vite) {
-                configs.push(supervised_config(ProcessKind::Vite, program, working_dir));
-                supervised_kinds.push(ProcessKind::Vite);
+                configs.push(supervised_config(
+                    ProcessKind::Vite.into(),
+                    program,
+                    working_dir,
+                ));
+                supervised_ids.push(ProcessKind::Vite.into());
             } else {
                 // Detect package manager
                 let pkg_manager = detect_package_manager(working_dir);
@@ -624,7 +779,169 @@ pub fn discover_services(
         }
     }
 
+    // Add supervised programs not claimed above: kinds the built-in checks
+    // skipped (e.g. Queue alongside Horizon), then unmapped programs
+    for program in &detected.programs {
+        let mapped_kind = supervised_map
+            .iter()
+            .find(|(_, p)| p.name == program.name)
+            .map(|(kind, _)| *kind);
+
+        let id = match mapped_kind {
+            Some(kind) => ProcessId::builtin(kind),
+            None => ProcessId::custom(program.name.clone()),
+        };
+        if supervised_ids.contains(&id) {
+            continue;
+        }
+
+        match mapped_kind {
+            Some(kind) if is_disabled(kind.config_name()) => continue,
+            Some(_) => {}
+            None => {
+                // A custom process in config with the same name takes precedence
+                let shadowed = config
+                    .map(|c| c.custom.iter().any(|p| p.name == program.name))
+                    .unwrap_or(false);
+                if shadowed {
+                    continue;
+                }
+                registry.register_custom(program.name.clone(), program.name.clone(), None);
+            }
+        }
+
+        configs.push(supervised_config(id.clone(), program, working_dir));
+        supervised_ids.push(id);
+    }
+
     // Add custom processes from config
+    configs.extend(custom_process_configs(working_dir, config, &mut registry));
+
+    // Supervisor control is only useful when something is supervised
+    let supervisor = if supervised_ids.is_empty() {
+        None
+    } else {
+        Some(SupervisorClient::new(
+            working_dir,
+            detected.socket_file.as_deref(),
+        ))
+    };
+
+    // Discover all artisan commands, and the make commands separately
+    let console = detect_console(working_dir, config, &composer, true, is_sail);
+    let (artisan_commands, artisan_make_commands) =
+        discover_console_commands(working_dir, console.as_ref());
+
+    // Discover quality and testing tools from composer.json and package.json
+    let (quality_tools, testing_tools) =
+        discover_tools(working_dir, &composer, config, is_sail, true);
+
+    Ok(DiscoveryResult {
+        is_laravel: true,
+        is_sail,
+        console,
+        configs,
+        registry,
+        artisan_commands,
+        artisan_make_commands,
+        quality_tools,
+        testing_tools,
+        supervisor,
+        supervised_ids,
+    })
+}
+
+/// Discover processes for a project without Laravel: custom processes from
+/// `.laramux.json` and the entries of a Procfile. Fails with `missing` when
+/// neither defines anything to run.
+fn discover_generic(
+    working_dir: &Path,
+    config: Option<&LaramuxConfig>,
+    composer: &ComposerJson,
+    missing: LaraMuxError,
+) -> Result<DiscoveryResult> {
+    let mut registry = ProcessRegistry::new();
+    let mut configs = custom_process_configs(working_dir, config, &mut registry);
+
+    if let Some((file_name, content)) = PROCFILES.iter().find_map(|name| {
+        std::fs::read_to_string(working_dir.join(name))
+            .ok()
+            .map(|content| (*name, content))
+    }) {
+        for (name, command) in parse_procfile(&content) {
+            // A custom process in config with the same name takes precedence
+            if configs
+                .iter()
+                .any(|c| c.id == ProcessId::custom(name.as_str()))
+            {
+                continue;
+            }
+            configs.push(
+                ProcessConfig::new(
+                    ProcessId::custom(name.clone()),
+                    "sh",
+                    working_dir.to_path_buf(),
+                )
+                .with_args(vec!["-c".to_string(), command])
+                .with_source("command", file_name)
+                .with_source("args", file_name),
+            );
+            registry.register_custom(name.clone(), name, None);
+        }
+    }
+
+    if configs.is_empty() {
+        return Err(missing);
+    }
+
+    let (quality_tools, testing_tools) =
+        discover_tools(working_dir, composer, config, false, false);
+    let console = detect_console(working_dir, config, composer, false, false);
+    let (artisan_commands, artisan_make_commands) =
+        discover_console_commands(working_dir, console.as_ref());
+
+    Ok(DiscoveryResult {
+        is_laravel: false,
+        is_sail: false,
+        console,
+        configs,
+        registry,
+        artisan_commands,
+        artisan_make_commands,
+        quality_tools,
+        testing_tools,
+        supervised_ids: Vec::new(),
+        supervisor: None,
+    })
+}
+
+/// Parse `name: command` entries from a Procfile, skipping comments and
+/// lines with an invalid process name
+fn parse_procfile(content: &str) -> Vec<(String, String)> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let (name, command) = line.split_once(':')?;
+            let (name, command) = (name.trim(), command.trim());
+            let valid_name = !name.is_empty()
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+            (valid_name && !command.is_empty()).then(|| (name.to_string(), command.to_string()))
+        })
+        .collect()
+}
+
+/// Build configs for the enabled custom processes in `.laramux.json` and
+/// register their display names and hotkeys
+fn custom_process_configs(
+    working_dir: &Path,
+    config: Option<&LaramuxConfig>,
+    registry: &mut ProcessRegistry,
+) -> Vec<ProcessConfig> {
+    let mut configs = Vec::new();
     if let Some(cfg) = config {
         for custom in cfg.enabled_custom_processes() {
             let custom_working_dir = custom
@@ -633,18 +950,33 @@ pub fn discover_services(
                 .map(|wd| working_dir.join(wd))
                 .unwrap_or_else(|| working_dir.to_path_buf());
 
+            let key = |field: &str| format!("custom.{}.{}", custom.name, field);
             let mut custom_config = ProcessConfig::new(
                 ProcessId::custom(custom.name.clone()),
                 custom.command.clone(),
                 custom_working_dir,
             )
-            .with_args(custom.args.clone());
+            .with_args(custom.args.clone())
+            .with_source("command", key("command"))
+            .with_source("args", key("args"));
 
+            if custom.working_dir.is_some() {
+                custom_config = custom_config.with_source("working_dir", key("working_dir"));
+            }
             if let Some(ref env) = custom.env {
-                custom_config = custom_config.with_env(env.clone());
+                custom_config = custom_config
+                    .with_env(env.clone())
+                    .with_source("env", key("env"));
             }
             if let Some(restart_policy) = custom.restart_policy {
-                custom_config = custom_config.with_restart_policy(restart_policy);
+                custom_config = custom_config
+                    .with_restart_policy(restart_policy)
+                    .with_source("restart_policy", key("restart_policy"));
+            }
+            if let Some(rules) = compile_output_rules(custom.output.as_ref()) {
+                custom_config = custom_config
+                    .with_output_rules(rules)
+                    .with_source("output", key("output"));
             }
 
             configs.push(custom_config);
@@ -657,13 +989,17 @@ pub fn discover_services(
             );
         }
     }
+    configs
+}
 
-    // Discover all artisan commands
-    let artisan_commands = discover_all_artisan_commands(working_dir, is_sail);
-
-    // Discover artisan make commands
-    let artisan_make_commands = discover_artisan_make_commands(working_dir, is_sail);
-
+/// Discover quality and testing tools and apply the `quality` config to them
+fn discover_tools(
+    working_dir: &Path,
+    composer: &ComposerJson,
+    config: Option<&LaramuxConfig>,
+    is_sail: bool,
+    is_laravel: bool,
+) -> (Vec<QualityTool>, Vec<QualityTool>) {
     // Load package.json if it exists
     let package_path = working_dir.join("package.json");
     let package: Option<PackageJson> = if package_path.exists() {
@@ -678,8 +1014,13 @@ pub fn discover_services(
     let package_manager = detect_package_manager(working_dir);
 
     // Discover quality and testing tools from composer.json and package.json
-    let (mut quality_tools, mut testing_tools) =
-        discover_dev_tools(&composer, package.as_ref(), &package_manager, is_sail);
+    let (mut quality_tools, mut testing_tools) = discover_dev_tools(
+        composer,
+        package.as_ref(),
+        &package_manager,
+        is_sail,
+        is_laravel,
+    );
 
     // Apply quality config: filter disabled tools, merge default args, add custom tools
     if let Some(cfg) = config {
@@ -713,16 +1054,7 @@ pub fn discover_services(
         }
     }
 
-    Ok(DiscoveryResult {
-        is_sail,
-        configs,
-        registry,
-        artisan_commands,
-        artisan_make_commands,
-        quality_tools,
-        testing_tools,
-        supervised_kinds,
-    })
+    (quality_tools, testing_tools)
 }
 
 /// Detect the package manager used in the project
@@ -748,6 +1080,7 @@ fn discover_dev_tools(
     package: Option<&PackageJson>,
     package_manager: &str,
     is_sail: bool,
+    is_laravel: bool,
 ) -> (Vec<QualityTool>, Vec<QualityTool>) {
     let mut quality_tools = Vec::new();
     let mut testing_tools = Vec::new();
@@ -920,21 +1253,11 @@ fn discover_dev_tools(
         ));
     }
 
-    // Laravel's artisan test is always available
-    if is_sail {
-        testing_tools.push(QualityTool {
-            display_name: "Artisan Test".to_string(),
-            command: "./vendor/bin/sail".to_string(),
-            args: vec![
-                "artisan".to_string(),
-                "test".to_string(),
-                "--ansi".to_string(),
-            ],
-        });
-    } else {
+    // Laravel's artisan test is always available in Laravel projects
+    if is_laravel {
         testing_tools.push(QualityTool {
             display_name: "Artisan Test".to_string(),
-            command: "php".to_string(),
+            command: if is_sail { "./vendor/bin/sail" } else { "php" }.to_string(),
             args: vec![
                 "artisan".to_string(),
                 "test".to_string(),
@@ -1061,53 +1384,82 @@ pub struct FullArtisanCommand {
     pub options: Vec<(String, String, String)>,
 }
 
-/// Discover available artisan make:* commands with full details
-fn discover_artisan_make_commands(working_dir: &Path, is_sail: bool) -> Vec<FullArtisanCommand> {
-    // Run php artisan list make --format=json (or via Sail)
-    let output = if is_sail {
-        Command::new("./vendor/bin/sail")
-            .args(["artisan", "list", "make", "--format=json"])
-            .current_dir(working_dir)
-            .stdin(Stdio::null())
-            .output()
-    } else {
-        Command::new("php")
-            .args(["artisan", "list", "make", "--format=json"])
-            .current_dir(working_dir)
-            .stdin(Stdio::null())
-            .output()
+/// Discover the console's commands and its make:* commands. The built-in
+/// Laravel lists stand in when artisan cannot be listed.
+fn discover_console_commands(
+    working_dir: &Path,
+    console: Option<&ConsoleBinary>,
+) -> (Vec<FullArtisanCommand>, Vec<FullArtisanCommand>) {
+    let Some(console) = console else {
+        return (Vec::new(), Vec::new());
     };
+    let (mut commands, mut make_commands) = (
+        discover_all_artisan_commands(working_dir, console),
+        discover_artisan_make_commands(working_dir, console),
+    );
+    if console.is_artisan() {
+        if commands.is_empty() {
+            commands = default_artisan_commands();
+        }
+        if make_commands.is_empty() {
+            make_commands = default_make_commands();
+        }
+    }
+    (commands, make_commands)
+}
 
-    let output = match output {
+/// Run `<console> list [namespace] --format=json` and parse the output
+fn list_console_commands(
+    working_dir: &Path,
+    console: &ConsoleBinary,
+    namespace: Option<&str>,
+) -> Option<ArtisanListOutput> {
+    let mut args = vec!["list"];
+    args.extend(namespace);
+    args.push("--format=json");
+    let name = console.name();
+
+    let output = match console.command(working_dir, &args).output() {
         Ok(o) if o.status.success() => o,
         Ok(o) => {
             eprintln!(
-                "[discovery] artisan list make failed: {}",
+                "[discovery] {} list failed: {}",
+                name,
                 String::from_utf8_lossy(&o.stderr)
             );
-            return default_make_commands();
+            return None;
         }
         Err(e) => {
-            eprintln!("[discovery] failed to run artisan for make commands: {}", e);
-            return default_make_commands();
+            eprintln!("[discovery] failed to run {}: {}", name, e);
+            return None;
         }
     };
 
     let json_str = match String::from_utf8(output.stdout) {
         Ok(s) => s,
         Err(e) => {
-            eprintln!("[discovery] invalid UTF-8 in artisan make output: {}", e);
-            return default_make_commands();
+            eprintln!("[discovery] invalid UTF-8 in {} output: {}", name, e);
+            return None;
         }
     };
 
-    let parsed: ArtisanListOutput = match serde_json::from_str(&json_str) {
-        Ok(p) => p,
+    match serde_json::from_str(&json_str) {
+        Ok(p) => Some(p),
         Err(e) => {
-            eprintln!("[discovery] failed to parse artisan make JSON: {}", e);
+            eprintln!("[discovery] failed to parse {} JSON: {}", name, e);
             eprintln!("[discovery] JSON snippet: {:.500}", json_str);
-            return default_make_commands();
+            None
         }
+    }
+}
+
+/// Discover available make:* commands with full details
+fn discover_artisan_make_commands(
+    working_dir: &Path,
+    console: &ConsoleBinary,
+) -> Vec<FullArtisanCommand> {
+    let Some(parsed) = list_console_commands(working_dir, console, Some("make")) else {
+        return Vec::new();
     };
 
     let mut commands: Vec<FullArtisanCommand> = parsed
@@ -1176,61 +1528,16 @@ fn discover_artisan_make_commands(working_dir: &Path, is_sail: bool) -> Vec<Full
 
     // Sort alphabetically by name
     commands.sort_by(|a, b| a.name.cmp(&b.name));
-
-    if commands.is_empty() {
-        default_make_commands()
-    } else {
-        commands
-    }
+    commands
 }
 
-/// Discover all artisan commands (excluding make:* which are handled separately)
-fn discover_all_artisan_commands(working_dir: &Path, is_sail: bool) -> Vec<FullArtisanCommand> {
-    // Run php artisan list --format=json (or via Sail)
-    let output = if is_sail {
-        Command::new("./vendor/bin/sail")
-            .args(["artisan", "list", "--format=json"])
-            .current_dir(working_dir)
-            .stdin(Stdio::null())
-            .output()
-    } else {
-        Command::new("php")
-            .args(["artisan", "list", "--format=json"])
-            .current_dir(working_dir)
-            .stdin(Stdio::null())
-            .output()
-    };
-
-    let output = match output {
-        Ok(o) if o.status.success() => o,
-        Ok(o) => {
-            eprintln!(
-                "[discovery] artisan list failed: {}",
-                String::from_utf8_lossy(&o.stderr)
-            );
-            return default_artisan_commands();
-        }
-        Err(e) => {
-            eprintln!("[discovery] failed to run artisan: {}", e);
-            return default_artisan_commands();
-        }
-    };
-
-    let json_str = match String::from_utf8(output.stdout) {
-        Ok(s) => s,
-        Err(e) => {
-            eprintln!("[discovery] invalid UTF-8 in artisan output: {}", e);
-            return default_artisan_commands();
-        }
-    };
-
-    let parsed: ArtisanListOutput = match serde_json::from_str(&json_str) {
-        Ok(p) => p,
-        Err(e) => {
-            eprintln!("[discovery] failed to parse artisan JSON: {}", e);
-            eprintln!("[discovery] JSON snippet: {:.500}", json_str);
-            return default_artisan_commands();
-        }
+/// Discover all console commands (excluding make:* which are handled separately)
+fn discover_all_artisan_commands(
+    working_dir: &Path,
+    console: &ConsoleBinary,
+) -> Vec<FullArtisanCommand> {
+    let Some(parsed) = list_console_commands(working_dir, console, None) else {
+        return Vec::new();
     };
 
     let mut commands: Vec<FullArtisanCommand> = parsed
@@ -1306,12 +1613,7 @@ fn discover_all_artisan_commands(working_dir: &Path, is_sail: bool) -> Vec<FullA
 
     // Sort alphabetically by name
     commands.sort_by(|a, b| a.name.cmp(&b.name));
-
-    if commands.is_empty() {
-        default_artisan_commands()
-    } else {
-        commands
-    }
+    commands
 }
 
 /// Default artisan commands if discovery fails
@@ -1428,3 +1730,169 @@ fn default_make_commands() -> Vec<FullArtisanCommand> {
         })
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_supervisor_config() {
+        let config = "\
+[supervisord]
+nodaemon=true
+
+[unix_http_server]
+file=/var/run/supervisor.sock
+
+[include]
+files = extra/*.conf other.conf
+
+[program:php]
+command=/usr/bin/php -d variables_order=EGPCS /var/www/html/artisan serve
+stdout_logfile=/dev/stdout
+
+[program:scheduler]
+command=php /var/www/html/artisan schedule:work
+stdout_logfile=/var/log/supervisor/scheduler.log
+";
+        let parsed = parse_supervisor_config(config);
+        let names: Vec<&str> = parsed.programs.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["php", "scheduler"]);
+        assert_eq!(parsed.programs[0].log_file, None);
+        assert_eq!(
+            parsed.programs[1].log_file.as_deref(),
+            Some("/var/log/supervisor/scheduler.log")
+        );
+        assert_eq!(parsed.includes, vec!["extra/*.conf", "other.conf"]);
+        assert_eq!(
+            parsed.socket_file.as_deref(),
+            Some("/var/run/supervisor.sock")
+        );
+    }
+
+    #[test]
+    fn test_generic_mode_from_procfile() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("Procfile"),
+            "# dev processes\nweb: node server.js --port 3000\nworker:npm run jobs\nbad name: x\n",
+        )
+        .unwrap();
+
+        let result = discover_services(dir.path(), None).unwrap();
+        assert!(!result.is_laravel);
+        let ids: Vec<String> = result.configs.iter().map(|c| c.id.to_string()).collect();
+        assert_eq!(ids, vec!["web", "worker"]);
+        assert_eq!(
+            result.configs[0].args,
+            vec!["-c".to_string(), "node server.js --port 3000".to_string()]
+        );
+        assert!(result
+            .testing_tools
+            .iter()
+            .all(|t| t.display_name != "Artisan Test"));
+
+        // Without anything to run, the Laravel check still fails
+        std::fs::remove_file(dir.path().join("Procfile")).unwrap();
+        assert!(discover_services(dir.path(), None).is_err());
+    }
+
+    #[test]
+    fn test_find_project_root() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::write(
+            root.join("composer.json"),
+            r#"{"require": {"laravel/framework": "^11.0"}}"#,
+        )
+        .unwrap();
+        // A nested non-Laravel package is not mistaken for the root
+        let package = root.join("packages/billing");
+        let models = package.join("src/Models");
+        std::fs::create_dir_all(&models).unwrap();
+        std::fs::write(package.join("composer.json"), r#"{"require": {}}"#).unwrap();
+        assert_eq!(find_project_root(&models), root);
+
+        // A .laramux.json marks a root of its own
+        std::fs::write(package.join(CONFIG_FILE), "{}").unwrap();
+        assert_eq!(find_project_root(&models), package);
+
+        // Nothing above: stay where we started
+        let outside = tempfile::tempdir().unwrap();
+        assert_eq!(find_project_root(outside.path()), outside.path());
+    }
+
+    #[test]
+    fn test_detect_console() {
+        let dir = tempfile::tempdir().unwrap();
+        let composer: ComposerJson = serde_json::from_str(
+            r#"{"require": {"laravel-zero/framework": "^11.0"}, "bin": "builds/app"}"#,
+        )
+        .unwrap();
+        let detect = |config: Option<&LaramuxConfig>| {
+            detect_console(dir.path(), config, &composer, false, false).map(|c| c.script)
+        };
+
+        // Laravel Zero: the composer bin only counts once it exists
+        assert_eq!(detect(None), None);
+        std::fs::write(dir.path().join("application"), "").unwrap();
+        assert_eq!(detect(None).as_deref(), Some("application"));
+        std::fs::create_dir(dir.path().join("builds")).unwrap();
+        std::fs::write(dir.path().join("builds/app"), "").unwrap();
+        assert_eq!(detect(None).as_deref(), Some("builds/app"));
+
+        // Symfony's bin/console, then a configured binary, take precedence
+        std::fs::create_dir(dir.path().join("bin")).unwrap();
+        std::fs::write(dir.path().join(SYMFONY_CONSOLE), "").unwrap();
+        assert_eq!(detect(None).as_deref(), Some(SYMFONY_CONSOLE));
+
+        std::fs::write(
+            dir.path().join(".laramux.json"),
+            r#"{"artisan": {"console_binary": "tools/cli"}}"#,
+        )
+        .unwrap();
+        let config = LaramuxConfig::load(dir.path()).unwrap();
+        assert_eq!(detect(config.as_ref()).as_deref(), Some("tools/cli"));
+
+        std::fs::write(
+            dir.path().join(".laramux.json"),
+            r#"{"artisan": {"console_binary": "../cli"}}"#,
+        )
+        .unwrap();
+        assert!(LaramuxConfig::load(dir.path()).is_err());
+    }
+
+    #[test]
+    fn test_override_sources() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join(".laramux.json"),
+            r#"{"overrides": {"queue": {"args": ["artisan", "queue:work", "--tries=3"], "restart_policy": "always"}}}"#,
+        )
+        .unwrap();
+        let laramux_config = LaramuxConfig::load(dir.path()).unwrap().unwrap();
+
+        let config = ProcessConfig::new(ProcessKind::Queue, "php", dir.path().to_path_buf())
+            .with_args(vec!["artisan".to_string(), "queue:listen".to_string()]);
+        let config = apply_overrides(
+            config,
+            ProcessKind::Queue,
+            Some(&laramux_config),
+            dir.path(),
+        );
+
+        assert_eq!(config.args[1], "queue:work");
+        assert_eq!(
+            config.sources.get("args").map(String::as_str),
+            Some("overrides.queue.args")
+        );
+        assert_eq!(
+            config.sources.get("restart_policy").map(String::as_str),
+            Some("overrides.queue.restart_policy")
+        );
+        // Fields the override leaves alone stay discovered
+        assert_eq!(config.command, "php");
+        assert!(!config.sources.contains_key("command"));
+        assert!(!config.sources.contains_key("env"));
+    }
+}
@@ -0,0 +1,421 @@
+use std::collections::BTreeMap;
+use std::fs::{File, OpenOptions};
+use std::io::{IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind};
+
+use crate::error::Result;
+use crate::process::stats::{command_line, process_group_of};
+
+/// Per-project file recording the running laramux and its process groups
+pub const STATE_FILE: &str = ".laramux/state.json";
+
+/// Per-project lock held by the laramux managing the project
+pub const LOCK_FILE: &str = ".laramux/lock";
+
+/// How long leftover groups get to exit after SIGTERM before SIGKILL
+const REAP_GRACE: Duration = Duration::from_secs(3);
+
+/// How long to wait for the lock holder to write its state file
+const STATE_WAIT: Duration = Duration::from_secs(2);
+
+/// Contents of the state file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InstanceState {
+    /// PID of the laramux process managing the project
+    pub pid: u32,
+    /// System boot time, so state from before a reboot is ignored
+    pub boot_time: u64,
+    /// Process group of each managed process, keyed by process id
+    #[serde(default)]
+    pub groups: BTreeMap<String, GroupRecord>,
+}
+
+/// A managed process group and its leader, so a PGID reused by an
+/// unrelated process after a crash is not mistaken for ours
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GroupRecord {
+    pub pgid: i32,
+    /// Leader's start time in seconds since the epoch
+    pub start_time: u64,
+    /// Leader's command line
+    pub command: String,
+}
+
+/// A process group left behind by a laramux session that is gone
+#[derive(Debug, Clone)]
+pub struct LeftoverGroup {
+    /// Process id the group was started for
+    pub name: String,
+    pub pgid: i32,
+    /// Live members of the group as (pid, command)
+    pub members: Vec<(u32, String)>,
+}
+
+/// Result of checking a dead session's state file at startup
+#[derive(Debug)]
+pub enum StartupCheck {
+    /// Nothing was left behind
+    Clear,
+    /// A dead session left process groups running
+    Leftovers(Vec<LeftoverGroup>),
+}
+
+/// Exclusive lock on the project. Released when dropped or when the process
+/// dies, so a crashed session never blocks the next one.
+#[derive(Debug)]
+pub struct InstanceLock {
+    #[cfg(unix)]
+    _lock: nix::fcntl::Flock<File>,
+    #[cfg(not(unix))]
+    _file: File,
+}
+
+/// How this session should start
+#[derive(Debug)]
+pub enum StartupMode {
+    /// Manage the project's processes, holding the project lock when it
+    /// could be taken
+    Normal(Option<InstanceLock>),
+    /// Another instance manages the processes; only watch them
+    Attach(InstanceState),
+    /// Exit without starting the TUI
+    Abort,
+}
+
+fn state_path(working_dir: &Path) -> PathBuf {
+    working_dir.join(STATE_FILE)
+}
+
+/// Read the project's state file
+pub fn read_state(working_dir: &Path) -> Option<InstanceState> {
+    let content = std::fs::read_to_string(state_path(working_dir)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Take the project lock without blocking. `None` when another process
+/// holds it.
+#[cfg(unix)]
+pub fn try_lock(working_dir: &Path) -> std::io::Result<Option<InstanceLock>> {
+    use nix::errno::Errno;
+    use nix::fcntl::{Flock, FlockArg};
+
+    let file = open_lock_file(working_dir)?;
+    match Flock::lock(file, FlockArg::LockExclusiveNonblock) {
+        Ok(lock) => Ok(Some(InstanceLock { _lock: lock })),
+        Err((_, Errno::EWOULDBLOCK)) => Ok(None),
+        Err((_, errno)) => Err(errno.into()),
+    }
+}
+
+#[cfg(not(unix))]
+pub fn try_lock(working_dir: &Path) -> std::io::Result<Option<InstanceLock>> {
+    Ok(Some(InstanceLock {
+        _file: open_lock_file(working_dir)?,
+    }))
+}
+
+fn open_lock_file(working_dir: &Path) -> std::io::Result<File> {
+    let path = working_dir.join(LOCK_FILE);
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(path)
+}
+
+/// Write the project's state file for this process, recording the leader
+/// of each process group
+pub fn write_state(working_dir: &Path, groups: BTreeMap<String, i32>) -> Result<()> {
+    let pids: Vec<Pid> = groups
+        .values()
+        .map(|&pgid| Pid::from_u32(pgid as u32))
+        .collect();
+    let mut sys = System::new();
+    sys.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&pids),
+        true,
+        ProcessRefreshKind::new().with_cmd(UpdateKind::OnlyIfNotSet),
+    );
+    let groups = groups
+        .into_iter()
+        .map(|(name, pgid)| {
+            let leader = sys.process(Pid::from_u32(pgid as u32));
+            let record = GroupRecord {
+                pgid,
+                start_time: leader.map_or(0, |p| p.start_time()),
+                command: leader.map(command_line).unwrap_or_default(),
+            };
+            (name, record)
+        })
+        .collect();
+
+    let state = InstanceState {
+        pid: std::process::id(),
+        boot_time: System::boot_time(),
+        groups,
+    };
+    let path = state_path(working_dir);
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    // Write to a temporary file first so readers never see a partial file
+    let tmp = path.with_extension("json.tmp");
+    std::fs::write(&tmp, serde_json::to_string_pretty(&state)?)?;
+    std::fs::rename(&tmp, &path)?;
+    Ok(())
+}
+
+/// Remove the state file if this process owns it
+pub fn remove_state(working_dir: &Path) {
+    if read_state(working_dir).is_some_and(|s| s.pid == std::process::id()) {
+        let _ = std::fs::remove_file(state_path(working_dir));
+    }
+}
+
+/// Look for leftovers of a dead session. Call with the project lock held,
+/// so the state file's owner is known to be gone.
+pub fn check(working_dir: &Path) -> StartupCheck {
+    let Some(state) = read_state(working_dir) else {
+        return StartupCheck::Clear;
+    };
+    if state.boot_time != System::boot_time() || state.pid == std::process::id() {
+        return StartupCheck::Clear;
+    }
+
+    let mut sys = System::new();
+    sys.refresh_processes_specifics(
+        ProcessesToUpdate::All,
+        true,
+        ProcessRefreshKind::new().with_cmd(UpdateKind::OnlyIfNotSet),
+    );
+
+    let leftovers: Vec<LeftoverGroup> = state
+        .groups
+        .iter()
+        .filter(|(_, record)| is_recorded_group(&sys, record))
+        .filter_map(|(name, record)| {
+            let members = group_members(&sys, record.pgid);
+            (!members.is_empty()).then(|| LeftoverGroup {
+                name: name.clone(),
+                pgid: record.pgid,
+                members,
+            })
+        })
+        .collect();
+
+    if leftovers.is_empty() {
+        StartupCheck::Clear
+    } else {
+        StartupCheck::Leftovers(leftovers)
+    }
+}
+
+/// Whether a process group is still the one recorded. A live leader must
+/// have the recorded start time; without a leader the group is ours, as a
+/// PID is not reused while its process group has members.
+fn is_recorded_group(sys: &System, record: &GroupRecord) -> bool {
+    match sys.process(Pid::from_u32(record.pgid as u32)) {
+        Some(leader) => leader.start_time() == record.start_time,
+        None => true,
+    }
+}
+
+/// Live processes belonging to a process group
+fn group_members(sys: &System, pgid: i32) -> Vec<(u32, String)> {
+    let mut members: Vec<(u32, String)> = sys
+        .processes()
+        .iter()
+        .filter(|(pid, _)| process_group_of(pid.as_u32()) == Some(pgid))
+        .map(|(pid, process)| (pid.as_u32(), command_line(process)))
+        .collect();
+    members.sort();
+    members
+}
+
+/// Whether a process group still has live members
+#[cfg(unix)]
+pub fn group_alive(pgid: i32) -> bool {
+    use nix::sys::signal::kill;
+    use nix::unistd::Pid as NixPid;
+
+    kill(NixPid::from_raw(-pgid), None).is_ok()
+}
+
+#[cfg(not(unix))]
+pub fn group_alive(_pgid: i32) -> bool {
+    false
+}
+
+/// Stop leftover groups: SIGTERM, a short grace period, then SIGKILL
+#[cfg(unix)]
+pub fn reap(groups: &[LeftoverGroup]) {
+    use nix::sys::signal::{kill, Signal};
+    use nix::unistd::Pid as NixPid;
+
+    for group in groups {
+        let _ = kill(NixPid::from_raw(-group.pgid), Signal::SIGTERM);
+        let _ = kill(NixPid::from_raw(-group.pgid), Signal::SIGCONT);
+    }
+
+    let deadline = Instant::now() + REAP_GRACE;
+    while Instant::now() < deadline && groups.iter().any(|g| group_alive(g.pgid)) {
+        std::thread::sleep(Duration::from_millis(100));
+    }
+
+    for group in groups.iter().filter(|g| group_alive(g.pgid)) {
+        let _ = kill(NixPid::from_raw(-group.pgid), Signal::SIGKILL);
+    }
+}
+
+#[cfg(not(unix))]
+pub fn reap(_groups: &[LeftoverGroup]) {}
+
+/// Take the project lock before starting and ask what to do about a
+/// running instance or leftover processes. The state file is written as
+/// soon as the lock is held, so a second start sees this one right away.
+pub fn prepare(working_dir: &Path) -> StartupMode {
+    let lock = match try_lock(working_dir) {
+        Ok(Some(lock)) => Some(lock),
+        Ok(None) => return running_instance(working_dir),
+        Err(e) => {
+            println!("Could not lock {}: {}", LOCK_FILE, e);
+            None
+        }
+    };
+
+    handle_leftovers(working_dir);
+    let _ = write_state(working_dir, BTreeMap::new());
+    StartupMode::Normal(lock)
+}
+
+/// Another laramux holds the project lock; offer to attach to it
+fn running_instance(working_dir: &Path) -> StartupMode {
+    // The lock holder writes its state right after taking the lock
+    let deadline = Instant::now() + STATE_WAIT;
+    let state = loop {
+        match read_state(working_dir) {
+            Some(state) if state.pid != std::process::id() => break Some(state),
+            _ if Instant::now() >= deadline => break None,
+            _ => std::thread::sleep(Duration::from_millis(100)),
+        }
+    };
+    let Some(state) = state else {
+        println!("laramux is already running for this project.");
+        return StartupMode::Abort;
+    };
+
+    println!(
+        "laramux is already running for this project (PID {}).",
+        state.pid
+    );
+    match ask("[a]ttach read-only or [q]uit? [a/Q] ").as_str() {
+        "a" | "attach" => StartupMode::Attach(state),
+        _ => StartupMode::Abort,
+    }
+}
+
+/// Offer to stop the process groups a dead session left running
+fn handle_leftovers(working_dir: &Path) {
+    match check(working_dir) {
+        StartupCheck::Clear => {}
+        StartupCheck::Leftovers(groups) => {
+            println!("A previous laramux session left these processes running:");
+            for group in &groups {
+                println!("  {} (process group {})", group.name, group.pgid);
+                for (pid, command) in &group.members {
+                    println!("    {:>7}  {}", pid, command);
+                }
+            }
+
+            if !std::io::stdin().is_terminal() {
+                println!("Stop them with `kill -- -<group>` if they hold ports laramux needs.");
+                return;
+            }
+            if matches!(ask("Stop them now? [Y/n] ").as_str(), "n" | "no") {
+                println!("Leaving them running.");
+            } else {
+                reap(&groups);
+                println!("Stopped {} leftover process group(s).", groups.len());
+            }
+        }
+    }
+}
+
+/// Ask a question on the terminal and return the lowercased answer.
+/// Returns an empty string when stdin is not a terminal.
+fn ask(prompt: &str) -> String {
+    if !std::io::stdin().is_terminal() {
+        return String::new();
+    }
+
+    print!("{}", prompt);
+    let _ = std::io::stdout().flush();
+
+    let mut answer = String::new();
+    if std::io::stdin().read_line(&mut answer).is_err() {
+        return String::new();
+    }
+    answer.trim().to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_state_round_trip_and_ownership() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut groups = BTreeMap::new();
+        groups.insert("serve".to_string(), 4242);
+        write_state(dir.path(), groups).unwrap();
+
+        let state = read_state(dir.path()).unwrap();
+        assert_eq!(state.pid, std::process::id());
+        assert_eq!(state.groups.get("serve").map(|g| g.pgid), Some(4242));
+
+        // Our own state file is not mistaken for another instance
+        assert!(matches!(check(dir.path()), StartupCheck::Clear));
+
+        remove_state(dir.path());
+        assert!(read_state(dir.path()).is_none());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_lock_is_exclusive() {
+        let dir = tempfile::tempdir().unwrap();
+        let lock = try_lock(dir.path()).unwrap();
+        assert!(lock.is_some());
+        assert!(try_lock(dir.path()).unwrap().is_none());
+
+        drop(lock);
+        assert!(try_lock(dir.path()).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_reused_group_is_not_ours() {
+        let pid = std::process::id();
+        let mut sys = System::new();
+        sys.refresh_processes(ProcessesToUpdate::Some(&[Pid::from_u32(pid)]), true);
+        let start_time = sys.process(Pid::from_u32(pid)).unwrap().start_time();
+
+        let mut record = GroupRecord {
+            pgid: pid as i32,
+            start_time,
+            command: String::new(),
+        };
+        assert!(is_recorded_group(&sys, &record));
+
+        // Same PID, different process
+        record.start_time = start_time - 60;
+        assert!(!is_recorded_group(&sys, &record));
+    }
+}
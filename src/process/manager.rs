@@ -1,6 +1,7 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::path::PathBuf;
 use std::process::Stdio;
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, SystemTime};
 
 use tokio::io::{AsyncBufReadExt, BufReader};
 use tokio::process::{Child, Command};
@@ -10,11 +11,32 @@ use tokio_util::sync::CancellationToken;
 use crate::config::RestartPolicy;
 use crate::error::{LaraMuxError, Result};
 use crate::event::Event;
-use crate::process::types::{ProcessConfig, ProcessId};
+use crate::process::instance;
+use crate::process::supervisor::{self, SupervisorAction, SupervisorClient};
+use crate::process::types::{ExitReason, ProcessConfig, ProcessId, ProcessSignal};
 
 /// Maximum backoff delay for restarts (60 seconds)
 const MAX_RESTART_BACKOFF_SECS: u64 = 60;
 
+/// Maximum number of restart history entries kept per process
+const MAX_RESTART_HISTORY: usize = 20;
+
+/// What happened in a restart history entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RestartEvent {
+    /// The process was spawned
+    Started,
+    /// The process exited with a failure
+    Failed,
+}
+
+/// A single entry in a process's restart history
+#[derive(Debug, Clone)]
+pub struct RestartRecord {
+    pub at: SystemTime,
+    pub event: RestartEvent,
+}
+
 /// Track restart state for a process
 #[derive(Debug, Clone, Default)]
 pub struct RestartState {
@@ -22,6 +44,8 @@ pub struct RestartState {
     pub consecutive_failures: u32,
     /// Last restart time
     pub last_restart: Option<Instant>,
+    /// Recent starts and failures, oldest first
+    pub history: VecDeque<RestartRecord>,
 }
 
 impl RestartState {
@@ -37,12 +61,24 @@ impl RestartState {
     pub fn reset(&mut self) {
         self.consecutive_failures = 0;
         self.last_restart = Some(Instant::now());
+        self.push_history(RestartEvent::Started);
     }
 
     /// Record a failure
     pub fn record_failure(&mut self) {
         self.consecutive_failures = self.consecutive_failures.saturating_add(1);
         self.last_restart = Some(Instant::now());
+        self.push_history(RestartEvent::Failed);
+    }
+
+    fn push_history(&mut self, event: RestartEvent) {
+        if self.history.len() >= MAX_RESTART_HISTORY {
+            self.history.pop_front();
+        }
+        self.history.push_back(RestartRecord {
+            at: SystemTime::now(),
+            event,
+        });
     }
 }
 
@@ -51,6 +87,12 @@ pub struct ProcessManager {
     children: HashMap<ProcessId, Child>,
     configs: HashMap<ProcessId, ProcessConfig>,
     restart_states: HashMap<ProcessId, RestartState>,
+    /// Processes the user wants running (cleared by an explicit stop)
+    wanted: HashSet<ProcessId>,
+    /// Controls supervised programs inside the Sail container
+    supervisor: Option<SupervisorClient>,
+    /// Project whose state file tracks our process groups
+    state_dir: Option<PathBuf>,
     event_tx: mpsc::Sender<Event>,
     cancel_token: CancellationToken,
 }
@@ -61,6 +103,9 @@ impl ProcessManager {
             children: HashMap::new(),
             configs: HashMap::new(),
             restart_states: HashMap::new(),
+            wanted: HashSet::new(),
+            supervisor: None,
+            state_dir: None,
             event_tx,
             cancel_token,
         }
@@ -71,6 +116,57 @@ impl ProcessManager {
         self.configs.insert(config.id.clone(), config);
     }
 
+    /// Record this instance and its process groups in the project's state file
+    pub fn track_state(&mut self, working_dir: PathBuf) {
+        self.state_dir = Some(working_dir);
+        self.save_state();
+    }
+
+    /// Rewrite the state file with the current process groups
+    fn save_state(&self) {
+        let Some(dir) = &self.state_dir else {
+            return;
+        };
+        // Every child leads its own process group, so its PID is the PGID
+        let groups: BTreeMap<String, i32> = self
+            .children
+            .iter()
+            .filter_map(|(id, child)| child.id().map(|pid| (id.to_string(), pid as i32)))
+            .collect();
+        let _ = instance::write_state(dir, groups);
+    }
+
+    /// Set the client used to control supervised programs
+    pub fn set_supervisor(&mut self, client: SupervisorClient) {
+        self.supervisor = Some(client);
+    }
+
+    /// Start, stop or restart a supervised program through supervisord.
+    /// Runs in the background and reports back via `Event::SupervisorActionFinished`.
+    /// Returns false if the process is not supervised or supervisord is not controllable.
+    pub fn supervisor_action(&self, id: &ProcessId, action: SupervisorAction) -> bool {
+        let Some(client) = self.supervisor.clone() else {
+            return false;
+        };
+        let Some(program) = self
+            .configs
+            .get(id)
+            .filter(|c| c.supervised)
+            .and_then(|c| c.supervisor_program.clone())
+        else {
+            return false;
+        };
+
+        tokio::spawn(supervisor::perform_background(
+            client,
+            id.clone(),
+            program,
+            action,
+            self.event_tx.clone(),
+        ));
+        true
+    }
+
     /// Spawn a process
     pub async fn spawn(&mut self, id: &ProcessId) -> Result<()> {
         let config = self
@@ -81,6 +177,7 @@ impl ProcessManager {
 
         // Kill existing process if running
         self.kill(id).await?;
+        self.wanted.insert(id.clone());
 
         let mut cmd = Command::new(&config.command);
         cmd.args(&config.args)
@@ -128,6 +225,13 @@ impl ProcessManager {
         self.restart_states.entry(id.clone()).or_default().reset();
 
         let pid = child.id();
+        let _ = self
+            .event_tx
+            .send(Event::ProcessStarted {
+                id: id.clone(),
+                pid,
+            })
+            .await;
 
         // Spawn stdout reader task
         if let Some(stdout) = child.stdout.take() {
@@ -188,6 +292,7 @@ impl ProcessManager {
         }
 
         self.children.insert(id.clone(), child);
+        self.save_state();
 
         // Send initial status via event
         let initial_msg = if config.supervised {
@@ -236,6 +341,7 @@ impl ProcessManager {
     /// Kill a process gracefully (SIGTERM, wait, then SIGKILL)
     pub async fn kill(&mut self, id: &ProcessId) -> Result<()> {
         if let Some(mut child) = self.children.remove(id) {
+            self.save_state();
             // Capture PID before any wait calls
             let pid = child.id();
 
@@ -247,6 +353,8 @@ impl ProcessManager {
 
                 if let Some(pid) = pid {
                     let _ = kill(Pid::from_raw(-(pid as i32)), Signal::SIGTERM);
+                    // A frozen (SIGSTOP) group only acts on SIGTERM once continued
+                    let _ = kill(Pid::from_raw(-(pid as i32)), Signal::SIGCONT);
                 }
             }
 
@@ -264,7 +372,8 @@ impl ProcessManager {
                         .event_tx
                         .send(Event::ProcessExited {
                             id: id.clone(),
-                            exit_code: status.code(),
+                            exit: ExitReason::from_status(status),
+                            requested: true,
                         })
                         .await;
                 }
@@ -280,12 +389,17 @@ impl ProcessManager {
                         }
                     }
                     let _ = child.kill().await;
-                    let _ = child.wait().await;
+                    let exit = child
+                        .wait()
+                        .await
+                        .map(ExitReason::from_status)
+                        .unwrap_or(ExitReason::Unknown);
                     let _ = self
                         .event_tx
                         .send(Event::ProcessExited {
                             id: id.clone(),
-                            exit_code: None,
+                            exit,
+                            requested: true,
                         })
                         .await;
                 }
@@ -294,6 +408,83 @@ impl ProcessManager {
         Ok(())
     }
 
+    /// Stop a process on user request, so it is not brought back on reconnect
+    pub async fn stop(&mut self, id: &ProcessId) -> Result<()> {
+        self.wanted.remove(id);
+        self.kill(id).await
+    }
+
+    /// Collect processes that exited on their own since the last call
+    pub fn reap_exited(&mut self) -> Vec<(ProcessId, ExitReason)> {
+        let mut exited = Vec::new();
+        for (id, child) in self.children.iter_mut() {
+            if let Ok(Some(status)) = child.try_wait() {
+                exited.push((id.clone(), ExitReason::from_status(status)));
+            }
+        }
+        for (id, _) in &exited {
+            self.children.remove(id);
+        }
+        if !exited.is_empty() {
+            self.save_state();
+        }
+        exited
+    }
+
+    /// Respawn wanted Sail-routed processes that are not running.
+    /// Called when the Sail container comes back up.
+    pub async fn reconnect_sail(&mut self) -> Vec<ProcessId> {
+        let ids: Vec<ProcessId> = self
+            .configs
+            .values()
+            .filter(|c| c.is_sail_routed())
+            .map(|c| c.id.clone())
+            .filter(|id| self.wanted.contains(id) && !self.children.contains_key(id))
+            .collect();
+
+        let mut reconnected = Vec::new();
+        for id in ids {
+            if self.spawn(&id).await.is_ok() {
+                reconnected.push(id);
+            }
+        }
+        reconnected
+    }
+
+    /// Check if a process runs through the Sail container
+    pub fn is_sail_routed(&self, id: &ProcessId) -> bool {
+        self.configs
+            .get(id)
+            .map(|c| c.is_sail_routed())
+            .unwrap_or(false)
+    }
+
+    /// Send a signal to a process's whole process group
+    pub fn send_signal(&self, id: &ProcessId, signal: ProcessSignal) -> Result<()> {
+        let pid = self
+            .get_pid(id)
+            .ok_or_else(|| LaraMuxError::Process(format!("{} is not running", id)))?;
+
+        #[cfg(unix)]
+        {
+            use nix::sys::signal::kill;
+            use nix::unistd::Pid;
+
+            kill(Pid::from_raw(-(pid as i32)), signal.to_nix()).map_err(|e| {
+                LaraMuxError::Process(format!("Failed to send {}: {}", signal.name(), e))
+            })
+        }
+
+        #[cfg(not(unix))]
+        {
+            let _ = pid;
+            Err(LaraMuxError::Process(format!(
+                "{} is not supported on this platform",
+                signal.name()
+            )))
+        }
+    }
+
     /// Kill all processes in parallel for fast shutdown
     pub async fn kill_all(&mut self) -> Result<()> {
         use futures::future::join_all;
@@ -330,12 +521,16 @@ impl ProcessManager {
     }
 
     /// Restart all processes
-    pub async fn restart_all(&mut self) -> Result<()> {
+    /// Returns a list of (process_id, error_message) for any that failed to restart
+    pub async fn restart_all(&mut self) -> Vec<(ProcessId, String)> {
         let ids: Vec<ProcessId> = self.configs.keys().cloned().collect();
+        let mut errors = Vec::new();
         for id in ids {
-            self.restart(&id).await?;
+            if let Err(e) = self.restart(&id).await {
+                errors.push((id, e.to_string()));
+            }
         }
-        Ok(())
+        errors
     }
 
     /// Check if a process is running
@@ -383,8 +578,7 @@ impl ProcessManager {
             .unwrap_or_default()
     }
 
-    /// Get the restart state for a process (for backoff calculation)
-    #[allow(dead_code)]
+    /// Get the restart state for a process (backoff and history)
     pub fn get_restart_state(&self, id: &ProcessId) -> Option<&RestartState> {
         self.restart_states.get(id)
     }
@@ -419,6 +613,7 @@ async fn kill_child(mut child: Child, id: &ProcessId, event_tx: &mpsc::Sender<Ev
 
         if let Some(pid) = pid {
             let _ = kill(Pid::from_raw(-(pid as i32)), Signal::SIGTERM);
+            let _ = kill(Pid::from_raw(-(pid as i32)), Signal::SIGCONT);
         }
     }
 
@@ -435,7 +630,8 @@ async fn kill_child(mut child: Child, id: &ProcessId, event_tx: &mpsc::Sender<Ev
             let _ = event_tx
                 .send(Event::ProcessExited {
                     id: id.clone(),
-                    exit_code: status.code(),
+                    exit: ExitReason::from_status(status),
+                    requested: true,
                 })
                 .await;
         }
@@ -451,11 +647,16 @@ async fn kill_child(mut child: Child, id: &ProcessId, event_tx: &mpsc::Sender<Ev
                 }
             }
             let _ = child.kill().await;
-            let _ = child.wait().await;
+            let exit = child
+                .wait()
+                .await
+                .map(ExitReason::from_status)
+                .unwrap_or(ExitReason::Unknown);
             let _ = event_tx
                 .send(Event::ProcessExited {
                     id: id.clone(),
-                    exit_code: None,
+                    exit,
+                    requested: true,
                 })
                 .await;
         }
@@ -1,7 +1,18 @@
+pub mod console;
+pub mod crash;
 pub mod discovery;
+pub mod instance;
 pub mod manager;
+pub mod output;
+pub mod sail;
+pub mod stats;
+pub mod supervisor;
+pub mod supervisor_patch;
 pub mod types;
 
-pub use discovery::{discover_services, FullArtisanCommand, QualityTool};
+pub use discovery::{
+    discover_services, find_project_root, is_laravel_root, is_sail_project, FullArtisanCommand,
+    QualityTool,
+};
 pub use manager::ProcessManager;
 pub use types::ProcessStatus;
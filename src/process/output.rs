@@ -0,0 +1,174 @@
+use ansi_to_tui::IntoText;
+use regex::Regex;
+
+use crate::config::OutputConfig;
+use crate::process::types::OutputLine;
+
+/// Style a highlight rule paints matching lines with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HighlightStyle {
+    Error,
+    Warning,
+    Success,
+    Info,
+    Accent,
+    Muted,
+    Bold,
+}
+
+impl HighlightStyle {
+    /// Style names accepted in `.laramux.json`
+    pub const NAMES: &'static [&'static str] = &[
+        "error", "warning", "success", "info", "accent", "muted", "bold",
+    ];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "error" => Some(HighlightStyle::Error),
+            "warning" => Some(HighlightStyle::Warning),
+            "success" => Some(HighlightStyle::Success),
+            "info" => Some(HighlightStyle::Info),
+            "accent" => Some(HighlightStyle::Accent),
+            "muted" => Some(HighlightStyle::Muted),
+            "bold" => Some(HighlightStyle::Bold),
+            _ => None,
+        }
+    }
+}
+
+/// Compiled `output` rules of a process
+#[derive(Debug, Clone, Default)]
+pub struct OutputRules {
+    hide: Vec<Regex>,
+    highlight: Vec<(Regex, HighlightStyle)>,
+    /// Replaces the built-in error heuristic when set
+    errors: Option<Vec<Regex>>,
+    warnings: Vec<Regex>,
+}
+
+impl OutputRules {
+    /// Compile an `output` config block. Config validation has already
+    /// checked the patterns and style names.
+    pub fn compile(config: &OutputConfig) -> Result<Self, regex::Error> {
+        let compile_all = |patterns: &[String]| patterns.iter().map(|p| Regex::new(p)).collect();
+
+        Ok(Self {
+            hide: compile_all(&config.hide)?,
+            highlight: config
+                .highlight
+                .iter()
+                .filter_map(|rule| {
+                    HighlightStyle::from_name(&rule.style)
+                        .map(|style| Regex::new(&rule.pattern).map(|re| (re, style)))
+                })
+                .collect::<Result<_, _>>()?,
+            errors: config.errors.as_deref().map(compile_all).transpose()?,
+            warnings: compile_all(config.warnings.as_deref().unwrap_or_default())?,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.hide.is_empty()
+            && self.highlight.is_empty()
+            && self.errors.is_none()
+            && self.warnings.is_empty()
+    }
+
+    /// Short summary of the configured rules, e.g. `2 hide, 1 highlight, errors`
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if !self.hide.is_empty() {
+            parts.push(format!("{} hide", self.hide.len()));
+        }
+        if !self.highlight.is_empty() {
+            parts.push(format!("{} highlight", self.highlight.len()));
+        }
+        if let Some(ref errors) = self.errors {
+            parts.push(format!("{} errors", errors.len()));
+        }
+        if !self.warnings.is_empty() {
+            parts.push(format!("{} warnings", self.warnings.len()));
+        }
+        parts.join(", ")
+    }
+
+    /// Classify a line and tag it for filtering and highlighting.
+    /// Patterns match the line with ANSI escape codes removed.
+    pub fn apply(&self, line: &mut OutputLine) {
+        if self.is_empty() {
+            return;
+        }
+
+        let text = plain_text(&line.content);
+        let matches = |patterns: &[Regex]| patterns.iter().any(|re| re.is_match(&text));
+
+        if let Some(ref errors) = self.errors {
+            line.is_error = matches(errors);
+        }
+        line.is_warning = !line.is_error && matches(&self.warnings);
+        line.hidden = matches(&self.hide);
+        line.highlight = self
+            .highlight
+            .iter()
+            .find(|(re, _)| re.is_match(&text))
+            .map(|(_, style)| *style);
+    }
+}
+
+/// Strip ANSI escape codes from a line of process output
+pub fn plain_text(content: &str) -> String {
+    match content.as_bytes().into_text() {
+        Ok(text) => text
+            .lines
+            .iter()
+            .map(|line| {
+                line.spans
+                    .iter()
+                    .map(|span| span.content.as_ref())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        Err(_) => content.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::HighlightRule;
+
+    #[test]
+    fn test_rules_replace_error_heuristic() {
+        let config = OutputConfig {
+            hide: vec![r"hmr update".to_string()],
+            highlight: vec![HighlightRule {
+                pattern: r"\bDONE\b".to_string(),
+                style: "success".to_string(),
+            }],
+            errors: Some(vec!["SQLSTATE".to_string()]),
+            warnings: Some(vec!["(?i)deprecated".to_string()]),
+        };
+        let rules = OutputRules::compile(&config).unwrap();
+
+        let mut line = OutputLine::stdout("Build failed, retrying".to_string());
+        rules.apply(&mut line);
+        assert!(!line.is_error);
+
+        let mut line = OutputLine::stderr("\u{1b}[31mSQLSTATE[HY000]\u{1b}[0m".to_string());
+        rules.apply(&mut line);
+        assert!(line.is_error);
+
+        let mut line = OutputLine::stdout("Deprecated: strlen()".to_string());
+        rules.apply(&mut line);
+        assert!(line.is_warning && !line.is_error);
+
+        let mut line = OutputLine::stdout("10:42:01 [vite] hmr update /app.js".to_string());
+        rules.apply(&mut line);
+        assert!(line.hidden);
+
+        let mut line = OutputLine::stdout("App\\Jobs\\Sync ... 3ms DONE".to_string());
+        rules.apply(&mut line);
+        assert_eq!(line.highlight, Some(HighlightStyle::Success));
+    }
+}
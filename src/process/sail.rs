@@ -0,0 +1,291 @@
+use std::io::{IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+use crate::config::{LaramuxConfig, DEFAULT_SAIL_HEALTH_TIMEOUT};
+use crate::error::{LaraMuxError, Result};
+use crate::event::Event;
+
+/// Name of the application service in Sail's docker-compose.yml
+pub const SAIL_SERVICE: &str = "laravel.test";
+
+/// Path to the Sail binary, relative to the project root
+pub const SAIL_BIN: &str = "./vendor/bin/sail";
+
+/// How often the container state is polled while the TUI is running
+const MONITOR_INTERVAL: Duration = Duration::from_secs(5);
+
+/// State of the Sail application container
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainerState {
+    /// No container exists for the service
+    Missing,
+    /// Container exists but is not running (created, exited, paused)
+    Stopped,
+    /// Container is running and its health check has not passed yet
+    Starting,
+    /// Container is running but its health check is failing
+    Unhealthy,
+    /// Container is running (healthy, or no health check defined)
+    Running,
+}
+
+impl ContainerState {
+    /// Whether the container accepts `docker compose exec` calls
+    pub fn is_up(&self) -> bool {
+        matches!(
+            self,
+            ContainerState::Running | ContainerState::Starting | ContainerState::Unhealthy
+        )
+    }
+
+    /// Short label for the header
+    pub fn label(&self) -> &'static str {
+        match self {
+            ContainerState::Missing => "not created",
+            ContainerState::Stopped => "stopped",
+            ContainerState::Starting => "starting",
+            ContainerState::Unhealthy => "unhealthy",
+            ContainerState::Running => "running",
+        }
+    }
+}
+
+/// Check if the project ships the Sail binary
+pub fn has_sail(working_dir: &Path) -> bool {
+    working_dir.join("vendor/bin/sail").exists()
+}
+
+/// Parse the output of `docker inspect --format '{{.State.Status}}|{{.State.Health.Status}}'`
+fn parse_inspect_state(output: &str) -> ContainerState {
+    let mut parts = output.trim().splitn(2, '|');
+    let status = parts.next().unwrap_or_default();
+    let health = parts.next().unwrap_or_default();
+
+    match status {
+        "running" => match health {
+            "starting" => ContainerState::Starting,
+            "unhealthy" => ContainerState::Unhealthy,
+            _ => ContainerState::Running,
+        },
+        "restarting" => ContainerState::Starting,
+        "" => ContainerState::Missing,
+        _ => ContainerState::Stopped,
+    }
+}
+
+/// Query the current state of the Sail application container
+pub fn container_state(working_dir: &Path) -> ContainerState {
+    let id_output = Command::new("docker")
+        .args(["compose", "ps", "-a", "-q", SAIL_SERVICE])
+        .current_dir(working_dir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .output();
+
+    let container_id = match id_output {
+        Ok(o) if o.status.success() => String::from_utf8_lossy(&o.stdout)
+            .lines()
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_string(),
+        _ => String::new(),
+    };
+
+    if container_id.is_empty() {
+        return ContainerState::Missing;
+    }
+
+    let inspect_output = Command::new("docker")
+        .args([
+            "inspect",
+            "--format",
+            "{{.State.Status}}|{{if .State.Health}}{{.State.Health.Status}}{{end}}",
+            &container_id,
+        ])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .output();
+
+    match inspect_output {
+        Ok(o) if o.status.success() => parse_inspect_state(&String::from_utf8_lossy(&o.stdout)),
+        _ => ContainerState::Missing,
+    }
+}
+
+/// Run `sail up -d` in the foreground, inheriting the terminal
+pub fn sail_up(working_dir: &Path) -> Result<()> {
+    let status = Command::new(SAIL_BIN)
+        .args(["up", "-d"])
+        .current_dir(working_dir)
+        .stdin(Stdio::null())
+        .status()
+        .map_err(|e| LaraMuxError::Process(format!("Failed to run sail up: {}", e)))?;
+
+    if status.success() {
+        Ok(())
+    } else {
+        Err(LaraMuxError::Process(format!(
+            "sail up -d exited with {}",
+            status
+        )))
+    }
+}
+
+/// Poll the container until it reports healthy (or has no health check), or the timeout elapses
+pub fn wait_until_ready(working_dir: &Path, timeout: Duration) -> ContainerState {
+    let deadline = Instant::now() + timeout;
+    loop {
+        let state = container_state(working_dir);
+        if state == ContainerState::Running || Instant::now() >= deadline {
+            return state;
+        }
+        std::thread::sleep(Duration::from_secs(1));
+    }
+}
+
+/// Make sure the Sail container is up before the TUI starts.
+///
+/// When the container is down, `sail.auto_up` decides what happens: `true`
+/// starts it, `false` leaves it alone, and unset asks on the terminal. Returns
+/// the container state after any action taken.
+pub fn ensure_container(working_dir: &Path, config: Option<&LaramuxConfig>) -> ContainerState {
+    let state = container_state(working_dir);
+    if state.is_up() {
+        return state;
+    }
+
+    let auto_up = config.and_then(|c| c.sail.auto_up);
+    let should_start = match auto_up {
+        Some(choice) => choice,
+        None => prompt_sail_up(state),
+    };
+
+    if !should_start {
+        println!("Continuing with the Sail container {}.", state.label());
+        return state;
+    }
+
+    println!("Starting Sail container (sail up -d)...");
+    if let Err(e) = sail_up(working_dir) {
+        eprintln!("{}", e);
+        return container_state(working_dir);
+    }
+
+    let timeout = Duration::from_secs(
+        config
+            .map(|c| c.sail_health_timeout())
+            .unwrap_or(DEFAULT_SAIL_HEALTH_TIMEOUT),
+    );
+    println!(
+        "Waiting up to {}s for the container to become healthy...",
+        timeout.as_secs()
+    );
+    let state = wait_until_ready(working_dir, timeout);
+    if state != ContainerState::Running {
+        eprintln!("Sail container is {} — continuing anyway.", state.label());
+    }
+    state
+}
+
+/// Ask on the terminal whether to run `sail up -d`. Defaults to yes.
+fn prompt_sail_up(state: ContainerState) -> bool {
+    if !std::io::stdin().is_terminal() {
+        return false;
+    }
+
+    print!(
+        "Laravel Sail container is {}. Start it with `sail up -d`? [Y/n] ",
+        state.label()
+    );
+    let _ = std::io::stdout().flush();
+
+    let mut answer = String::new();
+    if std::io::stdin().read_line(&mut answer).is_err() {
+        return false;
+    }
+    !matches!(answer.trim().to_lowercase().as_str(), "n" | "no")
+}
+
+/// Watch the Sail container and report state changes to the main loop
+pub async fn monitor(
+    working_dir: PathBuf,
+    event_tx: mpsc::Sender<Event>,
+    cancel_token: CancellationToken,
+) {
+    let mut last_state: Option<ContainerState> = None;
+    let mut interval = tokio::time::interval(MONITOR_INTERVAL);
+
+    loop {
+        tokio::select! {
+            _ = cancel_token.cancelled() => break,
+            _ = interval.tick() => {
+                let dir = working_dir.clone();
+                let Ok(state) = tokio::task::spawn_blocking(move || container_state(&dir)).await
+                else {
+                    continue;
+                };
+
+                if last_state != Some(state) {
+                    last_state = Some(state);
+                    let _ = event_tx.send(Event::SailStateChanged(state)).await;
+                }
+            }
+        }
+    }
+}
+
+/// Run `sail up -d` in the background and report the outcome to the main loop
+pub async fn sail_up_background(working_dir: PathBuf, event_tx: mpsc::Sender<Event>) {
+    let result = tokio::process::Command::new(SAIL_BIN)
+        .args(["up", "-d"])
+        .current_dir(&working_dir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .output()
+        .await;
+
+    let outcome = match result {
+        Ok(o) if o.status.success() => Ok(()),
+        Ok(o) => Err(String::from_utf8_lossy(&o.stderr)
+            .lines()
+            .last()
+            .unwrap_or("sail up -d failed")
+            .to_string()),
+        Err(e) => Err(e.to_string()),
+    };
+
+    let _ = event_tx.send(Event::SailUpFinished(outcome)).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_inspect_state() {
+        assert_eq!(
+            parse_inspect_state("running|healthy\n"),
+            ContainerState::Running
+        );
+        assert_eq!(parse_inspect_state("running|"), ContainerState::Running);
+        assert_eq!(
+            parse_inspect_state("running|starting"),
+            ContainerState::Starting
+        );
+        assert_eq!(
+            parse_inspect_state("running|unhealthy"),
+            ContainerState::Unhealthy
+        );
+        assert_eq!(parse_inspect_state("exited|"), ContainerState::Stopped);
+        assert_eq!(parse_inspect_state(""), ContainerState::Missing);
+    }
+}
@@ -0,0 +1,324 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind};
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+use crate::app::{ProcessStats, SystemStats, TreeStats};
+use crate::event::Event;
+
+/// How often tracked processes are sampled
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Look for new descendants every this many samples
+const RESCAN_EVERY: u32 = 5;
+
+/// PIDs of the managed processes whose trees are sampled, shared with the main loop
+pub type TrackedPids = Arc<Mutex<Vec<u32>>>;
+
+/// Short command line for a system process (falls back to its name)
+pub fn command_line(process: &sysinfo::Process) -> String {
+    let cmd: Vec<String> = process
+        .cmd()
+        .iter()
+        .map(|part| part.to_string_lossy().to_string())
+        .collect();
+    if cmd.is_empty() {
+        process.name().to_string_lossy().to_string()
+    } else {
+        cmd.join(" ")
+    }
+}
+
+/// Process group a PID belongs to
+#[cfg(unix)]
+pub fn process_group_of(pid: u32) -> Option<i32> {
+    nix::unistd::getpgid(Some(nix::unistd::Pid::from_raw(pid as i32)))
+        .ok()
+        .map(|group| group.as_raw())
+}
+
+#[cfg(not(unix))]
+pub fn process_group_of(_pid: u32) -> Option<i32> {
+    None
+}
+
+/// Members of each root's tree: its descendants plus anything else in its
+/// process group (children reparented after a wrapper exited). Root first.
+pub fn tree_members(
+    parents: &HashMap<u32, Option<u32>>,
+    groups: &HashMap<u32, i32>,
+    roots: &[u32],
+) -> HashMap<u32, Vec<u32>> {
+    let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
+    for (&pid, &parent) in parents {
+        if let Some(parent) = parent {
+            children.entry(parent).or_default().push(pid);
+        }
+    }
+
+    roots
+        .iter()
+        .filter(|root| parents.contains_key(root))
+        .map(|&root| {
+            let mut members = vec![root];
+            let mut seen: HashSet<u32> = HashSet::from([root]);
+            let mut queue: VecDeque<u32> = groups
+                .iter()
+                .filter(|(_, &group)| group == root as i32)
+                .map(|(&pid, _)| pid)
+                .collect();
+            queue.push_front(root);
+
+            while let Some(pid) = queue.pop_front() {
+                if seen.insert(pid) {
+                    members.push(pid);
+                }
+                for &child in children.get(&pid).into_iter().flatten() {
+                    if !seen.contains(&child) {
+                        queue.push_back(child);
+                    }
+                }
+            }
+            members[1..].sort_unstable();
+            (root, members)
+        })
+        .collect()
+}
+
+/// Work out each root's tree from its descendants and the members found by
+/// the last scan, which may have been reparented since. Only those PIDs are
+/// refreshed and have their process group looked up.
+fn scan_trees(
+    sys: &mut System,
+    roots: &[u32],
+    known: &HashMap<u32, Vec<u32>>,
+) -> HashMap<u32, Vec<u32>> {
+    let mut pids = if proc_children_available() {
+        descendants(roots, proc_children)
+    } else {
+        let children = children_table(sys);
+        descendants(roots, |pid| children.get(&pid).cloned().unwrap_or_default())
+    };
+    pids.extend(known.values().flatten());
+
+    let refresh: Vec<Pid> = pids.iter().map(|&pid| Pid::from_u32(pid)).collect();
+    sys.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&refresh),
+        true,
+        ProcessRefreshKind::new(),
+    );
+
+    let parents: HashMap<u32, Option<u32>> = pids
+        .iter()
+        .filter_map(|&pid| {
+            let process = sys.process(Pid::from_u32(pid))?;
+            Some((pid, process.parent().map(|p| p.as_u32())))
+        })
+        .collect();
+    let root_groups: HashSet<i32> = roots.iter().map(|&root| root as i32).collect();
+    let groups: HashMap<u32, i32> = parents
+        .keys()
+        .filter_map(|&pid| process_group_of(pid).map(|group| (pid, group)))
+        .filter(|(_, group)| root_groups.contains(group))
+        .collect();
+
+    tree_members(&parents, &groups, roots)
+}
+
+/// The roots and everything below them
+fn descendants(roots: &[u32], children: impl Fn(u32) -> Vec<u32>) -> HashSet<u32> {
+    let mut found = HashSet::new();
+    let mut queue: VecDeque<u32> = roots.iter().copied().collect();
+    while let Some(pid) = queue.pop_front() {
+        if found.insert(pid) {
+            queue.extend(children(pid));
+        }
+    }
+    found
+}
+
+/// Whether the kernel lists each thread's children under /proc (Linux)
+fn proc_children_available() -> bool {
+    let pid = std::process::id();
+    std::path::Path::new(&format!("/proc/{}/task/{}/children", pid, pid)).exists()
+}
+
+/// Children of a process, as listed by each of its threads in /proc
+fn proc_children(pid: u32) -> Vec<u32> {
+    let Ok(tasks) = std::fs::read_dir(format!("/proc/{}/task", pid)) else {
+        return Vec::new();
+    };
+    tasks
+        .flatten()
+        .filter_map(|task| std::fs::read_to_string(task.path().join("children")).ok())
+        .flat_map(|children| {
+            children
+                .split_whitespace()
+                .filter_map(|child| child.parse().ok())
+                .collect::<Vec<u32>>()
+        })
+        .collect()
+}
+
+/// Children of every process, from the whole process table. Used where
+/// /proc can't list a process's children.
+fn children_table(sys: &mut System) -> HashMap<u32, Vec<u32>> {
+    sys.refresh_processes_specifics(ProcessesToUpdate::All, true, ProcessRefreshKind::new());
+    let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
+    for (pid, process) in sys.processes() {
+        if let Some(parent) = process.parent() {
+            children
+                .entry(parent.as_u32())
+                .or_default()
+                .push(pid.as_u32());
+        }
+    }
+    children
+}
+
+/// Sample CPU and memory for the managed process trees and report them to the main loop.
+/// Only the tracked trees are refreshed; their descendants are looked up again when the
+/// tracked set changes and every few samples to pick up new children.
+pub async fn monitor(
+    tracked: TrackedPids,
+    event_tx: mpsc::Sender<Event>,
+    cancel_token: CancellationToken,
+) {
+    let mut sys = System::new();
+    let mut interval = tokio::time::interval(SAMPLE_INTERVAL);
+    let mut trees: HashMap<u32, Vec<u32>> = HashMap::new();
+    let mut last_roots: Vec<u32> = Vec::new();
+    let mut samples: u32 = 0;
+
+    loop {
+        tokio::select! {
+            _ = cancel_token.cancelled() => break,
+            _ = interval.tick() => {
+                let roots = tracked.lock().map(|r| r.clone()).unwrap_or_default();
+                if roots != last_roots || samples.is_multiple_of(RESCAN_EVERY) {
+                    trees = scan_trees(&mut sys, &roots, &trees);
+                    last_roots = roots;
+                }
+                samples = samples.wrapping_add(1);
+
+                let pids: Vec<Pid> = trees
+                    .values()
+                    .flatten()
+                    .map(|&pid| Pid::from_u32(pid))
+                    .collect();
+                sys.refresh_processes_specifics(
+                    ProcessesToUpdate::Some(&pids),
+                    true,
+                    ProcessRefreshKind::new()
+                        .with_cpu()
+                        .with_memory()
+                        .with_cmd(UpdateKind::OnlyIfNotSet),
+                );
+                sys.refresh_cpu_usage();
+                sys.refresh_memory();
+
+                let _ = event_tx
+                    .send(Event::SystemStatsUpdate(collect_stats(&sys, &trees)))
+                    .await;
+            }
+        }
+    }
+}
+
+/// Build the stats snapshot for the tracked trees
+fn collect_stats(sys: &System, trees: &HashMap<u32, Vec<u32>>) -> SystemStats {
+    let total_memory = sys.total_memory();
+    let used_memory = sys.used_memory();
+    let memory_usage = if total_memory > 0 {
+        (used_memory as f32 / total_memory as f32) * 100.0
+    } else {
+        0.0
+    };
+
+    let mut process_stats = HashMap::new();
+    let mut process_trees = HashMap::new();
+    for (&root, members) in trees {
+        let mut tree = TreeStats::default();
+        for &pid in members {
+            let Some(process) = sys.process(Pid::from_u32(pid)) else {
+                continue;
+            };
+            tree.cpu_usage += process.cpu_usage();
+            tree.memory_bytes += process.memory();
+            tree.pids.push(pid);
+            process_stats.insert(
+                pid,
+                ProcessStats {
+                    cpu_usage: process.cpu_usage(),
+                    memory_bytes: process.memory(),
+                    parent_pid: process.parent().map(|p| p.as_u32()),
+                    start_time: process.start_time(),
+                    command: command_line(process),
+                },
+            );
+        }
+        if !tree.pids.is_empty() {
+            process_trees.insert(root, tree);
+        }
+    }
+
+    SystemStats {
+        cpu_usage: sys.global_cpu_usage(),
+        memory_usage,
+        total_memory,
+        used_memory,
+        process_stats,
+        process_trees,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tree_members_include_descendants_and_group() {
+        // 100 (npm) -> 101 (sh) -> 102 (node); 103 was reparented to init but kept group 100
+        let parents = HashMap::from([
+            (1, None),
+            (100, Some(1)),
+            (101, Some(100)),
+            (102, Some(101)),
+            (103, Some(1)),
+            (200, Some(1)),
+        ]);
+        let groups = HashMap::from([(100, 100), (101, 100), (102, 100), (103, 100)]);
+
+        let trees = tree_members(&parents, &groups, &[100, 300]);
+        assert_eq!(trees.get(&100), Some(&vec![100, 101, 102, 103]));
+        // Roots that no longer exist are skipped
+        assert!(!trees.contains_key(&300));
+    }
+
+    #[test]
+    fn test_descendants() {
+        let children = HashMap::from([(100, vec![101, 104]), (101, vec![102]), (200, vec![201])]);
+        let found = descendants(&[100, 300], |pid| {
+            children.get(&pid).cloned().unwrap_or_default()
+        });
+        assert_eq!(found, HashSet::from([100, 101, 102, 104, 300]));
+    }
+
+    #[test]
+    fn test_proc_children() {
+        if !proc_children_available() {
+            return;
+        }
+        let mut child = std::process::Command::new("sleep")
+            .arg("5")
+            .spawn()
+            .unwrap();
+        let found = proc_children(std::process::id()).contains(&child.id());
+        child.kill().unwrap();
+        child.wait().unwrap();
+        assert!(found);
+    }
+}
@@ -0,0 +1,479 @@
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+use crate::error::{LaraMuxError, Result};
+use crate::event::Event;
+use crate::process::sail::SAIL_SERVICE;
+use crate::process::types::ProcessId;
+
+/// Path of the supervisord config inside the Sail container
+pub const SUPERVISOR_CONF: &str = "/etc/supervisor/conf.d/supervisord.conf";
+
+/// Where Sail mounts the project inside the container
+pub const CONTAINER_PROJECT_ROOT: &str = "/var/www/html/";
+
+/// How often supervisor program states are polled
+const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(3);
+
+/// Process state as reported by supervisord
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SupervisorState {
+    Stopped,
+    Starting,
+    Running,
+    Backoff,
+    Stopping,
+    Exited,
+    Fatal,
+    Unknown,
+}
+
+impl SupervisorState {
+    /// Parse a supervisord state name (e.g. "RUNNING")
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "STOPPED" => SupervisorState::Stopped,
+            "STARTING" => SupervisorState::Starting,
+            "RUNNING" => SupervisorState::Running,
+            "BACKOFF" => SupervisorState::Backoff,
+            "STOPPING" => SupervisorState::Stopping,
+            "EXITED" => SupervisorState::Exited,
+            "FATAL" => SupervisorState::Fatal,
+            _ => SupervisorState::Unknown,
+        }
+    }
+
+    /// State name as supervisord spells it
+    pub fn name(&self) -> &'static str {
+        match self {
+            SupervisorState::Stopped => "STOPPED",
+            SupervisorState::Starting => "STARTING",
+            SupervisorState::Running => "RUNNING",
+            SupervisorState::Backoff => "BACKOFF",
+            SupervisorState::Stopping => "STOPPING",
+            SupervisorState::Exited => "EXITED",
+            SupervisorState::Fatal => "FATAL",
+            SupervisorState::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+/// Live status of one supervisor program
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SupervisorInfo {
+    /// Program group (the `[program:X]` name)
+    pub group: String,
+    /// Process name within the group (differs from group when numprocs > 1)
+    pub name: String,
+    pub state: SupervisorState,
+    /// Uptime as formatted by supervisord (e.g. "0:05:12")
+    pub uptime: Option<String>,
+    /// Supervisord's description (e.g. "pid 42, uptime 0:05:12" or "Exited too quickly")
+    pub description: String,
+}
+
+/// An action performed on a supervisor program
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SupervisorAction {
+    Start,
+    Stop,
+    Restart,
+}
+
+impl SupervisorAction {
+    /// Verb for status messages
+    pub fn verb(&self) -> &'static str {
+        match self {
+            SupervisorAction::Start => "start",
+            SupervisorAction::Stop => "stop",
+            SupervisorAction::Restart => "restart",
+        }
+    }
+}
+
+/// Controls supervisord inside the Sail container.
+///
+/// Talks XML-RPC over supervisord's unix socket when that socket is reachable
+/// from the host (e.g. it lives under the mounted project directory), and
+/// falls back to `supervisorctl` via `docker compose exec` otherwise.
+#[derive(Debug, Clone)]
+pub struct SupervisorClient {
+    working_dir: PathBuf,
+    /// Host path of the XML-RPC socket, when reachable
+    socket: Option<PathBuf>,
+}
+
+impl SupervisorClient {
+    /// Create a client; `socket_file` is the `[unix_http_server] file=` path inside the container
+    pub fn new(working_dir: &Path, socket_file: Option<&str>) -> Self {
+        let socket = socket_file.and_then(|file| host_socket_path(working_dir, file));
+        Self {
+            working_dir: working_dir.to_path_buf(),
+            socket,
+        }
+    }
+
+    /// Start, stop or restart every process in a program group
+    pub fn perform(&self, program: &str, action: SupervisorAction) -> Result<()> {
+        match action {
+            SupervisorAction::Start => self.start(program),
+            SupervisorAction::Stop => self.stop(program),
+            SupervisorAction::Restart => {
+                // A program that is already stopped just needs starting
+                if let Err(e) = self.stop(program) {
+                    let message = e.to_string().to_lowercase().replace('_', " ");
+                    if !message.contains("not running") {
+                        return Err(e);
+                    }
+                }
+                self.start(program)
+            }
+        }
+    }
+
+    /// Start every process in a program group
+    pub fn start(&self, program: &str) -> Result<()> {
+        match &self.socket {
+            Some(socket) => {
+                xmlrpc_call(socket, "supervisor.startProcessGroup", program).map(|_| ())
+            }
+            None => self
+                .supervisorctl(&["start", &format!("{}:*", program)])
+                .map(|_| ()),
+        }
+    }
+
+    /// Stop every process in a program group
+    pub fn stop(&self, program: &str) -> Result<()> {
+        match &self.socket {
+            Some(socket) => xmlrpc_call(socket, "supervisor.stopProcessGroup", program).map(|_| ()),
+            None => self
+                .supervisorctl(&["stop", &format!("{}:*", program)])
+                .map(|_| ()),
+        }
+    }
+
+    /// Fetch the status of every supervisor process
+    pub fn status_all(&self) -> Result<Vec<SupervisorInfo>> {
+        match &self.socket {
+            Some(socket) => {
+                let body = xmlrpc_call(socket, "supervisor.getAllProcessInfo", "")?;
+                Ok(parse_xmlrpc_process_info(&body))
+            }
+            None => {
+                let output = self.supervisorctl(&["status"])?;
+                Ok(parse_supervisorctl_status(&output))
+            }
+        }
+    }
+
+    /// Run supervisorctl inside the container and return its stdout
+    fn supervisorctl(&self, args: &[&str]) -> Result<String> {
+        let output = Command::new("docker")
+            .args([
+                "compose",
+                "exec",
+                "-T",
+                SAIL_SERVICE,
+                "supervisorctl",
+                "-c",
+                SUPERVISOR_CONF,
+            ])
+            .args(args)
+            .current_dir(&self.working_dir)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .map_err(|e| LaraMuxError::Process(format!("Failed to run supervisorctl: {}", e)))?;
+
+        let stdout = String::from_utf8_lossy(&output.stdout).to_string();
+
+        // `status` exits non-zero when any program is not running; only treat
+        // connection problems and action failures as errors
+        if let Some(line) = supervisorctl_error(&stdout) {
+            return Err(LaraMuxError::Process(line.to_string()));
+        }
+        if stdout.trim().is_empty() && !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let message = stderr
+                .lines()
+                .find(|l| !l.trim().is_empty())
+                .unwrap_or("supervisorctl failed")
+                .trim()
+                .to_string();
+            return Err(LaraMuxError::Process(message));
+        }
+
+        Ok(stdout)
+    }
+}
+
+/// The first line of supervisorctl output that reports a failure: an
+/// action's `name: ERROR (reason)` or a connection problem. Status lines
+/// never count, whatever their program names and descriptions say.
+fn supervisorctl_error(stdout: &str) -> Option<&str> {
+    stdout.lines().map(str::trim).find(|line| {
+        let action_failed = line
+            .split_once(' ')
+            .is_some_and(|(name, result)| name.ends_with(':') && result.starts_with("ERROR ("));
+        let unreachable = (line.starts_with("unix://") || line.starts_with("http://"))
+            && (line.ends_with("refused connection") || line.ends_with("no such file"));
+        action_failed || unreachable
+    })
+}
+
+/// Map the in-container socket path to a host path, if the socket is reachable
+fn host_socket_path(working_dir: &Path, file: &str) -> Option<PathBuf> {
+    let file = file.trim_start_matches("unix://");
+    let candidate = match file.strip_prefix(CONTAINER_PROJECT_ROOT) {
+        Some(relative) => working_dir.join(relative),
+        None => PathBuf::from(file),
+    };
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::FileTypeExt;
+        std::fs::metadata(&candidate)
+            .ok()
+            .filter(|m| m.file_type().is_socket())
+            .map(|_| candidate)
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = candidate;
+        None
+    }
+}
+
+/// Perform a single XML-RPC call with zero or one string parameter, returning the response body
+#[cfg(unix)]
+fn xmlrpc_call(socket: &Path, method: &str, param: &str) -> Result<String> {
+    use std::io::{Read, Write};
+    use std::os::unix::net::UnixStream;
+
+    let params = if param.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "<param><value><string>{}</string></value></param>",
+            xml_escape(param)
+        )
+    };
+    let body = format!(
+        "<?xml version=\"1.0\"?><methodCall><methodName>{}</methodName><params>{}</params></methodCall>",
+        method, params
+    );
+    let request = format!(
+        "POST /RPC2 HTTP/1.0\r\nHost: localhost\r\nContent-Type: text/xml\r\nContent-Length: {}\r\n\r\n{}",
+        body.len(),
+        body
+    );
+
+    let mut stream = UnixStream::connect(socket)?;
+    stream.set_read_timeout(Some(std::time::Duration::from_secs(30)))?;
+    stream.write_all(request.as_bytes())?;
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response)?;
+
+    let body = response
+        .split_once("\r\n\r\n")
+        .map(|(_, b)| b)
+        .unwrap_or_default()
+        .to_string();
+
+    if body.contains("<fault>") {
+        let message = xml_member(&body, "faultString").unwrap_or("XML-RPC fault");
+        return Err(LaraMuxError::Process(message.to_string()));
+    }
+
+    Ok(body)
+}
+
+#[cfg(not(unix))]
+fn xmlrpc_call(_socket: &Path, _method: &str, _param: &str) -> Result<String> {
+    Err(LaraMuxError::Process(
+        "XML-RPC over unix sockets is not supported on this platform".to_string(),
+    ))
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Extract the scalar value of a named struct member from an XML-RPC response
+fn xml_member<'a>(xml: &'a str, name: &str) -> Option<&'a str> {
+    let marker = format!("<name>{}</name>", name);
+    let after_name = &xml[xml.find(&marker)? + marker.len()..];
+    let value_start = after_name.find("<value>")? + "<value>".len();
+    let value = &after_name[value_start..];
+    let value = &value[..value.find("</value>")?];
+
+    // Strip the type tag (<string>, <int>, <i4>, ...) if present
+    let value = value.trim();
+    if value.starts_with('<') {
+        let inner_start = value.find('>')? + 1;
+        let inner_end = value.rfind("</").unwrap_or(value.len());
+        Some(value.get(inner_start..inner_end).unwrap_or_default())
+    } else {
+        Some(value)
+    }
+}
+
+/// Pull the uptime out of a supervisord description ("pid 42, uptime 0:05:12")
+fn parse_uptime(description: &str) -> Option<String> {
+    description
+        .split_once("uptime ")
+        .map(|(_, uptime)| uptime.trim().to_string())
+}
+
+/// Parse a `supervisor.getAllProcessInfo` response
+fn parse_xmlrpc_process_info(xml: &str) -> Vec<SupervisorInfo> {
+    xml.split("<struct>")
+        .skip(1)
+        .filter_map(|entry| {
+            let name = xml_member(entry, "name")?.to_string();
+            let group = xml_member(entry, "group").unwrap_or(&name).to_string();
+            let state = SupervisorState::from_name(xml_member(entry, "statename")?);
+            let description = xml_member(entry, "description")
+                .unwrap_or_default()
+                .to_string();
+            Some(SupervisorInfo {
+                group,
+                name,
+                state,
+                uptime: parse_uptime(&description),
+                description,
+            })
+        })
+        .collect()
+}
+
+/// Parse `supervisorctl status` output
+fn parse_supervisorctl_status(output: &str) -> Vec<SupervisorInfo> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            let full_name = parts.next()?;
+            let state = SupervisorState::from_name(parts.next()?);
+            let description = parts.collect::<Vec<_>>().join(" ");
+
+            let (group, name) = full_name.split_once(':').unwrap_or((full_name, full_name));
+            Some(SupervisorInfo {
+                group: group.to_string(),
+                name: name.to_string(),
+                state,
+                uptime: parse_uptime(&description),
+                description,
+            })
+        })
+        .collect()
+}
+
+/// Poll supervisord and report program states to the main loop
+pub async fn monitor(
+    client: SupervisorClient,
+    event_tx: mpsc::Sender<Event>,
+    cancel_token: CancellationToken,
+) {
+    let mut interval = tokio::time::interval(POLL_INTERVAL);
+
+    loop {
+        tokio::select! {
+            _ = cancel_token.cancelled() => break,
+            _ = interval.tick() => {
+                let client = client.clone();
+                if let Ok(Ok(infos)) = tokio::task::spawn_blocking(move || client.status_all()).await {
+                    let _ = event_tx.send(Event::SupervisorStatus(infos)).await;
+                }
+            }
+        }
+    }
+}
+
+/// Perform a supervisor action in the background and report the outcome
+pub async fn perform_background(
+    client: SupervisorClient,
+    id: ProcessId,
+    program: String,
+    action: SupervisorAction,
+    event_tx: mpsc::Sender<Event>,
+) {
+    let result = tokio::task::spawn_blocking(move || client.perform(&program, action))
+        .await
+        .map_err(|e| e.to_string())
+        .and_then(|r| r.map_err(|e| e.to_string()));
+
+    let _ = event_tx
+        .send(Event::SupervisorActionFinished { id, action, result })
+        .await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_supervisorctl_status() {
+        let output = "\
+horizon                          RUNNING   pid 42, uptime 0:05:12
+worker:worker_00                 BACKOFF   Exited too quickly (process log may have details)
+vite                             FATAL     Exited too quickly
+";
+        let infos = parse_supervisorctl_status(output);
+        assert_eq!(infos.len(), 3);
+        assert_eq!(infos[0].group, "horizon");
+        assert_eq!(infos[0].state, SupervisorState::Running);
+        assert_eq!(infos[0].uptime.as_deref(), Some("0:05:12"));
+        assert_eq!(infos[1].group, "worker");
+        assert_eq!(infos[1].name, "worker_00");
+        assert_eq!(infos[1].state, SupervisorState::Backoff);
+        assert_eq!(infos[2].state, SupervisorState::Fatal);
+        assert_eq!(infos[2].uptime, None);
+    }
+
+    #[test]
+    fn test_supervisorctl_error() {
+        let status = "\
+horizon                          RUNNING   pid 42, uptime 0:05:12
+error-reporter:error-reporter_00 FATAL     Exited too quickly: ERROR (spawn error)
+";
+        assert_eq!(supervisorctl_error(status), None);
+
+        let stop = "horizon:horizon_00: ERROR (not running)\n";
+        assert_eq!(
+            supervisorctl_error(stop),
+            Some("horizon:horizon_00: ERROR (not running)")
+        );
+        assert_eq!(supervisorctl_error("horizon:horizon_00: stopped\n"), None);
+        assert!(
+            supervisorctl_error("unix:///var/run/supervisor.sock refused connection\n").is_some()
+        );
+    }
+
+    #[test]
+    fn test_parse_xmlrpc_process_info() {
+        let xml = "<?xml version='1.0'?><methodResponse><params><param><value><array><data>\
+<value><struct>\
+<member><name>description</name><value><string>pid 42, uptime 1:02:03</string></value></member>\
+<member><name>group</name><value><string>horizon</string></value></member>\
+<member><name>name</name><value><string>horizon</string></value></member>\
+<member><name>pid</name><value><int>42</int></value></member>\
+<member><name>statename</name><value><string>RUNNING</string></value></member>\
+</struct></value>\
+</data></array></value></param></params></methodResponse>";
+        let infos = parse_xmlrpc_process_info(xml);
+        assert_eq!(infos.len(), 1);
+        assert_eq!(infos[0].name, "horizon");
+        assert_eq!(infos[0].state, SupervisorState::Running);
+        assert_eq!(infos[0].uptime.as_deref(), Some("1:02:03"));
+    }
+}
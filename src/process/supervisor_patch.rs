@@ -0,0 +1,363 @@
+use std::io::{IsTerminal, Write};
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use crate::error::{LaraMuxError, Result};
+use crate::process::sail::SAIL_SERVICE;
+use crate::process::supervisor::SUPERVISOR_CONF;
+
+/// Directory the per-program log files are written to inside the container
+pub const SUPERVISOR_LOG_DIR: &str = "/var/log/supervisor";
+
+/// Suffix of the backup taken before the supervisor config is patched
+const BACKUP_SUFFIX: &str = ".laramux.bak";
+
+/// Lines of unchanged context shown around each change in a diff
+const DIFF_CONTEXT: usize = 2;
+
+/// Check that a supervisor program name is safe to use in file paths and commands
+pub fn is_valid_program_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with('.')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+}
+
+/// Per-program log file path for a supervisor program
+pub fn log_file_for(program: &str) -> String {
+    format!("{}/{}.log", SUPERVISOR_LOG_DIR, program)
+}
+
+/// Rewrite a supervisord config so the given programs log to their own files
+/// instead of /dev/stdout and /dev/stderr.
+pub fn patch_config(original: &str, programs: &[String]) -> String {
+    let mut patched = Vec::new();
+    let mut current: Option<&str> = None;
+
+    for line in original.lines() {
+        let trimmed = line.trim();
+
+        if trimmed.starts_with('[') && trimmed.ends_with(']') {
+            current = trimmed
+                .strip_prefix("[program:")
+                .and_then(|rest| rest.strip_suffix(']'))
+                .map(str::trim)
+                .filter(|name| programs.iter().any(|p| p == name));
+            patched.push(line.to_string());
+            continue;
+        }
+
+        let Some(program) = current else {
+            patched.push(line.to_string());
+            continue;
+        };
+
+        let (key, value) = trimmed
+            .split_once('=')
+            .map(|(k, v)| (k.trim(), v.trim()))
+            .unwrap_or((trimmed, ""));
+
+        match (key, value) {
+            ("stdout_logfile", "/dev/stdout") => {
+                patched.push(format!("stdout_logfile={}", log_file_for(program)));
+                patched.push("stdout_logfile_maxbytes=10MB".to_string());
+            }
+            ("stderr_logfile", "/dev/stderr") => {
+                patched.push(format!("stderr_logfile={}", log_file_for(program)));
+                patched.push("stderr_logfile_maxbytes=0".to_string());
+            }
+            ("stdout_logfile_maxbytes", _) | ("stderr_logfile_maxbytes", _) => {}
+            _ => patched.push(line.to_string()),
+        }
+    }
+
+    let mut result = patched.join("\n");
+    if original.ends_with('\n') {
+        result.push('\n');
+    }
+    result
+}
+
+/// Render a line diff between two texts, with a little context around each change
+pub fn line_diff(old: &str, new: &str) -> String {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // Longest common subsequence table
+    let mut lcs = vec![vec![0u32; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    // Walk the table into a list of (marker, line) operations
+    let mut ops: Vec<(char, &str)> = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            ops.push((' ', old[i]));
+            i += 1;
+            j += 1;
+        } else if j < new.len() && (i == old.len() || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            ops.push(('+', new[j]));
+            j += 1;
+        } else {
+            ops.push(('-', old[i]));
+            i += 1;
+        }
+    }
+
+    // Keep only changes and their surrounding context
+    let changed: Vec<usize> = ops
+        .iter()
+        .enumerate()
+        .filter(|(_, (marker, _))| *marker != ' ')
+        .map(|(idx, _)| idx)
+        .collect();
+
+    let mut out = String::new();
+    let mut last_shown: Option<usize> = None;
+    for (idx, (marker, line)) in ops.iter().enumerate() {
+        let near_change = changed
+            .iter()
+            .any(|&c| idx + DIFF_CONTEXT >= c && idx <= c + DIFF_CONTEXT);
+        if !near_change {
+            continue;
+        }
+        if last_shown.is_some_and(|last| idx > last + 1) {
+            out.push_str("...\n");
+        }
+        out.push_str(&format!("{}{}\n", marker, line));
+        last_shown = Some(idx);
+    }
+    out
+}
+
+/// Patch the supervisor config inside the container so each program logs to
+/// its own file. Shows a diff first and asks for confirmation; the original is
+/// backed up next to the config so `laramux sail restore-supervisor` can revert it.
+///
+/// Returns the programs whose logs were redirected.
+pub fn patch_log_files(working_dir: &Path, program_names: &[String]) -> Vec<String> {
+    let (programs, rejected): (Vec<String>, Vec<String>) = program_names
+        .iter()
+        .cloned()
+        .partition(|name| is_valid_program_name(name));
+    for name in &rejected {
+        eprintln!(
+            "Skipping supervisor program with unsupported name: {:?}",
+            name
+        );
+    }
+    if programs.is_empty() {
+        return Vec::new();
+    }
+
+    let Some(original) = read_config(working_dir) else {
+        return Vec::new();
+    };
+
+    // Programs defined in [include]d files are not touched by the patch
+    let programs: Vec<String> = programs
+        .into_iter()
+        .filter(|p| patch_config(&original, std::slice::from_ref(p)) != original)
+        .collect();
+    if programs.is_empty() {
+        return Vec::new();
+    }
+    let patched = patch_config(&original, &programs);
+
+    println!(
+        "laramux will patch {} inside the Sail container:",
+        SUPERVISOR_CONF
+    );
+    println!();
+    print!("{}", line_diff(&original, &patched));
+    println!();
+    println!(
+        "The original is backed up to {}{}. Supervisor is reloaded, which restarts the affected programs.",
+        SUPERVISOR_CONF, BACKUP_SUFFIX
+    );
+
+    if !confirm("Apply this change? [y/N] ") {
+        println!("Supervisor config left unchanged.");
+        return Vec::new();
+    }
+
+    match apply_config(working_dir, &patched, &programs) {
+        Ok(()) => programs,
+        Err(e) => {
+            eprintln!("{}", e);
+            Vec::new()
+        }
+    }
+}
+
+/// Restore the supervisor config from the backup taken before patching
+pub fn restore_config(working_dir: &Path) -> Result<()> {
+    let script = format!(
+        r#"conf="$1"
+[ -f "$conf{suffix}" ] || {{ echo "No laramux backup found at $conf{suffix}" >&2; exit 1; }}
+mv "$conf{suffix}" "$conf" && kill -HUP 1"#,
+        suffix = BACKUP_SUFFIX
+    );
+    run_in_container(working_dir, &script, &[SUPERVISOR_CONF.to_string()], None)
+}
+
+/// Ask a yes/no question on the terminal. Defaults to no.
+fn confirm(prompt: &str) -> bool {
+    if !std::io::stdin().is_terminal() {
+        return false;
+    }
+
+    print!("{}", prompt);
+    let _ = std::io::stdout().flush();
+
+    let mut answer = String::new();
+    if std::io::stdin().read_line(&mut answer).is_err() {
+        return false;
+    }
+    matches!(answer.trim().to_lowercase().as_str(), "y" | "yes")
+}
+
+/// Read the supervisor config from the container
+fn read_config(working_dir: &Path) -> Option<String> {
+    let output = Command::new("docker")
+        .args([
+            "compose",
+            "exec",
+            "-T",
+            SAIL_SERVICE,
+            "cat",
+            SUPERVISOR_CONF,
+        ])
+        .current_dir(working_dir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .output();
+
+    match output {
+        Ok(o) if o.status.success() => String::from_utf8(o.stdout).ok(),
+        _ => None,
+    }
+}
+
+/// Back up the config, write the patched version, create the log files and reload supervisor
+fn apply_config(working_dir: &Path, patched: &str, programs: &[String]) -> Result<()> {
+    let script = format!(
+        r#"conf="$1"; shift
+[ -f "$conf{suffix}" ] || cp "$conf" "$conf{suffix}" || exit 1
+cat > "$conf" || exit 1
+mkdir -p {log_dir}
+for name in "$@"; do touch "{log_dir}/$name.log"; done
+kill -HUP 1"#,
+        suffix = BACKUP_SUFFIX,
+        log_dir = SUPERVISOR_LOG_DIR
+    );
+
+    let mut args = vec![SUPERVISOR_CONF.to_string()];
+    args.extend(programs.iter().cloned());
+    run_in_container(working_dir, &script, &args, Some(patched))?;
+
+    // Give supervisor a moment to reread the config and respawn programs
+    std::thread::sleep(std::time::Duration::from_secs(1));
+    Ok(())
+}
+
+/// Run a fixed shell script inside the container. Values are passed as
+/// positional arguments, never interpolated into the script.
+fn run_in_container(
+    working_dir: &Path,
+    script: &str,
+    args: &[String],
+    stdin: Option<&str>,
+) -> Result<()> {
+    let mut child = Command::new("docker")
+        .args([
+            "compose",
+            "exec",
+            "-T",
+            SAIL_SERVICE,
+            "sh",
+            "-c",
+            script,
+            "sh",
+        ])
+        .args(args)
+        .current_dir(working_dir)
+        .stdin(if stdin.is_some() {
+            Stdio::piped()
+        } else {
+            Stdio::null()
+        })
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| LaraMuxError::Process(format!("Failed to run docker compose: {}", e)))?;
+
+    if let (Some(input), Some(mut pipe)) = (stdin, child.stdin.take()) {
+        pipe.write_all(input.as_bytes())?;
+    }
+
+    let output = child.wait_with_output()?;
+    if output.status.success() {
+        Ok(())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(LaraMuxError::Process(
+            stderr
+                .lines()
+                .last()
+                .unwrap_or("command failed inside the Sail container")
+                .to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = "\
+[program:horizon]
+command=php artisan horizon
+stdout_logfile=/dev/stdout
+stdout_logfile_maxbytes=0
+stderr_logfile=/dev/stderr
+stderr_logfile_maxbytes=0
+
+[program:php]
+command=php artisan serve
+stdout_logfile=/dev/stdout
+";
+
+    #[test]
+    fn test_patch_config_only_touches_targets() {
+        let patched = patch_config(CONFIG, &["horizon".to_string()]);
+        assert!(patched.contains("stdout_logfile=/var/log/supervisor/horizon.log\n"));
+        assert!(patched.contains("stderr_logfile=/var/log/supervisor/horizon.log\n"));
+        assert!(!patched.contains("stdout_logfile_maxbytes=0"));
+        // The php program is left alone
+        assert!(patched.ends_with("command=php artisan serve\nstdout_logfile=/dev/stdout\n"));
+
+        let diff = line_diff(CONFIG, &patched);
+        assert!(diff.contains("-stdout_logfile=/dev/stdout\n"));
+        assert!(diff.contains("+stdout_logfile=/var/log/supervisor/horizon.log\n"));
+    }
+
+    #[test]
+    fn test_program_name_validation() {
+        assert!(is_valid_program_name("laravel-worker_1.default"));
+        assert!(!is_valid_program_name(""));
+        assert!(!is_valid_program_name("../etc"));
+        assert!(!is_valid_program_name("x; kill -9 1"));
+        assert!(!is_valid_program_name("a/b"));
+    }
+}
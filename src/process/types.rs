@@ -2,8 +2,12 @@
 
 use std::collections::{HashMap, VecDeque};
 use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
 
 use crate::config::RestartPolicy;
+use crate::process::manager::RestartState;
+use crate::process::output::{plain_text, HighlightStyle, OutputRules};
+use crate::process::supervisor::SupervisorInfo;
 
 /// The kind of built-in Laravel process being managed
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -46,6 +50,14 @@ impl ProcessKind {
         }
     }
 
+    /// Parse a config name (e.g. "queue") back into a kind
+    pub fn from_config_name(name: &str) -> Option<ProcessKind> {
+        ProcessKind::all()
+            .iter()
+            .copied()
+            .find(|k| k.config_name() == name.to_lowercase())
+    }
+
     pub fn all() -> &'static [ProcessKind] {
         &[
             ProcessKind::Serve,
@@ -168,6 +180,8 @@ pub enum ProcessStatus {
     Restarting,
     Failed,
     Supervised,
+    /// Frozen with SIGSTOP
+    Paused,
 }
 
 impl ProcessStatus {
@@ -178,6 +192,83 @@ impl ProcessStatus {
             ProcessStatus::Restarting => "🟡",
             ProcessStatus::Failed => "🔴",
             ProcessStatus::Supervised => "🔵",
+            ProcessStatus::Paused => "⏸",
+        }
+    }
+}
+
+/// Signals that can be sent to a managed process from the signal menu
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessSignal {
+    Stop,
+    Cont,
+    Hup,
+    Usr1,
+    Usr2,
+    Int,
+    Term,
+}
+
+impl ProcessSignal {
+    pub fn all() -> &'static [ProcessSignal] {
+        &[
+            ProcessSignal::Stop,
+            ProcessSignal::Cont,
+            ProcessSignal::Hup,
+            ProcessSignal::Usr1,
+            ProcessSignal::Usr2,
+            ProcessSignal::Int,
+            ProcessSignal::Term,
+        ]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ProcessSignal::Stop => "SIGSTOP",
+            ProcessSignal::Cont => "SIGCONT",
+            ProcessSignal::Hup => "SIGHUP",
+            ProcessSignal::Usr1 => "SIGUSR1",
+            ProcessSignal::Usr2 => "SIGUSR2",
+            ProcessSignal::Int => "SIGINT",
+            ProcessSignal::Term => "SIGTERM",
+        }
+    }
+
+    /// What the signal typically does, shown next to its name in the menu
+    pub fn description(&self) -> &'static str {
+        match self {
+            ProcessSignal::Stop => "Freeze the process",
+            ProcessSignal::Cont => "Resume (frozen process, paused Horizon)",
+            ProcessSignal::Hup => "Reload configuration",
+            ProcessSignal::Usr1 => "User-defined 1",
+            ProcessSignal::Usr2 => "User-defined 2 (pause Horizon)",
+            ProcessSignal::Int => "Interrupt",
+            ProcessSignal::Term => "Terminate gracefully",
+        }
+    }
+
+    #[cfg(unix)]
+    pub fn to_nix(self) -> nix::sys::signal::Signal {
+        use nix::sys::signal::Signal;
+
+        match self {
+            ProcessSignal::Stop => Signal::SIGSTOP,
+            ProcessSignal::Cont => Signal::SIGCONT,
+            ProcessSignal::Hup => Signal::SIGHUP,
+            ProcessSignal::Usr1 => Signal::SIGUSR1,
+            ProcessSignal::Usr2 => Signal::SIGUSR2,
+            ProcessSignal::Int => Signal::SIGINT,
+            ProcessSignal::Term => Signal::SIGTERM,
+        }
+    }
+
+    /// Status of a process once this signal was delivered: SIGSTOP pauses
+    /// it, SIGCONT resumes a paused one and the rest leave it as it was
+    pub fn status_after(self, status: ProcessStatus) -> ProcessStatus {
+        match (self, status) {
+            (ProcessSignal::Stop, _) => ProcessStatus::Paused,
+            (ProcessSignal::Cont, ProcessStatus::Paused) => ProcessStatus::Running,
+            _ => status,
         }
     }
 }
@@ -193,6 +284,10 @@ pub struct ProcessConfig {
     pub restart_policy: RestartPolicy,
     pub supervised: bool,
     pub supervisor_program: Option<String>,
+    /// Filter, highlight and error classification rules for output
+    pub output_rules: OutputRules,
+    /// `.laramux.json` key each field came from; fields not listed were discovered
+    pub sources: HashMap<&'static str, String>,
 }
 
 impl ProcessConfig {
@@ -206,6 +301,8 @@ impl ProcessConfig {
             restart_policy: RestartPolicy::default(),
             supervised: false,
             supervisor_program: None,
+            output_rules: OutputRules::default(),
+            sources: HashMap::new(),
         }
     }
 
@@ -224,22 +321,151 @@ impl ProcessConfig {
         self
     }
 
+    pub fn with_output_rules(mut self, rules: OutputRules) -> Self {
+        self.output_rules = rules;
+        self
+    }
+
     pub fn with_supervised(mut self, program_name: String) -> Self {
         self.supervised = true;
         self.supervisor_program = Some(program_name);
         self
     }
+
+    /// Record the config key a field was taken from
+    pub fn with_source(mut self, field: &'static str, key: impl Into<String>) -> Self {
+        self.sources.insert(field, key.into());
+        self
+    }
+
+    /// Whether this process runs inside the Sail container (and dies with it)
+    pub fn is_sail_routed(&self) -> bool {
+        self.supervised
+            || self.command.ends_with("vendor/bin/sail")
+            || (self.command == "docker" && self.args.first().is_some_and(|a| a == "compose"))
+    }
 }
 
 /// Maximum number of output lines to keep per process
 pub const MAX_OUTPUT_LINES: usize = 1000;
 
+/// Maximum number of runs remembered per process
+pub const MAX_RUNS: usize = 20;
+
+/// How a process run ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExitReason {
+    /// Exited with a status code
+    Code(i32),
+    /// Terminated by a signal
+    Signal(i32),
+    /// The exit status could not be collected
+    Unknown,
+}
+
+impl ExitReason {
+    pub fn from_status(status: std::process::ExitStatus) -> Self {
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::ExitStatusExt;
+            if let Some(signal) = status.signal() {
+                return ExitReason::Signal(signal);
+            }
+        }
+        status
+            .code()
+            .map(ExitReason::Code)
+            .unwrap_or(ExitReason::Unknown)
+    }
+
+    pub fn code(&self) -> Option<i32> {
+        match self {
+            ExitReason::Code(code) => Some(*code),
+            _ => None,
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        *self == ExitReason::Code(0)
+    }
+
+    /// Short description, e.g. "exit 1" or "SIGKILL"
+    pub fn label(&self) -> String {
+        match self {
+            ExitReason::Code(code) => format!("exit {}", code),
+            ExitReason::Signal(signal) => signal_name(*signal),
+            ExitReason::Unknown => "unknown exit".to_string(),
+        }
+    }
+}
+
+#[cfg(unix)]
+fn signal_name(signal: i32) -> String {
+    nix::sys::signal::Signal::try_from(signal)
+        .map(|s| s.as_str().to_string())
+        .unwrap_or_else(|_| format!("signal {}", signal))
+}
+
+#[cfg(not(unix))]
+fn signal_name(signal: i32) -> String {
+    format!("signal {}", signal)
+}
+
+/// One run of a managed process, from spawn to exit
+#[derive(Debug, Clone)]
+pub struct RunRecord {
+    /// 1-based run number within this session
+    pub number: u32,
+    pub pid: Option<u32>,
+    pub started_at: SystemTime,
+    pub ended_at: Option<SystemTime>,
+    pub exit: Option<ExitReason>,
+    /// Ended by stop, restart or quit rather than on its own
+    pub requested: bool,
+    /// Crash report written when the run failed
+    pub crash_report: Option<PathBuf>,
+    /// Absolute index of the run's first output line
+    first_line: u64,
+}
+
+impl RunRecord {
+    pub fn is_running(&self) -> bool {
+        self.ended_at.is_none()
+    }
+
+    /// How long the run lasted, or has lasted so far
+    pub fn duration(&self) -> Duration {
+        self.ended_at
+            .unwrap_or_else(SystemTime::now)
+            .duration_since(self.started_at)
+            .unwrap_or_default()
+    }
+
+    /// Short outcome description for titles and lists
+    pub fn outcome(&self) -> String {
+        match (self.exit, self.requested) {
+            (None, _) if self.is_running() => "running".to_string(),
+            (Some(exit), true) => format!("stopped ({})", exit.label()),
+            (Some(exit), false) => exit.label(),
+            (None, _) => "ended".to_string(),
+        }
+    }
+}
+
 /// A single line of process output
 #[derive(Debug, Clone)]
 pub struct OutputLine {
     pub content: String,
     pub is_stderr: bool,
     pub is_error: bool,
+    /// Matched one of the process's configured warning patterns
+    pub is_warning: bool,
+    /// Matched a `hide` pattern; left out of the filtered output view
+    pub hidden: bool,
+    /// Style from the first matching highlight rule
+    pub highlight: Option<HighlightStyle>,
+    /// Stack trace line grouped with the error line above it
+    pub in_trace: bool,
 }
 
 impl OutputLine {
@@ -249,6 +475,10 @@ impl OutputLine {
             content,
             is_stderr: false,
             is_error,
+            is_warning: false,
+            hidden: false,
+            highlight: None,
+            in_trace: false,
         }
     }
 
@@ -257,6 +487,10 @@ impl OutputLine {
             content,
             is_stderr: true,
             is_error: true,
+            is_warning: false,
+            hidden: false,
+            highlight: None,
+            in_trace: false,
         }
     }
 
@@ -268,6 +502,24 @@ impl OutputLine {
             || lower.contains("failed")
             || content.contains("Stack trace:")
     }
+
+    /// Whether a line looks like part of a stack trace (PHP `#0 ...`,
+    /// Collision's numbered vendor frames, JS `at ...`)
+    pub fn looks_like_trace(content: &str) -> bool {
+        let text = content.trim_start();
+        let indented = text.len() < content.len();
+        text.starts_with("Stack trace:")
+            || text.starts_with("thrown in ")
+            || text.starts_with("Caused by:")
+            || text.starts_with("Next ")
+            || text.starts_with("{main}")
+            || text
+                .strip_prefix('#')
+                .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_digit()))
+            || (indented
+                && (text.starts_with("at ")
+                    || text.starts_with(|c: char| c.is_ascii_digit() || c == '+')))
+    }
 }
 
 /// A managed process with its state and output
@@ -279,6 +531,17 @@ pub struct Process {
     pub output: VecDeque<OutputLine>,
     pub pid: Option<u32>,
     pub scroll_offset: usize,
+    /// Supervisor's own view of the program (supervised processes only)
+    pub supervisor: Option<SupervisorInfo>,
+    /// Restart backoff and history, mirrored from the process manager
+    pub restart_state: Option<RestartState>,
+    /// Recent runs, oldest first
+    pub runs: VecDeque<RunRecord>,
+    /// Total output lines received, used to split output into runs
+    lines_seen: u64,
+    /// Errors and warnings received since the output was last viewed
+    pub unread_errors: usize,
+    pub unread_warnings: usize,
 }
 
 impl Process {
@@ -290,22 +553,205 @@ impl Process {
             output: VecDeque::with_capacity(MAX_OUTPUT_LINES),
             pid: None,
             scroll_offset: 0,
+            supervisor: None,
+            restart_state: None,
+            runs: VecDeque::new(),
+            lines_seen: 0,
+            unread_errors: 0,
+            unread_warnings: 0,
         }
     }
 
-    pub fn add_output(&mut self, line: OutputLine) {
+    pub fn add_output(&mut self, mut line: OutputLine) {
+        // Trace lines join the error above them and are not counted on their own
+        line.in_trace = self
+            .output
+            .back()
+            .is_some_and(|prev| prev.is_error || prev.in_trace)
+            && OutputLine::looks_like_trace(&plain_text(&line.content));
+        if !line.in_trace && !line.hidden {
+            if line.is_error {
+                self.unread_errors += 1;
+            } else if line.is_warning {
+                self.unread_warnings += 1;
+            }
+        }
+
         if self.output.len() >= MAX_OUTPUT_LINES {
             self.output.pop_front();
         }
         self.output.push_back(line);
+        self.lines_seen += 1;
+    }
+
+    /// Start a new run segment; output from now on belongs to it
+    pub fn begin_run(&mut self, pid: Option<u32>) {
+        let now = SystemTime::now();
+        if let Some(open) = self.runs.back_mut().filter(|r| r.is_running()) {
+            open.ended_at = Some(now);
+        }
+        if self.runs.len() >= MAX_RUNS {
+            self.runs.pop_front();
+        }
+        let number = self.runs.back().map(|r| r.number + 1).unwrap_or(1);
+        self.runs.push_back(RunRecord {
+            number,
+            pid,
+            started_at: now,
+            ended_at: None,
+            exit: None,
+            requested: false,
+            crash_report: None,
+            first_line: self.lines_seen,
+        });
+    }
+
+    /// Close the current run. Returns it, or None if no run was open.
+    pub fn end_run(&mut self, exit: ExitReason, requested: bool) -> Option<&mut RunRecord> {
+        let run = self.runs.back_mut().filter(|r| r.is_running())?;
+        run.ended_at = Some(SystemTime::now());
+        run.exit = Some(exit);
+        run.requested = requested;
+        Some(run)
+    }
+
+    /// Output lines of a run that are still in the buffer.
+    /// Without any recorded runs the whole buffer is returned.
+    pub fn run_output(&self, index: usize) -> impl Iterator<Item = &OutputLine> {
+        self.numbered_run_output(index).map(|(_, line)| line)
+    }
+
+    /// Like `run_output`, paired with each line's absolute number
+    pub fn numbered_run_output(&self, index: usize) -> impl Iterator<Item = (u64, &OutputLine)> {
+        let buffer_start = self.lines_seen - self.output.len() as u64;
+        let (start, end) = match self.runs.get(index) {
+            Some(run) => (
+                run.first_line,
+                self.runs
+                    .get(index + 1)
+                    .map(|next| next.first_line)
+                    .unwrap_or(self.lines_seen),
+            ),
+            None => (buffer_start, self.lines_seen),
+        };
+        let skip = start.saturating_sub(buffer_start) as usize;
+        let take = end.saturating_sub(start.max(buffer_start)) as usize;
+        (buffer_start..)
+            .zip(self.output.iter())
+            .skip(skip)
+            .take(take)
+    }
+
+    /// Reset the unread error and warning counters
+    pub fn mark_read(&mut self) {
+        self.unread_errors = 0;
+        self.unread_warnings = 0;
     }
 
     pub fn clear_output(&mut self) {
         self.output.clear();
         self.scroll_offset = 0;
+        self.mark_read();
     }
 
     pub fn is_supervised(&self) -> bool {
         self.config.supervised
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_output_segments() {
+        let config = ProcessConfig::new(ProcessKind::Queue, "php", PathBuf::from("."));
+        let mut process = Process::new(config);
+
+        process.begin_run(Some(1));
+        process.add_output(OutputLine::stdout("first".to_string()));
+        process.end_run(ExitReason::Code(1), false);
+        process.begin_run(Some(2));
+        process.add_output(OutputLine::stdout("second".to_string()));
+        process.add_output(OutputLine::stdout("third".to_string()));
+
+        let first: Vec<&str> = process.run_output(0).map(|l| l.content.as_str()).collect();
+        let second: Vec<&str> = process.run_output(1).map(|l| l.content.as_str()).collect();
+        assert_eq!(first, vec!["first"]);
+        assert_eq!(second, vec!["second", "third"]);
+        assert_eq!(process.runs[0].outcome(), "exit 1");
+        assert_eq!(process.runs[1].number, 2);
+
+        // Cleared output leaves every run empty
+        process.clear_output();
+        assert_eq!(process.run_output(1).count(), 0);
+    }
+
+    #[test]
+    fn test_stack_trace_grouped_with_error() {
+        let config = ProcessConfig::new(ProcessKind::Serve, "php", PathBuf::from("."));
+        let mut process = Process::new(config);
+
+        for line in [
+            "PHP Fatal error:  Uncaught RuntimeException: boom in /app/index.php:3",
+            "Stack trace:",
+            "#0 /app/routes/web.php(12): handle()",
+            "#1 {main}",
+            "  thrown in /app/index.php on line 3",
+            "Server running",
+            "#2 not a trace after a normal line",
+        ] {
+            process.add_output(OutputLine::stdout(line.to_string()));
+        }
+
+        let in_trace: Vec<bool> = process.output.iter().map(|l| l.in_trace).collect();
+        assert_eq!(in_trace, vec![false, true, true, true, true, false, false]);
+        assert_eq!(process.unread_errors, 1);
+
+        process.mark_read();
+        assert_eq!(process.unread_errors, 0);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_signal_mapping() {
+        use nix::sys::signal::Signal;
+
+        let signals: Vec<Signal> = ProcessSignal::all().iter().map(|s| s.to_nix()).collect();
+        assert_eq!(
+            signals,
+            vec![
+                Signal::SIGSTOP,
+                Signal::SIGCONT,
+                Signal::SIGHUP,
+                Signal::SIGUSR1,
+                Signal::SIGUSR2,
+                Signal::SIGINT,
+                Signal::SIGTERM,
+            ]
+        );
+        for signal in ProcessSignal::all() {
+            assert_eq!(signal.name(), signal.to_nix().as_str());
+        }
+    }
+
+    #[test]
+    fn test_paused_status_transition() {
+        let paused = ProcessSignal::Stop.status_after(ProcessStatus::Running);
+        assert_eq!(paused, ProcessStatus::Paused);
+        // Other signals leave a frozen process frozen
+        assert_eq!(
+            ProcessSignal::Hup.status_after(paused),
+            ProcessStatus::Paused
+        );
+        assert_eq!(
+            ProcessSignal::Cont.status_after(paused),
+            ProcessStatus::Running
+        );
+        // Resuming a process that was never paused changes nothing
+        assert_eq!(
+            ProcessSignal::Cont.status_after(ProcessStatus::Running),
+            ProcessStatus::Running
+        );
+    }
+}
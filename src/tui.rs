@@ -26,6 +26,14 @@ pub fn restore() -> Result<()> {
     Ok(())
 }
 
+/// Take the terminal back after an external program used it
+pub fn resume(terminal: &mut Tui) -> Result<()> {
+    enable_raw_mode()?;
+    execute!(stdout(), EnterAlternateScreen)?;
+    terminal.clear()?;
+    Ok(())
+}
+
 /// Install panic hook to restore terminal on panic
 pub fn install_panic_hook() {
     let original_hook = std::panic::take_hook();
@@ -0,0 +1,58 @@
+use ratatui::{
+    prelude::*,
+    widgets::{Block, BorderType, Borders, Clear, List, ListItem, ListState, Padding},
+};
+
+use crate::app::App;
+use crate::export::ExportFormat;
+use crate::ui::tabs::Tab;
+use crate::ui::theme::Theme;
+
+/// Popup listing the formats the current view can be exported as
+pub fn render(frame: &mut Frame, area: Rect, app: &App, selected: usize) {
+    let formats = ExportFormat::all();
+    let width = 52.min(area.width);
+    let height = (formats.len() as u16 + 2).min(area.height);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+
+    let items: Vec<ListItem> = formats
+        .iter()
+        .map(|format| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:<12}", format.name()),
+                    Style::default().fg(Theme::ACCENT),
+                ),
+                Span::styled(format.description(), Style::default().fg(Theme::TEXT_DIM)),
+            ]))
+        })
+        .collect();
+
+    let title = match (app.active_tab, app.selected_process()) {
+        (Tab::Logs, _) => format!(" Export {} Log Entries ", app.filtered_logs().len()),
+        (_, Some(process)) => format!(" Export {} Output ", app.registry.display_name(&process.id)),
+        _ => " Export ".to_string(),
+    };
+    let block = Block::default()
+        .title(title)
+        .title_style(Theme::title_style())
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(Theme::BORDER_FOCUSED))
+        .padding(Padding::horizontal(1));
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(Style::default().bg(Theme::SELECTION_BG));
+
+    let mut state = ListState::default();
+    state.select(Some(selected));
+
+    frame.render_widget(Clear, popup);
+    frame.render_stateful_widget(list, popup, &mut state);
+}
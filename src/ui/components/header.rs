@@ -1,18 +1,21 @@
+use std::path::Path;
+
 use ratatui::{
     prelude::*,
-    widgets::{Block, Borders, Tabs},
+    widgets::{Block, Borders, Paragraph, Tabs},
 };
 
 use crate::app::App;
-use crate::ui::tabs::Tab;
-use crate::ui::theme::Theme;
+use crate::process::sail::ContainerState;
+use crate::ui::theme::{symbols, Theme};
 
 pub fn render(frame: &mut Frame, area: Rect, app: &App) {
-    let titles: Vec<Line> = Tab::all()
+    let visible = app.visible_tabs();
+    let titles: Vec<Line> = visible
         .iter()
         .map(|tab| {
             let shortcut = tab.shortcut();
-            let name = tab.name();
+            let name = app.tab_name(*tab);
             Line::from(vec![
                 Span::styled(
                     format!("[{}] ", shortcut),
@@ -23,7 +26,7 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
         })
         .collect();
 
-    let selected_index = Tab::all()
+    let selected_index = visible
         .iter()
         .position(|t| *t == app.active_tab)
         .unwrap_or(0);
@@ -44,4 +47,67 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
         .divider(Span::styled(" │ ", Style::default().fg(Theme::TEXT_MUTED)));
 
     frame.render_widget(tabs, area);
+
+    let labels: Vec<(&str, &str)> = visible
+        .iter()
+        .map(|tab| (tab.shortcut(), app.tab_name(*tab)))
+        .collect();
+    render_status(frame, area, &labels, app);
+}
+
+/// Render the project root and Sail container state right-aligned on the tab
+/// row, dropping the root first when space is short
+fn render_status(frame: &mut Frame, area: Rect, tabs: &[(&str, &str)], app: &App) {
+    let mut root = vec![Span::styled(
+        format!("{} ", display_path(&app.working_dir)),
+        Style::default().fg(Theme::TEXT_DIM),
+    )];
+    let mut sail = Vec::new();
+    if let Some(state) = app.sail_state {
+        let (symbol, color) = match state {
+            ContainerState::Running => (symbols::RUNNING, Theme::SUCCESS),
+            ContainerState::Starting => (symbols::RESTARTING, Theme::WARNING),
+            ContainerState::Unhealthy => (symbols::FAILED, Theme::ERROR),
+            ContainerState::Stopped | ContainerState::Missing => (symbols::STOPPED, Theme::ERROR),
+        };
+        root.push(Span::styled("│ ", Style::default().fg(Theme::TEXT_MUTED)));
+        sail = vec![
+            Span::styled("Sail ", Style::default().fg(Theme::TEXT_MUTED)),
+            Span::styled(
+                format!("{} {} ", symbol, state.label()),
+                Style::default().fg(color),
+            ),
+        ];
+    }
+
+    // Only draw what fits beside the tabs
+    let tabs_width: usize = tabs
+        .iter()
+        .map(|(shortcut, name)| shortcut.len() + name.len() + 5)
+        .sum::<usize>()
+        + 3 * tabs.len().saturating_sub(1);
+    let fits = |line: &Line| (area.width as usize) > tabs_width + line.width();
+
+    let full = Line::from([root, sail.clone()].concat());
+    let line = if fits(&full) {
+        full
+    } else {
+        let sail = Line::from(sail);
+        if sail.spans.is_empty() || !fits(&sail) {
+            return;
+        }
+        sail
+    };
+
+    let width = line.width() as u16;
+    let status_area = Rect::new(area.x + area.width - width, area.y, width, 1);
+    frame.render_widget(Paragraph::new(line), status_area);
+}
+
+/// Project root for display, with the home directory shortened to `~`
+fn display_path(path: &Path) -> String {
+    std::env::var_os("HOME")
+        .and_then(|home| path.strip_prefix(home).ok().map(Path::to_path_buf))
+        .map(|rest| Path::new("~").join(rest).display().to_string())
+        .unwrap_or_else(|| path.display().to_string())
 }
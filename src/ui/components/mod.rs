@@ -1,5 +1,7 @@
+mod export_menu;
 mod header;
 mod statusbar;
 
+pub use export_menu::render as render_export_menu;
 pub use header::render as render_header;
 pub use statusbar::render as render_statusbar;
@@ -1,6 +1,6 @@
 use ratatui::{prelude::*, widgets::Paragraph};
 
-use crate::app::App;
+use crate::app::{App, LogsView, ProcessesView};
 use crate::ui::tabs::Tab;
 use crate::ui::theme::Theme;
 
@@ -45,18 +45,57 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
 
             // Context-aware hints based on active tab
             match app.active_tab {
+                _ if app.export_menu.is_some() => {
+                    spans.extend(key_hint("j/k", "Format"));
+                    spans.push(separator());
+                    spans.extend(key_hint("Enter", "Export"));
+                    spans.push(separator());
+                    spans.extend(key_hint("Esc", "Cancel"));
+                }
                 Tab::Processes => {
-                    if app.processes_tab.is_output_view() {
+                    if app.processes_tab.signal_menu.is_some() {
+                        spans.extend(key_hint("j/k", "Select"));
+                        spans.push(separator());
+                        spans.extend(key_hint("Enter", "Send"));
+                        spans.push(separator());
+                        spans.extend(key_hint("Esc", "Cancel"));
+                    } else if app.processes_tab.view == ProcessesView::Crashes {
+                        spans.extend(key_hint("Esc", "Back"));
+                        spans.push(separator());
+                        spans.extend(key_hint("j/k", "Select"));
+                        spans.push(separator());
+                        spans.extend(key_hint("PgUp/PgDn", "Scroll"));
+                    } else if app.processes_tab.view == ProcessesView::Inspector {
+                        spans.extend(key_hint("Esc", "Back"));
+                        spans.push(separator());
+                        spans.extend(key_hint("j/k", "Scroll"));
+                    } else if app.processes_tab.is_output_view() {
                         spans.extend(key_hint("Enter", "Back"));
                         spans.push(separator());
                         spans.extend(key_hint("c", "Clear"));
                         spans.push(separator());
                         spans.extend(key_hint("r", "Restart"));
+                        spans.push(separator());
+                        spans.extend(key_hint("f", "Filtered/Raw"));
+                        spans.push(separator());
+                        spans.extend(key_hint("n/N", "Errors"));
+                        spans.push(separator());
+                        spans.extend(key_hint("o", "Open File"));
+                        spans.push(separator());
+                        spans.extend(key_hint("[/]", "Runs"));
+                        spans.push(separator());
+                        spans.extend(key_hint("E", "Export"));
                     } else {
                         spans.extend(key_hint("j/k", "Navigate"));
                         spans.push(separator());
                         spans.extend(key_hint("Enter", "View Output"));
                         spans.push(separator());
+                        spans.extend(key_hint("i", "Inspect"));
+                        spans.push(separator());
+                        spans.extend(key_hint("P", "Signal"));
+                        spans.push(separator());
+                        spans.extend(key_hint("C", "Crashes"));
+                        spans.push(separator());
                         spans.extend(key_hint("s", "Start"));
                         spans.push(separator());
                         spans.extend(key_hint("x", "Stop"));
@@ -64,19 +103,69 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
                         spans.extend(key_hint("r", "Restart"));
                         spans.push(separator());
                         spans.extend(key_hint("R", "Restart All"));
+                        if app.is_sail && !app.sail_state.is_some_and(|s| s.is_up()) {
+                            spans.push(separator());
+                            spans.extend(key_hint("U", "Sail Up"));
+                        }
                     }
                 }
                 Tab::Logs => {
-                    if app.logs_tab.input_mode {
+                    if app.logs_tab.detail_open {
+                        spans.extend(key_hint("Esc", "Back"));
+                        spans.push(separator());
+                        spans.extend(key_hint("j/k", "Scroll"));
+                        spans.push(separator());
+                        spans.extend(key_hint("g/G", "Top/Bottom"));
+                        spans.push(separator());
+                        spans.extend(key_hint("n/N", "Location"));
+                        spans.push(separator());
+                        spans.extend(key_hint("o", "Open"));
+                    } else if app.logs_tab.view == LogsView::Exceptions {
+                        spans.extend(key_hint("j/k", "Select"));
+                        spans.push(separator());
+                        spans.extend(key_hint("Enter", "Occurrences"));
+                        spans.push(separator());
+                        spans.extend(key_hint("s", "Sort"));
+                        spans.push(separator());
+                        spans.extend(key_hint("Esc", "Back"));
+                    } else if app.logs_tab.view == LogsView::Occurrences {
+                        spans.extend(key_hint("j/k", "Select"));
+                        spans.push(separator());
+                        spans.extend(key_hint("Enter", "Details"));
+                        spans.push(separator());
+                        spans.extend(key_hint("Esc", "Back"));
+                    } else if app.logs_tab.range_input.is_some() {
+                        spans.extend(key_hint("Esc", "Cancel"));
+                        spans.push(separator());
+                        spans.extend(key_hint("Enter", "Apply Range"));
+                    } else if app.logs_tab.input_mode {
                         spans.extend(key_hint("Esc", "Exit Search"));
                         spans.push(separator());
                         spans.extend(key_hint("Enter", "Confirm"));
+                        spans.push(separator());
+                        spans.extend(key_hint("Tab", "Filter/Find"));
                     } else {
                         spans.extend(key_hint("/", "Search"));
                         spans.push(separator());
+                        if app.logs_tab.find_mode {
+                            spans.extend(key_hint("n/N", "Next/Prev Match"));
+                            spans.push(separator());
+                        }
                         spans.extend(key_hint("f", "Filter"));
                         spans.push(separator());
-                        spans.extend(key_hint("j/k", "Scroll"));
+                        spans.extend(key_hint("t/T", "Time"));
+                        spans.push(separator());
+                        spans.extend(key_hint("a", "Relative"));
+                        spans.push(separator());
+                        spans.extend(key_hint("e/z", "Expand"));
+                        spans.push(separator());
+                        spans.extend(key_hint("j/k", "Select"));
+                        spans.push(separator());
+                        spans.extend(key_hint("Enter", "Details"));
+                        spans.push(separator());
+                        spans.extend(key_hint("x", "Exceptions"));
+                        spans.push(separator());
+                        spans.extend(key_hint("E", "Export"));
                         spans.push(separator());
                         spans.extend(key_hint("g/G", "Top/Bottom"));
                         spans.push(separator());
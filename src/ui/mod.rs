@@ -28,6 +28,10 @@ pub fn render(frame: &mut Frame, app: &App) {
         Tab::About => tabs::render_about(frame, layout.content, app),
     }
 
+    if let Some(selected) = app.export_menu {
+        components::render_export_menu(frame, layout.content, app, selected);
+    }
+
     // Render status bar
     components::render_statusbar(frame, layout.status_bar, app);
 }
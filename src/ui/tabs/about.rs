@@ -76,7 +76,16 @@ pub fn render(frame: &mut Frame, area: Rect, _app: &App) {
         shortcut_line("x", "Stop selected process"),
         shortcut_line("r", "Restart selected process"),
         shortcut_line("R", "Restart all processes"),
+        shortcut_line("i", "Inspect resolved config and process tree"),
+        shortcut_line("P", "Send a signal (pause, resume, HUP, USR1/USR2)"),
+        shortcut_line("U", "Run sail up -d (Sail projects)"),
         shortcut_line("c", "Clear output (in output view)"),
+        shortcut_line("[ / ]", "Previous/next run (in output view)"),
+        shortcut_line("f", "Filtered/raw output (in output view)"),
+        shortcut_line("n / N", "Next/previous error (in output view)"),
+        shortcut_line("o", "Open error's file:line in editor (in output view)"),
+        shortcut_line("C", "Browse crash reports"),
+        shortcut_line("E", "Export process output"),
         shortcut_line("s/v/q/h/b", "Quick restart by hotkey"),
         Line::from(""),
         Line::from(""),
@@ -92,7 +101,23 @@ pub fn render(frame: &mut Frame, area: Rect, _app: &App) {
         )]),
         Line::from(""),
         shortcut_line("/", "Focus search"),
+        shortcut_line("Tab", "Filter or find mode (while searching)"),
+        shortcut_line(
+            "Ctrl+R / Ctrl+S",
+            "Regex / case-sensitive (while searching)",
+        ),
+        shortcut_line("n / N", "Next/previous match (find mode)"),
+        shortcut_line("j / k", "Select entry"),
+        shortcut_line("Enter", "Entry details (Esc to close)"),
+        shortcut_line("n / N, o", "Select / open file:line (in details)"),
+        shortcut_line("x", "Exceptions grouped by class and origin"),
+        shortcut_line("E", "Export the shown entries"),
         shortcut_line("f", "Cycle filter level"),
+        shortcut_line("t", "Cycle time filter (5m, 15m, 1h, 24h, since restart)"),
+        shortcut_line("T", "Custom time range, e.g. 10:00..10:30"),
+        shortcut_line("a", "Relative / absolute entry times"),
+        shortcut_line("e", "Expand / collapse the selected entry's trace"),
+        shortcut_line("z", "Expand / collapse all traces"),
         shortcut_line("c", "Clear logs"),
         shortcut_line("g / G", "Go to top / bottom"),
         Line::from(""),
@@ -8,6 +8,7 @@ use ratatui::{
 };
 
 use crate::app::App;
+use crate::ui::tabs::Tab;
 use crate::ui::theme::Theme;
 
 pub fn render(frame: &mut Frame, area: Rect, app: &App) {
@@ -43,7 +44,11 @@ fn render_command_list(frame: &mut Frame, area: Rect, app: &App) {
         .unwrap_or(&[]);
     let commands = app.artisan_tab.current_command_display(favorites);
 
-    let title = format!(" Artisan Commands ({}) ", commands.len());
+    let title = format!(
+        " {} Commands ({}) ",
+        app.tab_name(Tab::Artisan),
+        commands.len()
+    );
 
     let block = Block::default()
         .title(title)
@@ -165,14 +170,15 @@ fn render_command_details(frame: &mut Frame, area: Rect, app: &App) {
 
     let max_width = inner.width.saturating_sub(2) as usize;
 
-    if let Some(cmd) = app.artisan_tab.selected_artisan_command(favorites) {
+    let selected = app.artisan_tab.selected_artisan_command(favorites);
+    if let (Some(cmd), Some(console)) = (selected, app.console.as_ref()) {
         let mut lines = Vec::new();
 
         // Command name
         lines.push(Line::from(vec![
             Span::styled("Command: ", Style::default().fg(Theme::ACCENT)),
             Span::styled(
-                format!("php artisan {}", cmd.name),
+                console.display(&cmd.name),
                 Style::default()
                     .fg(Theme::TEXT)
                     .add_modifier(Modifier::BOLD),
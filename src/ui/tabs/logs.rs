@@ -1,17 +1,53 @@
 use ratatui::{
     prelude::*,
     widgets::{
-        Block, BorderType, Borders, Padding, Paragraph, Scrollbar, ScrollbarOrientation,
-        ScrollbarState, Wrap,
+        Block, BorderType, Borders, List, ListItem, ListState, Padding, Paragraph, Scrollbar,
+        ScrollbarOrientation, ScrollbarState, Wrap,
     },
 };
+use regex::Regex;
+use serde_json::Value;
 
-use crate::app::{App, LogLevel, LogLine};
+use crate::app::{App, LogLevel, LogLine, LogsView};
+use crate::log::detail::LogDetail;
+use crate::log::time::{format_relative, unix_now, TimeFilter};
 use crate::ui::theme::Theme;
+use crate::ui::TabLayout;
 
-/// Format a log line with timestamp, right-aligned level badge, and message
-fn format_log_line(log_line: &LogLine) -> Line<'static> {
-    let level_color = match log_line.level {
+/// Indent of continuation lines, lining them up with the entry's message
+const CONTINUATION_INDENT: usize = 32;
+
+/// Split text into spans, marking the search matches
+fn highlight_matches(text: &str, style: Style, pattern: Option<&Regex>) -> Vec<Span<'static>> {
+    let Some(pattern) = pattern else {
+        return vec![Span::styled(text.to_string(), style)];
+    };
+
+    let mut spans = Vec::new();
+    let mut last = 0;
+    for m in pattern.find_iter(text).filter(|m| !m.is_empty()) {
+        if m.start() > last {
+            spans.push(Span::styled(text[last..m.start()].to_string(), style));
+        }
+        spans.push(Span::styled(
+            m.as_str().to_string(),
+            Theme::search_match_style(),
+        ));
+        last = m.end();
+    }
+    if last < text.len() || spans.is_empty() {
+        spans.push(Span::styled(text[last..].to_string(), style));
+    }
+    spans
+}
+
+/// Rows of the log list for a terminal of the given size
+pub fn view_rows(terminal: Rect) -> usize {
+    TabLayout::new(terminal).content.height.saturating_sub(5) as usize
+}
+
+fn level_color(level: LogLevel) -> Color {
+    match level {
         LogLevel::Emergency | LogLevel::Alert | LogLevel::Critical => Theme::LOG_CRITICAL,
         LogLevel::Error => Theme::LOG_ERROR,
         LogLevel::Warning => Theme::LOG_WARNING,
@@ -19,7 +55,13 @@ fn format_log_line(log_line: &LogLine) -> Line<'static> {
         LogLevel::Info => Theme::LOG_INFO,
         LogLevel::Debug => Theme::LOG_DEBUG,
         LogLevel::Unknown => Theme::TEXT_MUTED,
-    };
+    }
+}
+
+/// Format a log line with timestamp, right-aligned level badge, and message.
+/// With `now`, the timestamp shows as an age such as `3m ago`.
+fn format_log_line(log_line: &LogLine, pattern: Option<&Regex>, now: Option<i64>) -> Line<'static> {
+    let level_color = level_color(log_line.level);
 
     let level_text = match log_line.level {
         LogLevel::Emergency => "EMERGENCY",
@@ -33,9 +75,12 @@ fn format_log_line(log_line: &LogLine) -> Line<'static> {
         LogLevel::Unknown => " UNKNOWN",
     };
 
-    let (timestamp, message) = extract_timestamp_and_message(&log_line.content);
+    let mut timestamp = list_time(&log_line.time);
+    if let (Some(now), Some(time)) = (now, log_line.timestamp) {
+        timestamp = format!("{:>19}", format_relative(time, now));
+    }
 
-    let spans = vec![
+    let mut spans = vec![
         Span::styled(timestamp, Style::default().fg(Theme::TEXT_MUTED)),
         Span::raw("  "),
         Span::styled(
@@ -45,34 +90,101 @@ fn format_log_line(log_line: &LogLine) -> Line<'static> {
                 .add_modifier(Modifier::BOLD),
         ),
         Span::raw("  "),
-        Span::styled(message, Style::default().fg(Theme::TEXT)),
     ];
+    spans.extend(highlight_matches(
+        &log_line.message,
+        Style::default().fg(Theme::TEXT),
+        pattern,
+    ));
+
+    if !log_line.continuation.is_empty() {
+        spans.push(Span::styled(
+            format!("  ▸ +{} lines", log_line.continuation.len()),
+            Style::default().fg(Theme::TEXT_MUTED),
+        ));
+    }
 
     Line::from(spans)
 }
 
-/// Extract timestamp and message from Laravel log line
-fn extract_timestamp_and_message(content: &str) -> (String, String) {
-    if content.starts_with('[') {
-        if let Some(end_bracket) = content.find(']') {
-            let timestamp = &content[1..end_bracket];
-            let rest = &content[end_bracket + 1..];
-            if let Some(colon_pos) = rest.find(':') {
-                let message = rest[colon_pos + 1..].trim();
-                return (timestamp.to_string(), message.to_string());
-            }
-            return (timestamp.to_string(), rest.trim().to_string());
-        }
+/// Format a log entry, with its continuation lines indented below the
+/// header when expanded. The selected entry gets a background.
+fn format_log_entry(
+    log_line: &LogLine,
+    expanded: bool,
+    pattern: Option<&Regex>,
+    focused: bool,
+    now: Option<i64>,
+) -> Vec<Line<'static>> {
+    let mut header = format_log_line(log_line, pattern, now);
+    if focused {
+        header.style = header.style.bg(Theme::SELECTION_BG);
     }
-    ("                   ".to_string(), content.to_string())
+
+    let mut lines = vec![header];
+    if expanded {
+        lines.extend(log_line.continuation.iter().map(|line| {
+            let mut spans = vec![Span::raw(" ".repeat(CONTINUATION_INDENT))];
+            spans.extend(highlight_matches(
+                line,
+                Style::default().fg(Theme::TEXT_DIM),
+                pattern,
+            ));
+            Line::from(spans)
+        }));
+    }
+    lines
+}
+
+/// Timestamp column of the list: ISO 8601 times shortened to
+/// `YYYY-MM-DD HH:MM:SS`, blank for lines without one
+fn list_time(time: &str) -> String {
+    let short = match time.get(..19) {
+        Some(head) if time.as_bytes().get(10) == Some(&b'T') => head.replacen('T', " ", 1),
+        _ => time.to_string(),
+    };
+    format!("{:<19}", short)
 }
 
 pub fn render(frame: &mut Frame, area: Rect, app: &App) {
+    if app.logs_tab.detail_open {
+        if let Some(log) = app.selected_log() {
+            let locations: Vec<String> = app
+                .detail_file_refs()
+                .iter()
+                .map(|file_ref| file_ref.display(&app.working_dir))
+                .collect();
+            render_detail(
+                frame,
+                area,
+                app.logs_tab.detail_scroll,
+                log,
+                &locations,
+                app.logs_tab.detail_ref,
+            );
+            return;
+        }
+    }
+    match app.logs_tab.view {
+        LogsView::Exceptions => return render_exceptions(frame, area, app),
+        LogsView::Occurrences => return render_occurrences(frame, area, app),
+        LogsView::Entries => {}
+    }
+
     // Get filtered logs
     let filtered_logs = app.filtered_logs();
+    let search = app.logs_tab.search_pattern();
+    let pattern = search.and_then(|s| s.as_ref().ok());
+    let find = pattern.filter(|_| app.logs_tab.find_mode);
+    let cursor = app.logs_tab.selected_entry;
+    let now = app.logs_tab.relative_times.then(unix_now);
     let lines: Vec<Line> = filtered_logs
         .iter()
-        .map(|log| format_log_line(log))
+        .flat_map(|log| {
+            let focused = cursor == Some(log.id);
+            let expanded = app.logs_tab.is_expanded(log);
+            format_log_entry(log, expanded, pattern, focused, now)
+        })
         .collect();
 
     // Calculate visible area height
@@ -91,11 +203,21 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
     // Build title with filter info
     let filter_info = app.logs_tab.filter_name();
     let file_info = app.logs_tab.file_name();
+    let time_info = match app.logs_tab.time_filter {
+        TimeFilter::All => String::new(),
+        filter => format!(" | Time: {}", filter.name(&app.log_timezone)),
+    };
+    let history = match app.log_history.lines {
+        0 => String::new(),
+        lines => format!(" | History: +{}", lines),
+    };
     let title = format!(
-        " Logs [{}] File: {} | Level: {} ",
+        " Logs [{}] File: {} | Level: {}{}{} ",
         filtered_logs.len(),
         file_info,
-        filter_info
+        filter_info,
+        time_info,
+        history
     );
 
     let block = Block::default()
@@ -140,9 +262,20 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
         height: 1,
     };
 
-    let search_line = if app.logs_tab.input_mode {
+    let options = format!("Search ({}): ", app.logs_tab.search_options());
+    let mut search_line = if let Some(input) = &app.logs_tab.range_input {
         Line::from(vec![
-            Span::styled("Search: ", Style::default().fg(Theme::ACCENT)),
+            Span::styled("Time range: ", Style::default().fg(Theme::ACCENT)),
+            Span::styled(input.clone(), Style::default().fg(Theme::TEXT)),
+            Span::styled("█", Style::default().fg(Theme::ACCENT)),
+            Span::styled(
+                "  e.g. 10:00..10:30, 2024-01-15 09:00..12:00, 30m",
+                Style::default().fg(Theme::TEXT_MUTED),
+            ),
+        ])
+    } else if app.logs_tab.input_mode {
+        Line::from(vec![
+            Span::styled(options, Style::default().fg(Theme::ACCENT)),
             Span::styled(
                 app.logs_tab.search_query.clone(),
                 Style::default().fg(Theme::TEXT),
@@ -151,7 +284,7 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
         ])
     } else if !app.logs_tab.search_query.is_empty() {
         Line::from(vec![
-            Span::styled("Search: ", Style::default().fg(Theme::TEXT_MUTED)),
+            Span::styled(options, Style::default().fg(Theme::TEXT_MUTED)),
             Span::styled(
                 format!("\"{}\"", app.logs_tab.search_query),
                 Style::default().fg(Theme::TEXT_DIM),
@@ -165,6 +298,29 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
         )])
     };
 
+    match (search, find) {
+        (Some(Err(_)), _) => search_line.spans.push(Span::styled(
+            "  invalid regex",
+            Style::default().fg(Theme::ERROR),
+        )),
+        (_, Some(pattern)) => {
+            let hits: Vec<u64> = filtered_logs
+                .iter()
+                .filter(|log| log.is_match(pattern))
+                .map(|log| log.id)
+                .collect();
+            let position = cursor
+                .and_then(|id| hits.iter().position(|&hit| hit == id))
+                .map(|i| format!("{}/", i + 1))
+                .unwrap_or_default();
+            search_line.spans.push(Span::styled(
+                format!("  {}{} matches", position, hits.len()),
+                Style::default().fg(Theme::TEXT_DIM),
+            ));
+        }
+        _ => {}
+    }
+
     frame.render_widget(Paragraph::new(search_line), search_area);
 
     // Footer with actions
@@ -175,35 +331,530 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
         height: 1,
     };
 
-    let footer = if app.logs_tab.input_mode {
+    let footer = if app.logs_tab.range_input.is_some() {
+        Paragraph::new(Line::from(vec![
+            Span::styled("[Esc] ", Style::default().fg(Theme::ACCENT)),
+            Span::styled("Cancel", Style::default().fg(Theme::TEXT_DIM)),
+            Span::raw("  "),
+            Span::styled("[Enter] ", Style::default().fg(Theme::ACCENT)),
+            Span::styled(
+                "Apply (empty for all)",
+                Style::default().fg(Theme::TEXT_DIM),
+            ),
+        ]))
+    } else if app.logs_tab.input_mode {
         Paragraph::new(Line::from(vec![
             Span::styled("[Esc] ", Style::default().fg(Theme::ACCENT)),
             Span::styled("Cancel", Style::default().fg(Theme::TEXT_DIM)),
             Span::raw("  "),
             Span::styled("[Enter] ", Style::default().fg(Theme::ACCENT)),
             Span::styled("Confirm", Style::default().fg(Theme::TEXT_DIM)),
+            Span::raw("  "),
+            Span::styled("[Tab] ", Style::default().fg(Theme::ACCENT)),
+            Span::styled("Filter/Find", Style::default().fg(Theme::TEXT_DIM)),
+            Span::raw("  "),
+            Span::styled("[Ctrl+R] ", Style::default().fg(Theme::ACCENT)),
+            Span::styled("Regex", Style::default().fg(Theme::TEXT_DIM)),
+            Span::raw("  "),
+            Span::styled("[Ctrl+S] ", Style::default().fg(Theme::ACCENT)),
+            Span::styled("Case", Style::default().fg(Theme::TEXT_DIM)),
         ]))
     } else {
-        Paragraph::new(Line::from(vec![
+        let mut spans = vec![
             Span::styled("[/] ", Style::default().fg(Theme::ACCENT)),
             Span::styled("Search", Style::default().fg(Theme::TEXT_DIM)),
             Span::raw("  "),
+        ];
+        if find.is_some() {
+            spans.extend([
+                Span::styled("[n/N] ", Style::default().fg(Theme::ACCENT)),
+                Span::styled("Next/Prev Match", Style::default().fg(Theme::TEXT_DIM)),
+                Span::raw("  "),
+            ]);
+        }
+        spans.extend([
+            Span::styled("[Enter] ", Style::default().fg(Theme::ACCENT)),
+            Span::styled("Details", Style::default().fg(Theme::TEXT_DIM)),
+            Span::raw("  "),
+            Span::styled("[x] ", Style::default().fg(Theme::ACCENT)),
+            Span::styled("Exceptions", Style::default().fg(Theme::TEXT_DIM)),
+            Span::raw("  "),
+        ]);
+        spans.extend([
             Span::styled("[f] ", Style::default().fg(Theme::ACCENT)),
             Span::styled("Level", Style::default().fg(Theme::TEXT_DIM)),
             Span::raw("  "),
             Span::styled("[F] ", Style::default().fg(Theme::ACCENT)),
             Span::styled("File", Style::default().fg(Theme::TEXT_DIM)),
             Span::raw("  "),
+            Span::styled("[t/T] ", Style::default().fg(Theme::ACCENT)),
+            Span::styled("Time", Style::default().fg(Theme::TEXT_DIM)),
+            Span::raw("  "),
+            Span::styled("[e] ", Style::default().fg(Theme::ACCENT)),
+            Span::styled("Expand", Style::default().fg(Theme::TEXT_DIM)),
+            Span::raw("  "),
+            Span::styled("[z] ", Style::default().fg(Theme::ACCENT)),
+            Span::styled(
+                if app.logs_tab.expanded {
+                    "Collapse all"
+                } else {
+                    "Expand all"
+                },
+                Style::default().fg(Theme::TEXT_DIM),
+            ),
+            Span::raw("  "),
             Span::styled("[c] ", Style::default().fg(Theme::ACCENT)),
             Span::styled("Clear", Style::default().fg(Theme::TEXT_DIM)),
             Span::raw("  "),
             Span::styled("[g/G] ", Style::default().fg(Theme::ACCENT)),
             Span::styled("Top/Bottom", Style::default().fg(Theme::TEXT_DIM)),
-        ]))
+        ]);
+        Paragraph::new(Line::from(spans))
     };
     frame.render_widget(footer, footer_area);
 }
 
+fn section_title(title: &str) -> Line<'static> {
+    Line::from(Span::styled(
+        title.to_string(),
+        Style::default()
+            .fg(Theme::ACCENT)
+            .add_modifier(Modifier::BOLD),
+    ))
+}
+
+/// Pretty-print a JSON value with syntax colors, one line per row
+fn json_lines(value: &Value) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    let mut spans = Vec::new();
+    push_json(value, 0, &mut spans, &mut lines);
+    lines.push(Line::from(spans));
+    lines
+}
+
+fn push_json(
+    value: &Value,
+    depth: usize,
+    spans: &mut Vec<Span<'static>>,
+    lines: &mut Vec<Line<'static>>,
+) {
+    let punct = Style::default().fg(Theme::TEXT_MUTED);
+    let indent = |depth: usize| Span::raw("  ".repeat(depth));
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            spans.push(Span::styled("{", punct));
+            for (i, (key, value)) in map.iter().enumerate() {
+                lines.push(Line::from(std::mem::take(spans)));
+                spans.push(indent(depth + 1));
+                spans.push(Span::styled(
+                    format!("\"{}\"", key),
+                    Style::default().fg(Theme::ACCENT),
+                ));
+                spans.push(Span::styled(": ", punct));
+                push_json(value, depth + 1, spans, lines);
+                if i + 1 < map.len() {
+                    spans.push(Span::styled(",", punct));
+                }
+            }
+            lines.push(Line::from(std::mem::take(spans)));
+            spans.extend([indent(depth), Span::styled("}", punct)]);
+        }
+        Value::Array(items) if !items.is_empty() => {
+            spans.push(Span::styled("[", punct));
+            for (i, item) in items.iter().enumerate() {
+                lines.push(Line::from(std::mem::take(spans)));
+                spans.push(indent(depth + 1));
+                push_json(item, depth + 1, spans, lines);
+                if i + 1 < items.len() {
+                    spans.push(Span::styled(",", punct));
+                }
+            }
+            lines.push(Line::from(std::mem::take(spans)));
+            spans.extend([indent(depth), Span::styled("]", punct)]);
+        }
+        Value::String(_) => spans.push(Span::styled(
+            value.to_string(),
+            Style::default().fg(Theme::SUCCESS),
+        )),
+        Value::Number(_) => spans.push(Span::styled(
+            value.to_string(),
+            Style::default().fg(Theme::INFO),
+        )),
+        Value::Bool(_) | Value::Null => spans.push(Span::styled(
+            value.to_string(),
+            Style::default().fg(Theme::WARNING),
+        )),
+        // Empty object or array
+        _ => spans.push(Span::styled(value.to_string(), punct)),
+    }
+}
+
+/// Lines of the detail pane for an entry
+fn detail_lines(
+    log: &LogLine,
+    locations: &[String],
+    selected_location: usize,
+) -> Vec<Line<'static>> {
+    let detail = LogDetail::parse(&log.message, &log.continuation);
+    let label = |text: &str| {
+        Span::styled(
+            format!("{:<10}", text),
+            Style::default().fg(Theme::TEXT_MUTED),
+        )
+    };
+
+    let mut lines = vec![
+        Line::from(vec![
+            label("Time"),
+            Span::styled(log.time.clone(), Style::default().fg(Theme::TEXT)),
+            Span::styled(
+                log.timestamp
+                    .map(|time| format!("  ({})", format_relative(time, unix_now())))
+                    .unwrap_or_default(),
+                Style::default().fg(Theme::TEXT_MUTED),
+            ),
+        ]),
+        Line::from(vec![
+            label("Level"),
+            Span::styled(
+                format!("{:?}", log.level).to_uppercase(),
+                Style::default()
+                    .fg(level_color(log.level))
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+    ];
+    if let Some(channel) = &log.channel {
+        lines.push(Line::from(vec![
+            label("Channel"),
+            Span::styled(channel.clone(), Style::default().fg(Theme::TEXT)),
+        ]));
+    }
+    lines.push(Line::from(vec![
+        label("File"),
+        Span::styled(log.file.clone(), Style::default().fg(Theme::TEXT)),
+        Span::styled(
+            format!(" ({} format)", log.format.name()),
+            Style::default().fg(Theme::TEXT_MUTED),
+        ),
+    ]));
+    lines.push(Line::default());
+    lines.push(section_title("Message"));
+    lines.extend(
+        detail
+            .message
+            .lines()
+            .map(|line| Line::styled(line.to_string(), Style::default().fg(Theme::TEXT))),
+    );
+
+    if let Some(exception) = &detail.exception {
+        lines.push(Line::default());
+        lines.push(section_title("Exception"));
+        lines.push(Line::from(Span::styled(
+            exception.class.clone(),
+            Style::default()
+                .fg(Theme::ERROR)
+                .add_modifier(Modifier::BOLD),
+        )));
+        lines.push(Line::styled(
+            exception.message.clone(),
+            Style::default().fg(Theme::TEXT),
+        ));
+        if let Some(location) = &exception.location {
+            lines.push(Line::from(vec![
+                Span::styled("at ", Style::default().fg(Theme::TEXT_MUTED)),
+                Span::styled(location.clone(), Style::default().fg(Theme::INFO)),
+            ]));
+        }
+    }
+
+    if !locations.is_empty() {
+        lines.push(Line::default());
+        lines.push(section_title("Locations"));
+        lines.extend(locations.iter().enumerate().map(|(i, location)| {
+            let style = Style::default().fg(Theme::INFO);
+            if i == selected_location {
+                Line::from(vec![
+                    Span::styled("> ", Style::default().fg(Theme::ACCENT)),
+                    Span::styled(location.clone(), style.bg(Theme::SELECTION_BG)),
+                ])
+            } else {
+                Line::from(vec![Span::raw("  "), Span::styled(location.clone(), style)])
+            }
+        }));
+    }
+
+    if let Some(context) = &detail.context {
+        lines.push(Line::default());
+        lines.push(section_title("Context"));
+        lines.extend(json_lines(&Value::Object(context.clone())));
+    }
+
+    if !detail.trace.is_empty() {
+        lines.push(Line::default());
+        lines.push(section_title("Stack Trace"));
+        lines.extend(
+            detail
+                .trace
+                .iter()
+                .map(|line| Line::styled(line.clone(), Style::default().fg(Theme::TEXT_DIM))),
+        );
+    }
+
+    if !detail.other.is_empty() {
+        lines.push(Line::default());
+        lines.push(section_title("Other"));
+        lines.extend(
+            detail
+                .other
+                .iter()
+                .map(|line| Line::styled(line.clone(), Style::default().fg(Theme::TEXT_DIM))),
+        );
+    }
+
+    lines
+}
+
+/// Furthest the detail pane of the selected entry can scroll in a terminal
+/// of the given size
+pub fn detail_max_scroll(app: &App, terminal: Rect) -> u16 {
+    let Some(log) = app.selected_log().filter(|_| app.logs_tab.detail_open) else {
+        return 0;
+    };
+    let locations: Vec<String> = app
+        .detail_file_refs()
+        .iter()
+        .map(|file_ref| file_ref.display(&app.working_dir))
+        .collect();
+    let total_lines = detail_lines(log, &locations, app.logs_tab.detail_ref).len();
+    let inner_height = TabLayout::new(terminal).content.height.saturating_sub(3) as usize;
+    total_lines.saturating_sub(inner_height) as u16
+}
+
+/// Full view of one entry: header fields, exception, pretty-printed
+/// context and the stack trace
+fn render_detail(
+    frame: &mut Frame,
+    area: Rect,
+    scroll: u16,
+    log: &LogLine,
+    locations: &[String],
+    selected_location: usize,
+) {
+    let lines = detail_lines(log, locations, selected_location);
+    let inner_height = area.height.saturating_sub(3) as usize;
+    let total_lines = lines.len();
+    let scroll = scroll.min(total_lines.saturating_sub(inner_height) as u16);
+
+    let block = Block::default()
+        .title(" Log Entry ")
+        .title_style(Theme::title_style())
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(Theme::BORDER_FOCUSED))
+        .padding(Padding::horizontal(1));
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap { trim: false })
+            .scroll((scroll, 0)),
+        area,
+    );
+    if total_lines > inner_height {
+        render_scrollbar(frame, area, total_lines, inner_height, scroll);
+    }
+
+    let footer_area = Rect {
+        x: area.x + 2,
+        y: area.y + area.height.saturating_sub(2),
+        width: area.width.saturating_sub(4),
+        height: 1,
+    };
+    let mut hints = vec![
+        Span::styled("[Esc] ", Style::default().fg(Theme::ACCENT)),
+        Span::styled("Back", Style::default().fg(Theme::TEXT_DIM)),
+        Span::raw("  "),
+        Span::styled("[j/k] ", Style::default().fg(Theme::ACCENT)),
+        Span::styled("Scroll", Style::default().fg(Theme::TEXT_DIM)),
+        Span::raw("  "),
+        Span::styled("[g] ", Style::default().fg(Theme::ACCENT)),
+        Span::styled("Top", Style::default().fg(Theme::TEXT_DIM)),
+    ];
+    if !locations.is_empty() {
+        hints.extend([
+            Span::raw("  "),
+            Span::styled("[n/N] ", Style::default().fg(Theme::ACCENT)),
+            Span::styled("Location", Style::default().fg(Theme::TEXT_DIM)),
+            Span::raw("  "),
+            Span::styled("[o] ", Style::default().fg(Theme::ACCENT)),
+            Span::styled("Open", Style::default().fg(Theme::TEXT_DIM)),
+        ]);
+    }
+    let footer = Paragraph::new(Line::from(hints));
+    frame.render_widget(footer, footer_area);
+}
+
+fn focused_list_block(title: String) -> Block<'static> {
+    Block::default()
+        .title(title)
+        .title_style(Theme::title_style())
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(Theme::BORDER_FOCUSED))
+        .padding(Padding::horizontal(1))
+}
+
+/// Render a list with a key hint footer inside its bottom border
+fn render_list_with_footer(
+    frame: &mut Frame,
+    area: Rect,
+    list: List,
+    selected: Option<usize>,
+    footer: Line,
+) {
+    let list_area = Rect {
+        height: area.height.saturating_sub(1),
+        ..area
+    };
+    let mut state = ListState::default();
+    state.select(selected);
+    frame.render_stateful_widget(list, list_area, &mut state);
+
+    let footer_area = Rect {
+        x: area.x + 2,
+        y: area.y + area.height.saturating_sub(2),
+        width: area.width.saturating_sub(4),
+        height: 1,
+    };
+    frame.render_widget(Paragraph::new(footer), footer_area);
+}
+
+/// Error entries grouped by exception class and origin
+fn render_exceptions(frame: &mut Frame, area: Rect, app: &App) {
+    let rows = app.exception_rows();
+    let items: Vec<ListItem> = if rows.is_empty() {
+        vec![ListItem::new(Line::styled(
+            "No exceptions logged yet",
+            Style::default().fg(Theme::TEXT_MUTED),
+        ))]
+    } else {
+        rows.iter()
+            .map(|(_, group)| {
+                let mut header = vec![
+                    Span::styled(
+                        format!("{:>5}×  ", group.count),
+                        Style::default()
+                            .fg(Theme::ERROR)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(group.class.clone(), Style::default().fg(Theme::TEXT)),
+                ];
+                if let Some(location) = &group.location {
+                    header.push(Span::styled(
+                        format!("  {}", location),
+                        Style::default().fg(Theme::INFO),
+                    ));
+                }
+                ListItem::new(vec![
+                    Line::from(header),
+                    Line::from(vec![
+                        Span::raw("        "),
+                        Span::styled(
+                            format!("{} – {}  ", group.first_seen, group.last_seen),
+                            Style::default().fg(Theme::TEXT_MUTED),
+                        ),
+                        Span::styled(
+                            group.latest_message.clone(),
+                            Style::default().fg(Theme::TEXT_DIM),
+                        ),
+                    ]),
+                ])
+            })
+            .collect()
+    };
+
+    let title = format!(
+        " Exceptions [{}] Sort: {} ",
+        rows.len(),
+        app.logs_tab.exception_sort.name()
+    );
+    let list = List::new(items)
+        .block(focused_list_block(title))
+        .highlight_style(Style::default().bg(Theme::SELECTION_BG));
+    let footer = Line::from(vec![
+        Span::styled("[Enter] ", Style::default().fg(Theme::ACCENT)),
+        Span::styled("Occurrences", Style::default().fg(Theme::TEXT_DIM)),
+        Span::raw("  "),
+        Span::styled("[s] ", Style::default().fg(Theme::ACCENT)),
+        Span::styled("Sort", Style::default().fg(Theme::TEXT_DIM)),
+        Span::raw("  "),
+        Span::styled("[c] ", Style::default().fg(Theme::ACCENT)),
+        Span::styled("Clear", Style::default().fg(Theme::TEXT_DIM)),
+        Span::raw("  "),
+        Span::styled("[x/Esc] ", Style::default().fg(Theme::ACCENT)),
+        Span::styled("Back to Logs", Style::default().fg(Theme::TEXT_DIM)),
+    ]);
+    let selected = (!rows.is_empty()).then_some(app.logs_tab.exception_index);
+    render_list_with_footer(frame, area, list, selected, footer);
+}
+
+/// Entries of one exception group, newest first
+fn render_occurrences(frame: &mut Frame, area: Rect, app: &App) {
+    let Some(group) = app
+        .logs_tab
+        .open_exception
+        .and_then(|index| app.exception_groups.get(index))
+    else {
+        frame.render_widget(focused_list_block(" Occurrences ".to_string()), area);
+        return;
+    };
+
+    let occurrences = app.exception_occurrences();
+    let mut items: Vec<ListItem> = occurrences
+        .iter()
+        .map(|log| {
+            // Drop the context JSON, which usually continues on the next lines
+            let message = log
+                .message
+                .split_once(" {\"")
+                .map_or(log.message.as_str(), |(message, _)| message)
+                .to_string();
+            ListItem::new(Line::from(vec![
+                Span::styled(list_time(&log.time), Style::default().fg(Theme::TEXT_MUTED)),
+                Span::raw("  "),
+                Span::styled(
+                    format!("{:<12}", log.file),
+                    Style::default().fg(Theme::TEXT_DIM),
+                ),
+                Span::raw("  "),
+                Span::styled(message, Style::default().fg(Theme::TEXT)),
+            ]))
+        })
+        .collect();
+    // Older occurrences have left the log buffer; only their count is kept
+    let dropped = group.count - occurrences.len();
+    if dropped > 0 {
+        items.push(ListItem::new(Line::styled(
+            format!("… {} older occurrences no longer in the buffer", dropped),
+            Style::default().fg(Theme::TEXT_MUTED),
+        )));
+    }
+
+    let title = format!(" {} [{}] ", group.class, group.count);
+    let list = List::new(items)
+        .block(focused_list_block(title))
+        .highlight_style(Style::default().bg(Theme::SELECTION_BG));
+    let footer = Line::from(vec![
+        Span::styled("[Enter] ", Style::default().fg(Theme::ACCENT)),
+        Span::styled("Details", Style::default().fg(Theme::TEXT_DIM)),
+        Span::raw("  "),
+        Span::styled("[Esc] ", Style::default().fg(Theme::ACCENT)),
+        Span::styled("Back", Style::default().fg(Theme::TEXT_DIM)),
+    ]);
+    let selected = (!occurrences.is_empty()).then_some(app.logs_tab.occurrence_index);
+    render_list_with_footer(frame, area, list, selected, footer);
+}
+
 #[allow(clippy::too_many_arguments)]
 fn render_with_search(
     frame: &mut Frame,
@@ -252,3 +903,33 @@ fn render_scrollbar(
 
     frame.render_stateful_widget(scrollbar, scrollbar_area, &mut scrollbar_state);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(spans: &[Span]) -> Vec<String> {
+        spans.iter().map(|span| span.content.to_string()).collect()
+    }
+
+    #[test]
+    fn test_highlight_matches() {
+        let style = Style::default();
+        assert_eq!(texts(&highlight_matches("abc", style, None)), vec!["abc"]);
+
+        let pattern = Regex::new("b+").unwrap();
+        let spans = highlight_matches("abbcb", style, Some(&pattern));
+        assert_eq!(texts(&spans), vec!["a", "bb", "c", "b"]);
+        assert_eq!(spans[1].style, Theme::search_match_style());
+
+        // Empty matches are not highlighted and don't split the text
+        let pattern = Regex::new("x*").unwrap();
+        let spans = highlight_matches("abc", style, Some(&pattern));
+        assert_eq!(texts(&spans), vec!["abc"]);
+        assert_eq!(spans[0].style, style);
+        assert_eq!(
+            texts(&highlight_matches("", style, Some(&pattern))),
+            vec![""]
+        );
+    }
+}
@@ -41,7 +41,10 @@ fn render_command_list(frame: &mut Frame, area: Rect, app: &App) {
         .as_ref()
         .map(|c| c.make_favorites())
         .unwrap_or(&[]);
-    let commands = app.make_tab.current_command_display(favorites, app.is_sail);
+    let Some(console) = app.console.as_ref() else {
+        return;
+    };
+    let commands = app.make_tab.current_command_display(favorites, console);
 
     let title = format!(" Make Commands ({}) ", commands.len());
 
@@ -165,14 +168,15 @@ fn render_command_details(frame: &mut Frame, area: Rect, app: &App) {
 
     let max_width = inner.width.saturating_sub(2) as usize;
 
-    if let Some(cmd) = app.make_tab.selected_make_command(favorites) {
+    let selected = app.make_tab.selected_make_command(favorites);
+    if let (Some(cmd), Some(console)) = (selected, app.console.as_ref()) {
         let mut lines = Vec::new();
 
         // Command name
         lines.push(Line::from(vec![
             Span::styled("Command: ", Style::default().fg(Theme::ACCENT)),
             Span::styled(
-                format!("php artisan {}", cmd.name),
+                console.display(&cmd.name),
                 Style::default()
                     .fg(Theme::TEXT)
                     .add_modifier(Modifier::BOLD),
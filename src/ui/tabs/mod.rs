@@ -9,7 +9,9 @@ mod quality;
 pub use about::render as render_about;
 pub use artisan::render as render_artisan;
 pub use config::render as render_config;
-pub use logs::render as render_logs;
+pub use logs::{
+    detail_max_scroll as logs_detail_max_scroll, render as render_logs, view_rows as logs_view_rows,
+};
 pub use make::render as render_make;
 pub use processes::render as render_processes;
 pub use quality::render as render_quality;
@@ -2,19 +2,27 @@ use ansi_to_tui::IntoText;
 use ratatui::{
     prelude::*,
     widgets::{
-        Block, BorderType, Borders, List, ListItem, ListState, Padding, Paragraph, Scrollbar,
-        ScrollbarOrientation, ScrollbarState, Wrap,
+        Block, BorderType, Borders, Clear, List, ListItem, ListState, Padding, Paragraph,
+        Scrollbar, ScrollbarOrientation, ScrollbarState, Wrap,
     },
 };
 
 use crate::app::{App, ProcessesView};
-use crate::process::types::ProcessStatus;
+use crate::process::manager::RestartEvent;
+use crate::process::supervisor::SupervisorState;
+use crate::process::types::{ProcessSignal, ProcessStatus};
 use crate::ui::theme::{symbols, Theme};
 
 pub fn render(frame: &mut Frame, area: Rect, app: &App) {
     match app.processes_tab.view {
         ProcessesView::List => render_list_view(frame, area, app),
         ProcessesView::Output => render_output_view(frame, area, app),
+        ProcessesView::Inspector => render_inspector_view(frame, area, app),
+        ProcessesView::Crashes => render_crashes_view(frame, area, app),
+    }
+
+    if let Some(selected) = app.processes_tab.signal_menu {
+        render_signal_menu(frame, area, app, selected);
     }
 }
 
@@ -32,8 +40,8 @@ fn render_list_view(frame: &mut Frame, area: Rect, app: &App) {
 
             let is_selected = idx == app.processes_tab.selected_index;
 
-            // Get process stats if running
-            let process_stats = pid.and_then(|p| app.system_stats.process_stats.get(&p));
+            // Stats summed over the process tree if running
+            let process_stats = pid.and_then(|p| app.system_stats.process_trees.get(&p));
 
             // Build the line with proper styling
             let mut spans = Vec::new();
@@ -72,8 +80,22 @@ fn render_list_view(frame: &mut Frame, area: Rect, app: &App) {
                 },
             ));
 
+            // Supervisor's own state and uptime for supervised processes,
             // CPU/RAM stats for running processes
-            if status == ProcessStatus::Running || status == ProcessStatus::Supervised {
+            if let Some(info) = process.and_then(|p| p.supervisor.as_ref()) {
+                spans.push(Span::styled(
+                    format!("{:<9}", info.state.name()),
+                    supervisor_state_style(info.state),
+                ));
+                spans.push(Span::styled(
+                    format!("{:<8}", info.uptime.as_deref().unwrap_or("-")),
+                    Style::default().fg(Theme::TEXT_DIM),
+                ));
+                spans.push(Span::raw("  "));
+            } else if matches!(
+                status,
+                ProcessStatus::Running | ProcessStatus::Supervised | ProcessStatus::Paused
+            ) {
                 if let Some(stats) = process_stats {
                     let cpu_str = format!("{:>5.1}%", stats.cpu_usage);
                     let mem_mb = stats.memory_bytes as f64 / 1024.0 / 1024.0;
@@ -97,23 +119,38 @@ fn render_list_view(frame: &mut Frame, area: Rect, app: &App) {
             }
 
             // Action hints
+            let supervisor_controls = app.has_supervisor
+                && process.is_some_and(|p| p.config.supervisor_program.is_some());
             let action_hint = match status {
-                ProcessStatus::Running => "[x]stop [r]restart",
-                ProcessStatus::Supervised => "[x]detach [r]reconnect",
-                ProcessStatus::Stopped | ProcessStatus::Failed => {
-                    if is_supervised {
-                        "[s]tail logs"
-                    } else {
-                        "[s]start"
-                    }
-                }
+                ProcessStatus::Supervised if !supervisor_controls => "[x]detach [r]reconnect",
+                ProcessStatus::Running | ProcessStatus::Supervised => "[x]stop [r]restart",
+                ProcessStatus::Stopped | ProcessStatus::Failed => "[s]start",
                 ProcessStatus::Restarting => "please wait...",
+                ProcessStatus::Paused => "[P]signal [x]stop",
             };
             spans.push(Span::styled(
                 action_hint,
                 Style::default().fg(Theme::TEXT_MUTED),
             ));
 
+            // Errors and warnings received since the output was last viewed
+            if let Some(process) = process {
+                if process.unread_errors > 0 {
+                    spans.push(Span::styled(
+                        format!("  {} {}", symbols::FAILED, process.unread_errors),
+                        Style::default()
+                            .fg(Theme::ERROR)
+                            .add_modifier(Modifier::BOLD),
+                    ));
+                }
+                if process.unread_warnings > 0 {
+                    spans.push(Span::styled(
+                        format!("  {} {}", symbols::WARNING, process.unread_warnings),
+                        Style::default().fg(Theme::WARNING),
+                    ));
+                }
+            }
+
             ListItem::new(Line::from(spans))
         })
         .collect();
@@ -150,36 +187,160 @@ fn render_list_view(frame: &mut Frame, area: Rect, app: &App) {
             Span::raw("  "),
             Span::styled("[Enter] ", Style::default().fg(Theme::ACCENT)),
             Span::styled("View Output", Style::default().fg(Theme::TEXT_DIM)),
+            Span::raw("  "),
+            Span::styled("[i] ", Style::default().fg(Theme::ACCENT)),
+            Span::styled("Inspect", Style::default().fg(Theme::TEXT_DIM)),
+            Span::raw("  "),
+            Span::styled("[P] ", Style::default().fg(Theme::ACCENT)),
+            Span::styled("Signal", Style::default().fg(Theme::TEXT_DIM)),
         ]));
         frame.render_widget(footer, footer_area);
     }
 }
 
+/// Popup listing the signals that can be sent to the selected process
+fn render_signal_menu(frame: &mut Frame, area: Rect, app: &App, selected: usize) {
+    let signals = ProcessSignal::all();
+    let width = 52.min(area.width);
+    let height = (signals.len() as u16 + 2).min(area.height);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+
+    let items: Vec<ListItem> = signals
+        .iter()
+        .map(|signal| {
+            ListItem::new(Line::from(vec![
+                Span::styled(
+                    format!("{:<9}", signal.name()),
+                    Style::default().fg(Theme::ACCENT),
+                ),
+                Span::styled(signal.description(), Style::default().fg(Theme::TEXT_DIM)),
+            ]))
+        })
+        .collect();
+
+    let title = match app.selected_process() {
+        Some(process) => format!(" Signal {} ", app.registry.display_name(&process.id)),
+        None => " Signal ".to_string(),
+    };
+    let block = Block::default()
+        .title(title)
+        .title_style(Theme::title_style())
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(Theme::BORDER_FOCUSED))
+        .padding(Padding::horizontal(1));
+
+    let list = List::new(items)
+        .block(block)
+        .highlight_style(Style::default().bg(Theme::SELECTION_BG));
+
+    let mut state = ListState::default();
+    state.select(Some(selected));
+
+    frame.render_widget(Clear, popup);
+    frame.render_stateful_widget(list, popup, &mut state);
+}
+
+/// Color a supervisord state like the matching process status
+fn supervisor_state_style(state: SupervisorState) -> Style {
+    let color = match state {
+        SupervisorState::Running => Theme::SUCCESS,
+        SupervisorState::Starting | SupervisorState::Stopping | SupervisorState::Backoff => {
+            Theme::WARNING
+        }
+        SupervisorState::Fatal | SupervisorState::Exited | SupervisorState::Unknown => Theme::ERROR,
+        SupervisorState::Stopped => Theme::TEXT_MUTED,
+    };
+    Style::default().fg(color)
+}
+
 fn render_output_view(frame: &mut Frame, area: Rect, app: &App) {
     let (title, lines, scroll_state) = match app.selected_process() {
         Some(process) => {
             let display_name = app.registry.display_name(&process.id);
             let status_symbol = Theme::status_symbol(process.status);
-            let title = format!(" {} {} ", display_name, status_symbol);
+            let run_index = app.viewed_run_index();
+            let mut title = match run_index.and_then(|idx| process.runs.get(idx)) {
+                Some(run) => format!(
+                    " {} {} · run {} · {} · {} ",
+                    display_name,
+                    status_symbol,
+                    run.number,
+                    run.outcome(),
+                    format_duration(run.duration().as_secs())
+                ),
+                None => format!(" {} {} ", display_name, status_symbol),
+            };
+
+            let raw = app.processes_tab.raw_output;
+            let cursor = app.processes_tab.error_cursor;
+            let output = || {
+                process
+                    .numbered_run_output(run_index.unwrap_or(usize::MAX))
+                    .filter(move |(_, line)| raw || !line.hidden)
+            };
+            if !process.config.output_rules.is_empty() {
+                if raw {
+                    title.push_str("· raw ");
+                } else {
+                    let hidden = process
+                        .run_output(run_index.unwrap_or(usize::MAX))
+                        .filter(|line| line.hidden)
+                        .count();
+                    title.push_str(&format!("· {} hidden ", hidden));
+                }
+            }
 
-            let lines: Vec<Line> = process
-                .output
-                .iter()
-                .flat_map(|line| {
+            // Position of the error jumped to with n/N
+            if let Some(cursor) = cursor {
+                let errors: Vec<u64> = output()
+                    .filter(|(_, line)| line.is_error && !line.in_trace)
+                    .map(|(number, _)| number)
+                    .collect();
+                if let Some(pos) = errors.iter().position(|&number| number == cursor) {
+                    title.push_str(&format!("· error {}/{} ", pos + 1, errors.len()));
+                }
+            }
+
+            let lines: Vec<Line> = output()
+                .flat_map(|(number, line)| {
                     // Parse ANSI codes, fall back to plain text on error
-                    match line.content.as_bytes().into_text() {
+                    let mut lines = match line.content.as_bytes().into_text() {
                         Ok(text) => text.lines,
                         Err(_) => {
                             let style = if line.is_error {
                                 Style::default().fg(Theme::ERROR)
-                            } else if line.is_stderr {
+                            } else if line.is_stderr || line.is_warning {
                                 Style::default().fg(Theme::WARNING)
                             } else {
                                 Style::default().fg(Theme::TEXT)
                             };
                             vec![Line::styled(line.content.clone(), style)]
                         }
+                    };
+                    if let Some(highlight) = line.highlight.filter(|_| !raw) {
+                        let style = Theme::highlight_style(highlight);
+                        for span in lines.iter_mut().flat_map(|l| l.spans.iter_mut()) {
+                            span.style = span.style.patch(style);
+                        }
+                    }
+                    for rendered in &mut lines {
+                        // Stack traces hang off their error line
+                        if line.in_trace {
+                            rendered
+                                .spans
+                                .insert(0, Span::styled("│ ", Style::default().fg(Theme::ERROR)));
+                        }
+                        if cursor == Some(number) {
+                            rendered.style = rendered.style.bg(Theme::SELECTION_BG);
+                        }
                     }
+                    lines
                 })
                 .collect();
 
@@ -260,8 +421,324 @@ fn render_output_view(frame: &mut Frame, area: Rect, app: &App) {
         Span::styled("[r] ", Style::default().fg(Theme::ACCENT)),
         Span::styled("Restart", Style::default().fg(Theme::TEXT_DIM)),
         Span::raw("  "),
+        Span::styled("[f] ", Style::default().fg(Theme::ACCENT)),
+        Span::styled("Filtered/Raw", Style::default().fg(Theme::TEXT_DIM)),
+        Span::raw("  "),
+        Span::styled("[n/N] ", Style::default().fg(Theme::ACCENT)),
+        Span::styled("Next/Prev Error", Style::default().fg(Theme::TEXT_DIM)),
+        Span::raw("  "),
+        Span::styled("[ / ] ", Style::default().fg(Theme::ACCENT)),
+        Span::styled("Prev/Next Run", Style::default().fg(Theme::TEXT_DIM)),
+        Span::raw("  "),
         Span::styled("[PageUp/Down] ", Style::default().fg(Theme::ACCENT)),
         Span::styled("Scroll", Style::default().fg(Theme::TEXT_DIM)),
     ]));
     frame.render_widget(footer, footer_area);
 }
+
+/// Crash reports from `.laramux/crashes/` with a preview of the selected one
+fn render_crashes_view(frame: &mut Frame, area: Rect, app: &App) {
+    let tab = &app.processes_tab;
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(35), Constraint::Percentage(65)])
+        .split(area);
+
+    let items: Vec<ListItem> = if tab.crashes.is_empty() {
+        vec![ListItem::new(muted_line(
+            "No crash reports yet".to_string(),
+        ))]
+    } else {
+        tab.crashes
+            .iter()
+            .map(|report| {
+                let age = report
+                    .written_at
+                    .elapsed()
+                    .map(|d| format!("{} ago", format_duration(d.as_secs())))
+                    .unwrap_or_default();
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        format!("{:<12}", report.process),
+                        Style::default().fg(Theme::TEXT),
+                    ),
+                    Span::styled(
+                        format!("{:<10}", report.exit),
+                        Style::default().fg(Theme::ERROR),
+                    ),
+                    Span::styled(age, Style::default().fg(Theme::TEXT_DIM)),
+                ]))
+            })
+            .collect()
+    };
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(format!(" Crashes ({}) ", tab.crashes.len()))
+                .title_style(Theme::title_style())
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Theme::BORDER_FOCUSED))
+                .padding(Padding::horizontal(1)),
+        )
+        .highlight_style(Style::default().bg(Theme::SELECTION_BG));
+
+    let mut state = ListState::default();
+    if !tab.crashes.is_empty() {
+        state.select(Some(tab.crash_index));
+    }
+    frame.render_stateful_widget(list, chunks[0], &mut state);
+
+    let title = tab
+        .selected_crash()
+        .and_then(|report| report.path.file_name())
+        .map(|name| format!(" {} ", name.to_string_lossy()))
+        .unwrap_or_else(|| " Report ".to_string());
+    let preview = Paragraph::new(tab.crash_preview.as_str())
+        .style(Style::default().fg(Theme::TEXT))
+        .block(
+            Block::default()
+                .title(title)
+                .title_style(Theme::title_style())
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(Theme::BORDER))
+                .padding(Padding::horizontal(1)),
+        )
+        .wrap(Wrap { trim: false })
+        .scroll((tab.crash_scroll, 0));
+    frame.render_widget(preview, chunks[1]);
+}
+
+fn render_inspector_view(frame: &mut Frame, area: Rect, app: &App) {
+    let Some(process) = app.selected_process() else {
+        frame.render_widget(Theme::focused_block(" No Process Selected "), area);
+        return;
+    };
+
+    let display_name = app.registry.display_name(&process.id);
+    let title = format!(" Inspect {} ", display_name);
+    let config = &process.config;
+
+    let mut lines = vec![section_header("Resolved config")];
+
+    // One row per config field, annotated with the .laramux.json key it came from
+    let field = |label: &str, value: String, source_key: &str| -> Line<'static> {
+        let source = config
+            .sources
+            .get(source_key)
+            .cloned()
+            .unwrap_or_else(|| "discovered".to_string());
+        Line::from(vec![
+            Span::styled(
+                format!("{:<20}", label),
+                Style::default().fg(Theme::TEXT_DIM),
+            ),
+            Span::styled(value, Style::default().fg(Theme::TEXT)),
+            Span::styled(
+                format!("  ← {}", source),
+                Style::default().fg(Theme::TEXT_MUTED),
+            ),
+        ])
+    };
+
+    lines.push(field("command", config.command.clone(), "command"));
+    lines.push(field("args", format!("{:?}", config.args), "args"));
+    lines.push(field(
+        "working_dir",
+        config.working_dir.display().to_string(),
+        "working_dir",
+    ));
+    lines.push(field(
+        "restart_policy",
+        format!("{:?}", config.restart_policy),
+        "restart_policy",
+    ));
+    if let Some(ref program) = config.supervisor_program {
+        lines.push(field(
+            "supervisor_program",
+            program.clone(),
+            "supervisor_program",
+        ));
+    }
+    if !config.output_rules.is_empty() {
+        lines.push(field("output", config.output_rules.summary(), "output"));
+    }
+    if config.env.is_empty() {
+        lines.push(field("env", "(none)".to_string(), "env"));
+    } else {
+        let mut env: Vec<_> = config.env.iter().collect();
+        env.sort();
+        lines.push(field("env", String::new(), "env"));
+        for (key, value) in env {
+            lines.push(Line::from(vec![
+                Span::raw("  "),
+                Span::styled(
+                    format!("{}={}", key, value),
+                    Style::default().fg(Theme::TEXT),
+                ),
+            ]));
+        }
+    }
+
+    // Live process tree under the process group leader, with per-process usage
+    lines.push(Line::from(""));
+    lines.push(section_header("Process tree"));
+    match process.pid {
+        Some(pid) => match app.system_stats.process_trees.get(&pid) {
+            Some(tree) => {
+                let stats = &app.system_stats.process_stats;
+                let tree_row = |current: u32, depth: usize| -> Option<Line<'static>> {
+                    let info = stats.get(&current)?;
+                    Some(Line::from(vec![
+                        Span::styled(
+                            format!("{}{:<8}", "  ".repeat(depth), current),
+                            Style::default().fg(Theme::ACCENT),
+                        ),
+                        Span::styled(
+                            format!(
+                                "{:>5.1}% {:>7}  {:<10}",
+                                info.cpu_usage,
+                                format_memory(info.memory_bytes),
+                                format_ago_secs(info.start_time)
+                            ),
+                            Style::default().fg(Theme::TEXT_DIM),
+                        ),
+                        Span::styled(info.command.clone(), Style::default().fg(Theme::TEXT)),
+                    ]))
+                };
+
+                let mut shown = Vec::new();
+                let mut stack = vec![(pid, 0usize)];
+                while let Some((current, depth)) = stack.pop() {
+                    if shown.contains(&current) || !tree.pids.contains(&current) {
+                        continue;
+                    }
+                    lines.extend(tree_row(current, depth));
+                    shown.push(current);
+
+                    let mut children: Vec<u32> = tree
+                        .pids
+                        .iter()
+                        .copied()
+                        .filter(|p| stats.get(p).and_then(|s| s.parent_pid) == Some(current))
+                        .collect();
+                    children.sort_unstable_by(|a, b| b.cmp(a));
+                    stack.extend(children.into_iter().map(|child| (child, depth + 1)));
+                }
+
+                // Group members that were reparented away from the tree
+                let detached: Vec<u32> = tree
+                    .pids
+                    .iter()
+                    .copied()
+                    .filter(|p| !shown.contains(p))
+                    .collect();
+                if !detached.is_empty() {
+                    lines.push(muted_line("Same process group, reparented:".to_string()));
+                    for current in detached {
+                        lines.extend(tree_row(current, 1));
+                    }
+                }
+
+                lines.push(Line::from(vec![
+                    Span::styled(
+                        format!("{:<8}", "total"),
+                        Style::default().fg(Theme::ACCENT),
+                    ),
+                    Span::styled(
+                        format!(
+                            "{:>5.1}% {:>7}  {} process(es)",
+                            tree.cpu_usage,
+                            format_memory(tree.memory_bytes),
+                            tree.pids.len()
+                        ),
+                        Style::default()
+                            .fg(Theme::TEXT)
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                ]));
+            }
+            None => lines.push(muted_line(format!("PID {} (waiting for stats)", pid))),
+        },
+        None => lines.push(muted_line("Not running".to_string())),
+    }
+
+    // Restart history from the manager's RestartState
+    lines.push(Line::from(""));
+    lines.push(section_header("Restart history"));
+    match process.restart_state.as_ref() {
+        Some(state) if !state.history.is_empty() => {
+            lines.push(muted_line(format!(
+                "{} consecutive failure(s), next backoff {}s",
+                state.consecutive_failures,
+                state.backoff_delay().as_secs()
+            )));
+            for record in state.history.iter().rev() {
+                let (label, color) = match record.event {
+                    RestartEvent::Started => ("started", Theme::SUCCESS),
+                    RestartEvent::Failed => ("failed", Theme::ERROR),
+                };
+                let ago = record
+                    .at
+                    .elapsed()
+                    .map(|d| format!("{} ago", format_duration(d.as_secs())))
+                    .unwrap_or_else(|_| "just now".to_string());
+                lines.push(Line::from(vec![
+                    Span::styled(format!("{:<10}", label), Style::default().fg(color)),
+                    Span::styled(ago, Style::default().fg(Theme::TEXT_DIM)),
+                ]));
+            }
+        }
+        _ => lines.push(muted_line("No restarts recorded".to_string())),
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .block(Theme::focused_block(&title).padding(Padding::horizontal(1)))
+        .wrap(Wrap { trim: false })
+        .scroll((app.processes_tab.inspector_scroll, 0));
+    frame.render_widget(paragraph, area);
+}
+
+fn section_header(title: &str) -> Line<'static> {
+    Line::from(Span::styled(
+        title.to_string(),
+        Style::default()
+            .fg(Theme::ACCENT)
+            .add_modifier(Modifier::BOLD),
+    ))
+}
+
+fn muted_line(text: String) -> Line<'static> {
+    Line::from(Span::styled(text, Style::default().fg(Theme::TEXT_MUTED)))
+}
+
+/// Format "how long ago" for a Unix timestamp in seconds
+fn format_ago_secs(epoch_secs: u64) -> String {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    format!("{} ago", format_duration(now.saturating_sub(epoch_secs)))
+}
+
+/// Format a byte count as megabytes, or gigabytes when large
+fn format_memory(bytes: u64) -> String {
+    let mb = bytes as f64 / 1024.0 / 1024.0;
+    if mb >= 1024.0 {
+        format!("{:.1}GB", mb / 1024.0)
+    } else {
+        format!("{:.1}MB", mb)
+    }
+}
+
+/// Format a number of seconds as a compact duration
+fn format_duration(secs: u64) -> String {
+    match secs {
+        0..=59 => format!("{}s", secs),
+        60..=3599 => format!("{}m", secs / 60),
+        3600..=86399 => format!("{}h{}m", secs / 3600, (secs % 3600) / 60),
+        _ => format!("{}d", secs / 86400),
+    }
+}
@@ -3,6 +3,7 @@ use ratatui::{
     widgets::{block::Title, Block, BorderType, Borders},
 };
 
+use crate::process::output::HighlightStyle;
 use crate::process::types::ProcessStatus;
 
 /// Claude Code inspired color theme
@@ -15,6 +16,8 @@ pub mod symbols {
     pub const RESTARTING: &str = "↻";
     pub const FAILED: &str = "✗";
     pub const SUPERVISED: &str = "◆";
+    pub const PAUSED: &str = "⏸";
+    pub const WARNING: &str = "⚠";
     pub const SELECTOR: &str = "▶";
 }
 
@@ -75,6 +78,14 @@ impl Theme {
             .add_modifier(Modifier::BOLD)
     }
 
+    /// Returns the style for search matches in the Logs tab
+    pub fn search_match_style() -> Style {
+        Style::default()
+            .fg(Color::Black)
+            .bg(Self::WARNING)
+            .add_modifier(Modifier::BOLD)
+    }
+
     /// Returns a block with rounded borders and consistent styling
     pub fn default_block(title: &str) -> Block<'_> {
         Block::default()
@@ -106,6 +117,7 @@ impl Theme {
             ProcessStatus::Restarting => symbols::RESTARTING,
             ProcessStatus::Failed => symbols::FAILED,
             ProcessStatus::Supervised => symbols::SUPERVISED,
+            ProcessStatus::Paused => symbols::PAUSED,
         }
     }
 
@@ -117,6 +129,7 @@ impl Theme {
             ProcessStatus::Restarting => Style::default().fg(Self::WARNING),
             ProcessStatus::Failed => Style::default().fg(Self::ERROR),
             ProcessStatus::Supervised => Style::default().fg(Self::SUPERVISED),
+            ProcessStatus::Paused => Style::default().fg(Self::WARNING),
         }
     }
 
@@ -127,7 +140,21 @@ impl Theme {
             ProcessStatus::Restarting => Some("restarting..."),
             ProcessStatus::Failed => Some("failed"),
             ProcessStatus::Supervised => Some("supervised"),
+            ProcessStatus::Paused => Some("paused"),
             ProcessStatus::Stopped => None,
         }
     }
+
+    /// Returns the style for an output highlight rule
+    pub fn highlight_style(style: HighlightStyle) -> Style {
+        match style {
+            HighlightStyle::Error => Style::default().fg(Self::ERROR),
+            HighlightStyle::Warning => Style::default().fg(Self::WARNING),
+            HighlightStyle::Success => Style::default().fg(Self::SUCCESS),
+            HighlightStyle::Info => Style::default().fg(Self::INFO),
+            HighlightStyle::Accent => Style::default().fg(Self::ACCENT),
+            HighlightStyle::Muted => Style::default().fg(Self::TEXT_MUTED),
+            HighlightStyle::Bold => Style::default().add_modifier(Modifier::BOLD),
+        }
+    }
 }
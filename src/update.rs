@@ -12,7 +12,11 @@ pub fn print_help() {
     println!();
     println!("USAGE:");
     println!("    laramux              Start the TUI in a Laravel project directory");
+    println!("                         (or any subdirectory of one)");
+    println!("    laramux --dir <path> Start the TUI for the project at or above <path>");
     println!("    laramux update       Update to the latest version");
+    println!("    laramux sail restore-supervisor");
+    println!("                         Revert the supervisor config patched by laramux");
     println!("    laramux --version    Print version");
     println!("    laramux --help       Print this help message");
 }